@@ -0,0 +1,109 @@
+/*
+    Appellation: cli <module>
+    Created At: 2026.10.19:16:22:51
+    Contrib: @FL03
+*/
+use crate::format::Format;
+use std::path::PathBuf;
+
+#[derive(Debug, clap::Parser)]
+#[command(
+    name = "rstm",
+    version,
+    about = "run, inspect and convert Turing machines"
+)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Debug, clap::Subcommand)]
+pub enum Command {
+    /// run a program until it halts, printing the final configuration
+    Run(RunArgs),
+    /// run a program, printing the configuration after every step
+    Trace(RunArgs),
+    /// step through a program interactively
+    Debug(DebugArgs),
+    /// validate a program, reporting any errors or warnings
+    Lint(LoadArgs),
+    /// translate a program from one format into another
+    Convert(ConvertArgs),
+    /// render the state diagram of a program
+    Render(RenderArgs),
+}
+
+/// the arguments used to load a program
+#[derive(Debug, clap::Args)]
+pub struct LoadArgs {
+    /// the path to the program, or `-` to read it from stdin
+    pub program: PathBuf,
+    /// the format of the program; inferred from the extension by default
+    #[arg(short, long, value_enum)]
+    pub format: Option<Format>,
+}
+
+#[derive(Debug, clap::Args)]
+pub struct RunArgs {
+    #[command(flatten)]
+    pub load: LoadArgs,
+    /// the initial contents of the tape, where `_` denotes a blank; read from stdin when
+    /// omitted and stdin is not a terminal
+    #[arg(short, long)]
+    pub input: Option<String>,
+    /// the maximum number of steps to execute before giving up
+    #[arg(short = 'n', long, default_value_t = 10_000)]
+    pub max_steps: usize,
+    /// overrides the initial state of the program
+    #[arg(short, long)]
+    pub state: Option<String>,
+}
+
+#[derive(Debug, clap::Args)]
+pub struct DebugArgs {
+    #[command(flatten)]
+    pub load: LoadArgs,
+    /// the initial contents of the tape, where `_` denotes a blank
+    #[arg(short, long)]
+    pub input: Option<String>,
+    /// the maximum number of steps to execute with each `continue`
+    #[arg(short = 'n', long, default_value_t = 10_000)]
+    pub max_steps: usize,
+    /// overrides the initial state of the program
+    #[arg(short, long)]
+    pub state: Option<String>,
+}
+
+#[derive(Debug, clap::Args)]
+pub struct ConvertArgs {
+    #[command(flatten)]
+    pub load: LoadArgs,
+    /// the format to convert into; inferred from the output path by default
+    #[arg(short, long, value_enum)]
+    pub to: Option<Format>,
+    /// the path to write the converted program to; written to stdout by default
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Debug, clap::Args)]
+pub struct RenderArgs {
+    #[command(flatten)]
+    pub load: LoadArgs,
+    /// the kind of diagram to render
+    #[arg(short, long, value_enum, default_value_t = Diagram::Dot)]
+    pub diagram: Diagram,
+    /// the path to write the diagram to; written to stdout by default
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+}
+
+/// the kinds of diagrams supported by the `render` command
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, clap::ValueEnum)]
+pub enum Diagram {
+    /// a Graphviz (DOT) digraph
+    #[default]
+    Dot,
+    /// a Mermaid state diagram
+    Mermaid,
+}
@@ -0,0 +1,26 @@
+/*
+    Appellation: convert <module>
+    Created At: 2026.10.19:17:03:40
+    Contrib: @FL03
+*/
+use crate::cli::ConvertArgs;
+use crate::format::{self, Format};
+use anyhow::Context;
+use std::process::ExitCode;
+
+/// translates the program from one format into another
+pub fn convert(args: &ConvertArgs) -> anyhow::Result<ExitCode> {
+    let program = format::load(&args.load.program, args.load.format)?;
+    let to = match (args.to, &args.output) {
+        (Some(to), _) => to,
+        (None, Some(output)) => Format::resolve(None, output)?,
+        (None, None) => anyhow::bail!("the format to convert into must be specified with `--to`"),
+    };
+    let output = format::emit(&program, to)?;
+    match &args.output {
+        Some(path) => std::fs::write(path, output)
+            .with_context(|| format!("unable to write {}", path.display()))?,
+        None => print!("{output}"),
+    }
+    Ok(ExitCode::SUCCESS)
+}
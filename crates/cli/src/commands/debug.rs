@@ -0,0 +1,396 @@
+/*
+    Appellation: debug <module>
+    Created At: 2026.10.19:18:02:37
+    Contrib: @FL03
+*/
+use crate::cli::DebugArgs;
+use crate::format::{self, Machine};
+use crate::machine::{self, Halting, Outcome};
+use anyhow::{Context, bail};
+use rstm_core::tape::{PersistentStore, PersistentTape};
+use rstm_core::{DEFAULT_DISPLAY_RADIUS, MovingHead, State};
+use std::collections::VecDeque;
+use std::io::{BufRead, IsTerminal, Write};
+use std::process::ExitCode;
+
+/// the maximum number of snapshots retained for stepping backwards
+const HISTORY_LIMIT: usize = 10_000;
+
+const HELP: &str = "\
+commands:
+    step, s [n]               execute the next `n` steps (default: 1)
+    continue, c               run until a breakpoint is hit or the machine stops
+    back, b [n]               undo the last `n` steps or edits (default: 1)
+    break, br state <q>       break upon entering the state `q`
+    break, br symbol <a>      break upon reading the symbol `a`
+    delete, d [n]             delete the breakpoint `n`, or every breakpoint
+    info, i                   list the breakpoints alongside the current configuration
+    print, p [radius]         print the window of the tape surrounding the head
+    rule, r                   show the rule that applies to the current head
+    write, w [offset] <a>     write `a` onto the cell at `offset` from the head (default: 0)
+    reload                    reload the program, keeping the current configuration
+    restart                   restore the initial configuration
+    help, h                   show this message
+    quit, q                   exit the debugger
+
+an empty line repeats the previous command, while `_` denotes a blank.";
+
+/// the engine driving the debugger, whose persistent tape may be cheaply snapshotted
+type Engine = MovingHead<String, char, PersistentStore<char>>;
+
+/// a condition upon which execution is suspended
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Breakpoint {
+    State(String),
+    Symbol(char),
+}
+
+impl core::fmt::Display for Breakpoint {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::State(state) => write!(f, "state `{state}`"),
+            Self::Symbol(symbol) => write!(f, "symbol `{}`", machine::display_symbol(*symbol)),
+        }
+    }
+}
+
+/// the engine alongside the program loaded into it, whose tape grows on demand
+struct Session {
+    engine: Engine,
+}
+
+/// a snapshot of the configuration of the engine, excluding the program; the persistent tape
+/// shares its cells with the engine until either of them is written to.
+struct Snapshot {
+    state: String,
+    tape: PersistentTape<char>,
+    cycles: usize,
+}
+
+impl Session {
+    fn new(program: Machine, state: String, mut input: Vec<char>) -> Self {
+        // ensure the head rests upon a cell when displaying the tape
+        if input.is_empty() {
+            input.push(char::default());
+        }
+        let engine =
+            Engine::from_state_with_tape(state, PersistentTape::from_iter(input)).load(program);
+        Self { engine }
+    }
+    /// captures the current configuration of the engine
+    fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            state: self.engine.current_state().cloned().value(),
+            tape: self.engine.tape().clone(),
+            cycles: self.engine.cycles(),
+        }
+    }
+    /// restores the configuration captured by the snapshot, keeping the current program
+    fn restore(
+        &mut self,
+        Snapshot {
+            state,
+            tape,
+            cycles,
+        }: Snapshot,
+    ) {
+        let driver = self.engine.driver_mut();
+        driver.state = State(state);
+        driver.symbol = tape.head();
+        *self.engine.tape_mut() = tape;
+        self.engine.set_cycles(cycles);
+    }
+    /// returns the position of the head relative to the first cell of the input
+    fn position(&self) -> isize {
+        self.engine.tape().position()
+    }
+    /// returns the symbol under the head
+    fn symbol(&self) -> char {
+        self.engine.read().copied().unwrap_or_default()
+    }
+    /// writes the symbol onto the cell at the given offset from the head
+    fn write(&mut self, offset: isize, symbol: char) {
+        let tape = self.engine.tape_mut();
+        tape.write_at(tape.position() + offset, symbol);
+    }
+}
+
+/// an interactive debugger stepping through a program one rule at a time
+struct Debugger<'a> {
+    args: &'a DebugArgs,
+    input: Vec<char>,
+    state: String,
+    session: Session,
+    history: VecDeque<Snapshot>,
+    breakpoints: Vec<Breakpoint>,
+}
+
+impl<'a> Debugger<'a> {
+    fn new(args: &'a DebugArgs) -> anyhow::Result<Self> {
+        let program = format::load(&args.load.program, args.load.format)?;
+        let state = match &args.state {
+            Some(state) => state.clone(),
+            None => machine::initial_state(&program)?,
+        };
+        let input = machine::parse_tape(args.input.as_deref().unwrap_or_default());
+        let session = Session::new(program, state.clone(), input.clone());
+        Ok(Self {
+            args,
+            input,
+            state,
+            session,
+            history: VecDeque::new(),
+            breakpoints: Vec::new(),
+        })
+    }
+    /// returns the program loaded into the engine
+    fn program(&self) -> &Machine {
+        self.session
+            .engine
+            .program()
+            .expect("the debugger always loads a program")
+    }
+    /// returns the reason the machine is unable to proceed, if any
+    fn stopped(&self) -> Option<Outcome> {
+        let state = self.session.engine.current_state();
+        if let Some(kind) = Halting::new(self.program()).kind(state.get()) {
+            Some(Outcome::Halted(kind))
+        } else if self.session.engine.current_tail().is_none() {
+            Some(Outcome::NoTransition)
+        } else {
+            None
+        }
+    }
+    /// returns the first breakpoint matching the current configuration, if any
+    fn breakpoint(&self) -> Option<(usize, &Breakpoint)> {
+        let state = self.session.engine.current_state();
+        let symbol = self.session.symbol();
+        self.breakpoints
+            .iter()
+            .enumerate()
+            .find(|(_, bp)| match bp {
+                Breakpoint::State(q) => q == *state.get(),
+                Breakpoint::Symbol(a) => *a == symbol,
+            })
+            .map(|(idx, bp)| (idx + 1, bp))
+    }
+    /// records a snapshot of the current configuration, enabling it to be restored with `back`
+    fn checkpoint(&mut self) {
+        if self.history.len() == HISTORY_LIMIT {
+            self.history.pop_front();
+        }
+        self.history.push_back(self.session.snapshot());
+    }
+    /// executes up to `limit` steps, stopping early whenever a breakpoint is hit or the
+    /// machine is unable to proceed; returns the number of steps executed.
+    fn advance(&mut self, limit: usize, out: &mut impl Write) -> anyhow::Result<usize> {
+        let mut steps = 0;
+        while steps < limit {
+            if let Some(outcome) = self.stopped() {
+                writeln!(out, "the machine has stopped: {outcome}")?;
+                break;
+            }
+            self.checkpoint();
+            self.session.engine.step()?;
+            steps += 1;
+            if let Some((idx, bp)) = self.breakpoint() {
+                writeln!(out, "breakpoint {idx} hit: {bp}")?;
+                break;
+            }
+        }
+        Ok(steps)
+    }
+    /// prints the current configuration on a single line
+    fn status(&self, out: &mut impl Write) -> std::io::Result<()> {
+        writeln!(
+            out,
+            "{:>6}  {:<12} {}",
+            self.session.engine.cycles(),
+            self.session.engine.current_state().get(),
+            self.window(DEFAULT_DISPLAY_RADIUS)
+        )
+    }
+    /// returns the window of the tape surrounding the head
+    fn window(&self, radius: usize) -> String {
+        self.session.engine.print_with(radius, |&symbol| {
+            machine::display_symbol(symbol).to_string()
+        })
+    }
+    /// describes the rule applying to the current head
+    fn rule(&self) -> String {
+        let state = self.session.engine.current_state();
+        let symbol = machine::display_symbol(self.session.symbol());
+        if Halting::new(self.program()).is_halting(state.get()) {
+            return format!("the state `{}` halts", state.get());
+        }
+        match self.session.engine.current_tail() {
+            Some(tail) => format!(
+                "({}, {symbol}) -> {:?}({}, {})",
+                state.get(),
+                tail.direction(),
+                tail.next_state.get(),
+                machine::display_symbol(tail.write_symbol)
+            ),
+            None => format!("no rule is defined for ({}, {symbol})", state.get()),
+        }
+    }
+    /// executes a single command, returning false once the debugger should exit
+    fn execute(&mut self, line: &str, out: &mut impl Write) -> anyhow::Result<bool> {
+        let mut words = line.split_whitespace();
+        let Some(cmd) = words.next() else {
+            return Ok(true);
+        };
+        let args = words.collect::<Vec<_>>();
+        match (cmd, args.as_slice()) {
+            ("step" | "s", args) => {
+                let n = parse_count(args)?;
+                if self.advance(n, out)? > 0 {
+                    self.status(out)?;
+                }
+            }
+            ("continue" | "c", []) => {
+                let steps = self.advance(self.args.max_steps, out)?;
+                if steps == self.args.max_steps {
+                    writeln!(out, "paused after {steps} steps")?;
+                }
+                self.status(out)?;
+            }
+            ("back" | "b", args) => {
+                let n = parse_count(args)?;
+                let n = n.min(self.history.len());
+                if n == 0 {
+                    writeln!(out, "there are no steps to undo")?;
+                } else {
+                    self.history.truncate(self.history.len() - n + 1);
+                    let snapshot = self.history.pop_back().unwrap();
+                    self.session.restore(snapshot);
+                    self.status(out)?;
+                }
+            }
+            ("break" | "br", ["state", state]) => {
+                self.add_breakpoint(Breakpoint::State(state.to_string()), out)?
+            }
+            ("break" | "br", ["symbol", symbol]) => {
+                self.add_breakpoint(Breakpoint::Symbol(parse_symbol(symbol)?), out)?
+            }
+            ("delete" | "d", []) => {
+                self.breakpoints.clear();
+                writeln!(out, "deleted every breakpoint")?;
+            }
+            ("delete" | "d", [idx]) => {
+                let idx = idx.parse::<usize>().context("invalid breakpoint")?;
+                if idx == 0 || idx > self.breakpoints.len() {
+                    bail!("there is no breakpoint {idx}");
+                }
+                let bp = self.breakpoints.remove(idx - 1);
+                writeln!(out, "deleted breakpoint {idx}: {bp}")?;
+            }
+            ("info" | "i", []) => {
+                for (idx, bp) in self.breakpoints.iter().enumerate() {
+                    writeln!(out, "breakpoint {}: {bp}", idx + 1)?;
+                }
+                writeln!(
+                    out,
+                    "state:    {}",
+                    self.session.engine.current_state().get()
+                )?;
+                writeln!(out, "steps:    {}", self.session.engine.cycles())?;
+                writeln!(out, "head:     {}", self.session.position())?;
+                writeln!(out, "history:  {}", self.history.len())?;
+            }
+            ("print" | "p", []) => writeln!(out, "{}", self.window(DEFAULT_DISPLAY_RADIUS))?,
+            ("print" | "p", [radius]) => {
+                let radius = radius.parse().context("invalid radius")?;
+                writeln!(out, "{}", self.window(radius))?;
+            }
+            ("rule" | "r", []) => writeln!(out, "{}", self.rule())?,
+            ("write" | "w", [symbol]) => self.write(0, symbol, out)?,
+            ("write" | "w", [offset, symbol]) => {
+                let offset = offset.parse().context("invalid offset")?;
+                self.write(offset, symbol, out)?;
+            }
+            ("reload", []) => {
+                let program = format::load(&self.args.load.program, self.args.load.format)?;
+                self.session.engine.set_program(program);
+                writeln!(out, "reloaded {}", self.args.load.program.display())?;
+            }
+            ("restart", []) => {
+                let program = self.program().clone();
+                self.session = Session::new(program, self.state.clone(), self.input.clone());
+                self.history.clear();
+                self.status(out)?;
+            }
+            ("help" | "h" | "?", []) => writeln!(out, "{HELP}")?,
+            ("quit" | "q", []) => return Ok(false),
+            _ => bail!("unrecognized command `{}`; try `help`", line.trim()),
+        }
+        Ok(true)
+    }
+    /// registers the breakpoint, reporting its index
+    fn add_breakpoint(&mut self, bp: Breakpoint, out: &mut impl Write) -> std::io::Result<()> {
+        writeln!(out, "breakpoint {}: {bp}", self.breakpoints.len() + 1)?;
+        self.breakpoints.push(bp);
+        Ok(())
+    }
+    /// writes the symbol onto the tape before printing the surrounding window
+    fn write(&mut self, offset: isize, symbol: &str, out: &mut impl Write) -> anyhow::Result<()> {
+        let symbol = parse_symbol(symbol)?;
+        self.checkpoint();
+        self.session.write(offset, symbol);
+        writeln!(out, "{}", self.window(DEFAULT_DISPLAY_RADIUS))?;
+        Ok(())
+    }
+}
+
+/// parses an optional number of steps, defaulting to one
+fn parse_count(args: &[&str]) -> anyhow::Result<usize> {
+    match args {
+        [] => Ok(1),
+        [n] => n.parse().context("invalid number of steps"),
+        _ => bail!("expected at most one number of steps"),
+    }
+}
+
+/// parses a single symbol, where `_` denotes a blank
+fn parse_symbol(input: &str) -> anyhow::Result<char> {
+    let mut chars = input.chars();
+    match (chars.next(), chars.next()) {
+        (Some(machine::BLANK), None) => Ok(char::default()),
+        (Some(c), None) => Ok(c),
+        _ => bail!("expected a single symbol, found `{input}`"),
+    }
+}
+
+/// starts an interactive debugging session, reading commands from stdin
+pub fn debug(args: &DebugArgs) -> anyhow::Result<ExitCode> {
+    if args.load.program.as_os_str() == "-" {
+        bail!("the debugger reads commands from stdin; the program must be loaded from a file");
+    }
+    let mut debugger = Debugger::new(args)?;
+    let interactive = std::io::stdin().is_terminal();
+    let mut stdout = std::io::stdout().lock();
+    debugger.status(&mut stdout)?;
+    let mut lines = std::io::stdin().lock().lines();
+    let mut previous = String::new();
+    loop {
+        if interactive {
+            write!(stdout, "(rstm) ")?;
+            stdout.flush()?;
+        }
+        let Some(line) = lines.next().transpose()? else {
+            break;
+        };
+        // an empty line repeats the previous command
+        let line = if line.trim().is_empty() {
+            previous.clone()
+        } else {
+            line
+        };
+        match debugger.execute(&line, &mut stdout) {
+            Ok(true) => previous = line,
+            Ok(false) => break,
+            Err(err) => writeln!(stdout, "error: {err:#}")?,
+        }
+    }
+    Ok(ExitCode::SUCCESS)
+}
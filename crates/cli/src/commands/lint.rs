@@ -0,0 +1,132 @@
+/*
+    Appellation: lint <module>
+    Created At: 2026.10.19:17:08:26
+    Contrib: @FL03
+*/
+use crate::cli::LoadArgs;
+use crate::format::{self, Machine};
+use crate::machine::{self, Halting};
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::process::ExitCode;
+
+/// the severity of a diagnostic
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Level {
+    Error,
+    Warning,
+    Note,
+}
+
+impl core::fmt::Display for Level {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::Error => "error",
+            Self::Warning => "warning",
+            Self::Note => "note",
+        })
+    }
+}
+
+/// validates the program, reporting any errors or warnings
+pub fn lint(args: &LoadArgs) -> anyhow::Result<ExitCode> {
+    let program = format::load(&args.program, args.format)?;
+    let diagnostics = diagnose(&program);
+    for (level, message) in &diagnostics {
+        println!("{level}: {message}");
+    }
+    let count = |lvl| diagnostics.iter().filter(|(l, _)| *l == lvl).count();
+    let (errors, warnings) = (count(Level::Error), count(Level::Warning));
+    println!(
+        "{}: {errors} error(s), {warnings} warning(s)",
+        args.program.display()
+    );
+    Ok(if errors > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    })
+}
+
+/// returns the diagnostics of the given program, ordered by their severity
+pub fn diagnose(program: &Machine) -> Vec<(Level, String)> {
+    let mut diagnostics = Vec::new();
+    if program.is_empty() {
+        diagnostics.push((Level::Error, "the program does not define any rules".into()));
+        return diagnostics;
+    }
+    // detect any duplicate or conflicting rules
+    let mut heads = BTreeMap::new();
+    for rule in program.iter() {
+        let head = (rule.state().get(), *rule.symbol());
+        match heads.insert(head, rule.tail()) {
+            Some(prev) if prev == rule.tail() => diagnostics.push((
+                Level::Warning,
+                format!(
+                    "the rule for ({}, {}) is defined more than once",
+                    head.0,
+                    machine::display_symbol(head.1)
+                ),
+            )),
+            Some(_) => diagnostics.push((
+                Level::Error,
+                format!(
+                    "conflicting rules for ({}, {})",
+                    head.0,
+                    machine::display_symbol(head.1)
+                ),
+            )),
+            None => {}
+        }
+    }
+    let halting = Halting::new(program);
+    let initial = match program.initial_state() {
+        Some(state) => state.get().as_str(),
+        None => {
+            diagnostics.push((
+                Level::Warning,
+                "no initial state was specified; the state of the first rule will be used".into(),
+            ));
+            program.iter().next().unwrap().state().get().as_str()
+        }
+    };
+    if halting.is_halting(initial) {
+        diagnostics.push((
+            Level::Warning,
+            format!("the initial state `{initial}` halts immediately"),
+        ));
+    }
+    // detect any states unreachable from the initial state
+    let mut reachable = BTreeSet::from([initial]);
+    let mut queue = VecDeque::from([initial]);
+    while let Some(state) = queue.pop_front() {
+        for rule in program.iter().filter(|r| r.state().get() == state) {
+            let next = rule.next_state().get().as_str();
+            if reachable.insert(next) {
+                queue.push_back(next);
+            }
+        }
+    }
+    for state in halting.active().filter(|state| !reachable.contains(state)) {
+        diagnostics.push((
+            Level::Warning,
+            format!("the state `{state}` is unreachable from the initial state"),
+        ));
+    }
+    // report the halting states of the program
+    let declared = program.halt_states().iter().map(|(state, _)| state);
+    let halting = program
+        .iter()
+        .map(|rule| rule.next_state())
+        .chain(declared)
+        .map(|state| state.get().as_str())
+        .filter(|state| halting.is_halting(state))
+        .collect::<BTreeSet<_>>();
+    if halting.is_empty() {
+        diagnostics.push((Level::Note, "the program has no halting states".into()));
+    } else {
+        let halting = halting.into_iter().collect::<Vec<_>>().join(", ");
+        diagnostics.push((Level::Note, format!("halting states: {halting}")));
+    }
+    diagnostics.sort_by_key(|(level, _)| *level);
+    diagnostics
+}
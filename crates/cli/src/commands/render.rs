@@ -0,0 +1,112 @@
+/*
+    Appellation: render <module>
+    Created At: 2026.10.19:17:19:52
+    Contrib: @FL03
+*/
+use crate::cli::{Diagram, RenderArgs};
+use crate::format::{self, Machine};
+use crate::machine::{self, Halting};
+use anyhow::Context;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::process::ExitCode;
+
+/// renders the state diagram of the program
+pub fn render(args: &RenderArgs) -> anyhow::Result<ExitCode> {
+    let program = format::load(&args.load.program, args.load.format)?;
+    let output = match args.diagram {
+        Diagram::Dot => to_dot(&program)?,
+        Diagram::Mermaid => to_mermaid(&program)?,
+    };
+    match &args.output {
+        Some(path) => std::fs::write(path, output)
+            .with_context(|| format!("unable to write {}", path.display()))?,
+        None => print!("{output}"),
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// the labels of the transitions between two states, keyed by their source and target
+type Edges = BTreeMap<(usize, usize), Vec<String>>;
+
+/// returns the states of the program in order of their appearance alongside the labels of
+/// the transitions between them, grouped by their source and target.
+fn transitions(program: &Machine) -> (Vec<&str>, Edges) {
+    let mut states = Vec::new();
+    let mut edges = Edges::new();
+    let mut lookup = |state| match states.iter().position(|s| *s == state) {
+        Some(idx) => idx,
+        None => {
+            states.push(state);
+            states.len() - 1
+        }
+    };
+    let mut pairs = Vec::new();
+    if let Some(initial) = program.initial_state() {
+        lookup(initial.get().as_str());
+    }
+    for rule in program.iter() {
+        let from = lookup(rule.state().get().as_str());
+        let to = lookup(rule.next_state().get().as_str());
+        pairs.push((from, to, rule));
+    }
+    for (from, to, rule) in pairs {
+        edges.entry((from, to)).or_default().push(format!(
+            "{}/{},{}",
+            machine::display_symbol(*rule.symbol()),
+            machine::display_symbol(*rule.write_symbol()),
+            rule.direction().as_char(),
+        ));
+    }
+    (states, edges)
+}
+
+/// renders the program as a Graphviz (DOT) digraph
+fn to_dot(program: &Machine) -> anyhow::Result<String> {
+    let (states, edges) = transitions(program);
+    let halting = Halting::new(program);
+    let mut buf = String::new();
+    writeln!(buf, "digraph machine {{")?;
+    writeln!(buf, "    rankdir=LR;")?;
+    writeln!(buf, "    node [shape=circle];")?;
+    writeln!(buf, "    __start [shape=point];")?;
+    for (idx, state) in states.iter().enumerate() {
+        let shape = if halting.is_halting(state) {
+            "doublecircle"
+        } else {
+            "circle"
+        };
+        writeln!(buf, "    s{idx} [label={state:?}, shape={shape}];")?;
+    }
+    if !states.is_empty() {
+        writeln!(buf, "    __start -> s0;")?;
+    }
+    for ((from, to), labels) in &edges {
+        writeln!(buf, "    s{from} -> s{to} [label={:?}];", labels.join("\n"))?;
+    }
+    writeln!(buf, "}}")?;
+    Ok(buf)
+}
+
+/// renders the program as a Mermaid state diagram
+fn to_mermaid(program: &Machine) -> anyhow::Result<String> {
+    let (states, edges) = transitions(program);
+    let halting = Halting::new(program);
+    let mut buf = String::new();
+    writeln!(buf, "stateDiagram-v2")?;
+    for (idx, state) in states.iter().enumerate() {
+        writeln!(buf, "    state {:?} as s{idx}", state.replace('"', "'"))?;
+    }
+    if !states.is_empty() {
+        writeln!(buf, "    [*] --> s0")?;
+    }
+    for ((from, to), labels) in &edges {
+        writeln!(buf, "    s{from} --> s{to}: {}", labels.join("<br>"))?;
+    }
+    for (idx, state) in states.iter().enumerate() {
+        if halting.is_halting(state) {
+            writeln!(buf, "    s{idx} --> [*]")?;
+        }
+    }
+    Ok(buf)
+}
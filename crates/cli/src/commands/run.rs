@@ -0,0 +1,55 @@
+/*
+    Appellation: run <module>
+    Created At: 2026.10.19:16:57:18
+    Contrib: @FL03
+*/
+use crate::cli::RunArgs;
+use crate::format;
+use crate::machine::{self, Outcome};
+use rstm_core::actors::Configuration;
+use std::io::{IsTerminal, Read};
+use std::process::ExitCode;
+
+/// the exit code used whenever the step limit is exceeded
+const EXIT_EXCEEDED: u8 = 2;
+
+/// runs the program, optionally printing the configuration after every step
+pub fn run(args: &RunArgs, trace: bool) -> anyhow::Result<ExitCode> {
+    let program = format::load(&args.load.program, args.load.format)?;
+    let state = match &args.state {
+        Some(state) => state.clone(),
+        None => machine::initial_state(&program)?,
+    };
+    let input = match &args.input {
+        Some(input) => input.clone(),
+        None if args.load.program.as_os_str() != "-" && !std::io::stdin().is_terminal() => {
+            let mut buf = String::new();
+            std::io::stdin().read_to_string(&mut buf)?;
+            buf
+        }
+        None => String::new(),
+    };
+    let mut config = Configuration::from_input(state, machine::parse_tape(&input));
+    let outcome = machine::execute(&program, &mut config, args.max_steps, |config| {
+        if trace {
+            println!(
+                "{:>6}  {:<12} {}",
+                config.steps(),
+                config.state().get(),
+                machine::display_tape(config, true)
+            );
+        }
+    });
+    if trace {
+        println!();
+    }
+    println!("outcome: {outcome}");
+    println!("state:   {}", config.state().get());
+    println!("steps:   {}", config.steps());
+    println!("head:    {}", config.position());
+    println!("tape:    {}", machine::display_tape(&config, false));
+    Ok(match outcome {
+        Outcome::Exceeded => ExitCode::from(EXIT_EXCEEDED),
+        _ => ExitCode::SUCCESS,
+    })
+}
@@ -0,0 +1,234 @@
+/*
+    Appellation: format <module>
+    Created At: 2026.10.19:16:31:07
+    Contrib: @FL03
+*/
+use crate::machine::Halting;
+use anyhow::{Context, bail};
+use rstm_core::formats::{self, JflapMachine};
+use rstm_core::programs::{CANONICAL_HALT, ProgramFormat};
+use rstm_core::{Program, Rule};
+use std::io::Read;
+use std::path::Path;
+
+/// the type of program used throughout the command-line interface
+pub type Machine = Program<String, char>;
+
+/// the formats supported by the command-line interface
+#[derive(Clone, Copy, Debug, Eq, PartialEq, clap::ValueEnum)]
+pub enum Format {
+    Json,
+    Yaml,
+    Toml,
+    Ron,
+    /// the line-based format used by online simulators
+    Tm,
+    /// the XML-based format used by JFLAP
+    Jflap,
+    /// the compact format used by the busy beaver community (e.g. `1RB1LB_1LA1RZ`)
+    Compact,
+    /// the notation of the `program!` macro
+    Notation,
+}
+
+impl Format {
+    /// returns the format associated with the extension of the given path, if any
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let ext = path.extension()?.to_str()?.to_ascii_lowercase();
+        let format = match ext.as_str() {
+            "tm" => Self::Tm,
+            "jff" => Self::Jflap,
+            "bb" => Self::Compact,
+            "rstm" => Self::Notation,
+            ext => match ProgramFormat::from_extension(ext)? {
+                ProgramFormat::Json => Self::Json,
+                ProgramFormat::Yaml => Self::Yaml,
+                ProgramFormat::Toml => Self::Toml,
+                ProgramFormat::Ron => Self::Ron,
+            },
+        };
+        Some(format)
+    }
+    /// returns the explicit format, falling back onto the one inferred from the path
+    pub fn resolve(format: Option<Self>, path: &Path) -> anyhow::Result<Self> {
+        format.or_else(|| Self::from_path(path)).with_context(|| {
+            format!(
+                "unable to infer the format of {}; consider specifying one with `--format`",
+                path.display()
+            )
+        })
+    }
+    /// returns the corresponding serialization format, if any
+    const fn serde(self) -> Option<ProgramFormat> {
+        match self {
+            Self::Json => Some(ProgramFormat::Json),
+            Self::Yaml => Some(ProgramFormat::Yaml),
+            Self::Toml => Some(ProgramFormat::Toml),
+            Self::Ron => Some(ProgramFormat::Ron),
+            _ => None,
+        }
+    }
+}
+
+/// loads a program from the given path, where `-` denotes stdin
+pub fn load(path: &Path, format: Option<Format>) -> anyhow::Result<Machine> {
+    let format = Format::resolve(format, path)?;
+    let input = if path == Path::new("-") {
+        let mut buf = String::new();
+        std::io::stdin().read_to_string(&mut buf)?;
+        buf
+    } else {
+        std::fs::read_to_string(path)
+            .with_context(|| format!("unable to read {}", path.display()))?
+    };
+    parse(&input, format).with_context(|| format!("unable to load {}", path.display()))
+}
+
+/// parses a program written in the given format
+pub fn parse(input: &str, format: Format) -> anyhow::Result<Machine> {
+    if let Some(format) = format.serde() {
+        return Ok(Machine::deserialize_as(input, format)?);
+    }
+    let program = match format {
+        Format::Tm => formats::parse_tm(input, &[])?,
+        Format::Jflap => JflapMachine::from_xml(input)?.to_program()?,
+        Format::Compact => from_compact(&formats::parse_compact(input)?),
+        Format::Notation => formats::parse_program(input)?,
+        _ => unreachable!("serialization formats are handled above"),
+    };
+    Ok(program)
+}
+
+/// writes the program using the given format
+pub fn emit(program: &Machine, format: Format) -> anyhow::Result<String> {
+    if let Some(format) = format.serde() {
+        return Ok(program.serialize_as(format)?);
+    }
+    let output = match format {
+        Format::Tm => formats::to_tm(program)?,
+        Format::Jflap => JflapMachine::from_program(program).to_xml(),
+        Format::Compact => format!("{}\n", to_compact(program)?.to_compact()?),
+        Format::Notation => to_notation(program),
+        _ => unreachable!("serialization formats are handled above"),
+    };
+    Ok(output)
+}
+
+/// converts a program written in the compact format, naming the states `A` through `Y` (with
+/// `Z` halting) while the symbol `0` is mapped onto the blank.
+fn from_compact(program: &Program<usize, usize>) -> Machine {
+    let state = |q: usize| match q {
+        CANONICAL_HALT => formats::COMPACT_HALT.to_string(),
+        q => char::from(b'A' + q as u8).to_string(),
+    };
+    let symbol = |a: usize| match a {
+        0 => char::default(),
+        a => char::from_digit(a as u32, 10).expect("compact symbols are single digits"),
+    };
+    program
+        .iter()
+        .map(|rule| {
+            Rule::from_parts(
+                state(*rule.state().get()),
+                symbol(*rule.symbol()),
+                rule.direction(),
+                state(*rule.next_state().get()),
+                symbol(*rule.write_symbol()),
+            )
+        })
+        .collect::<Machine>()
+        .with_default_state(state(0))
+}
+
+/// converts the program into the compact format, numbering the states in order of their
+/// appearance (beginning with the initial state) and mapping the blank onto `0`. States that
+/// do not define any rules are treated as halting.
+fn to_compact(program: &Machine) -> anyhow::Result<Program<usize, usize>> {
+    let halting = Halting::new(program);
+    let mut states = Vec::new();
+    // the compact format always begins in the first state, which cannot halt immediately
+    if let Some(initial) = program.initial_state() {
+        let initial = initial.get().as_str();
+        if halting.is_halting(initial) {
+            bail!(
+                "the initial state `{initial}` halts immediately and cannot be represented by the compact format"
+            );
+        }
+        states.push(initial);
+    }
+    // the rules of any declared halting states are never applied
+    let rules = program
+        .iter()
+        .filter(|rule| !halting.is_halting(rule.state().get()))
+        .collect::<Vec<_>>();
+    for rule in &rules {
+        if !states.contains(&rule.state().get().as_str()) {
+            states.push(rule.state().get().as_str());
+        }
+    }
+    let state = |q: &str| match states.iter().position(|s| *s == q) {
+        Some(idx) if !halting.is_halting(q) => idx,
+        _ => CANONICAL_HALT,
+    };
+    let symbol = |a: char| match a {
+        c if c == char::default() => Ok(0),
+        c => match c.to_digit(10) {
+            Some(d) if d > 0 => Ok(d as usize),
+            _ => bail!("the symbol {c:?} cannot be represented by the compact format"),
+        },
+    };
+    let rules = rules
+        .into_iter()
+        .map(|rule| {
+            Ok(Rule::from_parts(
+                state(rule.state().get()),
+                symbol(*rule.symbol())?,
+                rule.direction(),
+                state(rule.next_state().get()),
+                symbol(*rule.write_symbol())?,
+            ))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    if states.len() > formats::COMPACT_MAX_STATES {
+        bail!("the program has too many states to be represented by the compact format");
+    }
+    Ok(Program::from_rules(rules).with_default_state(0))
+}
+
+/// writes the program using the notation of the `program!` macro
+fn to_notation(program: &Machine) -> String {
+    use std::fmt::Write;
+
+    let mut buf = String::new();
+    if let Some(initial) = program.initial_state() {
+        let _ = writeln!(buf, "#[default_state({})]", quote_state(initial.get()));
+    }
+    buf.push_str("rules: {\n");
+    for rule in program.iter() {
+        let _ = writeln!(
+            buf,
+            "    ({}, {:?}) -> {:?}({}, {:?}),",
+            quote_state(rule.state().get()),
+            rule.symbol(),
+            rule.direction(),
+            quote_state(rule.next_state().get()),
+            rule.write_symbol(),
+        );
+    }
+    buf.push_str("};\n");
+    buf
+}
+
+/// quotes the state whenever it cannot be written as a bare token
+fn quote_state(state: &str) -> String {
+    let bare = !state.is_empty()
+        && state
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.'))
+        && !state.contains("->");
+    if bare {
+        state.to_string()
+    } else {
+        format!("{state:?}")
+    }
+}
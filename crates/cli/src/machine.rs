@@ -0,0 +1,153 @@
+/*
+    Appellation: machine <module>
+    Created At: 2026.10.19:16:48:33
+    Contrib: @FL03
+*/
+use crate::format::Machine;
+use anyhow::Context;
+use rstm_core::HaltKind;
+use rstm_core::actors::Configuration;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// the character used to display the blank symbol
+pub const BLANK: char = '_';
+
+/// the reason a run came to an end
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Outcome {
+    /// the machine entered a halting state
+    Halted(HaltKind),
+    /// the machine is in a state lacking a rule for the symbol under the head
+    NoTransition,
+    /// the machine exceeded the maximum number of steps
+    Exceeded,
+}
+
+impl core::fmt::Display for Outcome {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::Halted(HaltKind::Halt) => "halted",
+            Self::Halted(HaltKind::Accept) => "accepted",
+            Self::Halted(HaltKind::Reject) => "rejected",
+            Self::NoTransition => "no transition",
+            Self::Exceeded => "step limit exceeded",
+        })
+    }
+}
+
+/// returns the initial state of the program, falling back onto the state of its first rule
+pub fn initial_state(program: &Machine) -> anyhow::Result<String> {
+    program
+        .initial_state()
+        .or_else(|| program.iter().next().map(|rule| rule.state()))
+        .map(|state| state.get().clone())
+        .context("the program does not define any rules")
+}
+
+/// [`Halting`] decides in which states a program halts; the halting states declared by the
+/// program take precedence, while any other state that does not define any rules simply halts.
+pub struct Halting<'a> {
+    declared: BTreeMap<&'a str, HaltKind>,
+    active: BTreeSet<&'a str>,
+}
+
+impl<'a> Halting<'a> {
+    pub fn new(program: &'a Machine) -> Self {
+        let declared = program
+            .halt_states()
+            .iter()
+            .map(|(state, kind)| (state.get().as_str(), kind))
+            .collect();
+        let active = program
+            .iter()
+            .map(|rule| rule.state().get().as_str())
+            .collect();
+        Self { declared, active }
+    }
+    /// returns true if the state defines at least one rule
+    pub fn is_active(&self, state: &str) -> bool {
+        self.active.contains(state)
+    }
+    /// returns true if the machine halts upon entering the state
+    pub fn is_halting(&self, state: &str) -> bool {
+        self.kind(state).is_some()
+    }
+    /// returns the kind of halt for the given state, if it halts at all
+    pub fn kind(&self, state: &str) -> Option<HaltKind> {
+        match self.declared.get(state) {
+            Some(&kind) => Some(kind),
+            None if !self.is_active(state) => Some(HaltKind::Halt),
+            None => None,
+        }
+    }
+    /// returns an iterator over the states defining at least one rule
+    pub fn active(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.active.iter().copied()
+    }
+}
+
+/// parses the contents of a tape, where `_` denotes a blank
+pub fn parse_tape(input: &str) -> Vec<char> {
+    input
+        .trim()
+        .chars()
+        .map(|c| if c == BLANK { char::default() } else { c })
+        .collect()
+}
+
+/// displays a single symbol, substituting the blank
+pub fn display_symbol(symbol: char) -> char {
+    if symbol == char::default() {
+        BLANK
+    } else {
+        symbol
+    }
+}
+
+/// displays the visited portion of the tape, optionally surrounding the symbol under the head
+/// with brackets; otherwise, any leading or trailing blanks are trimmed.
+pub fn display_tape(config: &Configuration<String, char>, head: bool) -> String {
+    let mut buf = String::new();
+    for (idx, &symbol) in config.tape().iter().enumerate() {
+        let symbol = display_symbol(symbol);
+        if head && idx == config.head_index() {
+            buf.push('[');
+            buf.push(symbol);
+            buf.push(']');
+        } else {
+            buf.push(symbol);
+        }
+    }
+    if head {
+        buf
+    } else {
+        buf.trim_matches(BLANK).to_string()
+    }
+}
+
+/// runs the program until it halts, gets stuck, or exceeds the given number of steps; the
+/// callback is invoked with the initial configuration and after every step.
+pub fn execute<F>(
+    program: &Machine,
+    config: &mut Configuration<String, char>,
+    max_steps: usize,
+    mut callback: F,
+) -> Outcome
+where
+    F: FnMut(&Configuration<String, char>),
+{
+    let halting = Halting::new(program);
+    callback(config);
+    loop {
+        if let Some(kind) = halting.kind(config.state().get()) {
+            return Outcome::Halted(kind);
+        }
+        if config.steps() >= max_steps {
+            return Outcome::Exceeded;
+        }
+        if config.step(program).is_err() {
+            return Outcome::NoTransition;
+        }
+        callback(config);
+    }
+}
@@ -0,0 +1,56 @@
+/*
+    Appellation: rstm <binary>
+    Created At: 2026.10.19:16:20:14
+    Contrib: @FL03
+*/
+//! The `rstm` command-line interface enables programs to be run, traced, debugged, validated,
+//! converted between formats and rendered as diagrams without writing any Rust.
+//!
+//! Programs are loaded as `Program<String, char>`, with the format being inferred from the
+//! extension of the file unless explicitly specified:
+//!
+//! | format     | extension              |
+//! |------------|------------------------|
+//! | `json`     | `.json`                |
+//! | `yaml`     | `.yaml`, `.yml`        |
+//! | `toml`     | `.toml`                |
+//! | `ron`      | `.ron`                 |
+//! | `tm`       | `.tm`                  |
+//! | `jflap`    | `.jff`                 |
+//! | `compact`  | `.bb`                  |
+//! | `notation` | `.rstm`                |
+//!
+//! A machine halts upon entering any of the halting states declared by its program (e.g. the
+//! final states of a JFLAP machine), reporting whether it accepted or rejected its input, or
+//! any other state that does not define any rules of its own.
+mod cli;
+mod format;
+mod machine;
+
+mod commands {
+    pub mod convert;
+    pub mod debug;
+    pub mod lint;
+    pub mod render;
+    pub mod run;
+}
+
+use self::cli::{Cli, Command};
+use clap::Parser;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let result = match cli.command {
+        Command::Run(args) => commands::run::run(&args, false),
+        Command::Trace(args) => commands::run::run(&args, true),
+        Command::Debug(args) => commands::debug::debug(&args),
+        Command::Lint(args) => commands::lint::lint(&args),
+        Command::Convert(args) => commands::convert::convert(&args),
+        Command::Render(args) => commands::render::render(&args),
+    };
+    result.unwrap_or_else(|err| {
+        eprintln!("error: {err:#}");
+        ExitCode::FAILURE
+    })
+}
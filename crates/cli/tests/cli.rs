@@ -0,0 +1,247 @@
+/*
+    Appellation: cli <tests>
+    Created At: 2026.10.19:17:41:05
+    Contrib: @FL03
+*/
+use std::path::PathBuf;
+use std::process::{Command, Output};
+
+/// the two-state, two-symbol busy beaver champion
+const BB2: &str = "1RB1LB_1LA1RZ";
+
+/// writes the given contents to a uniquely named file within the temporary directory
+fn fixture(name: &str, contents: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("rstm-cli-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join(name);
+    std::fs::write(&path, contents).unwrap();
+    path
+}
+
+fn rstm(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_rstm"))
+        .args(args)
+        .output()
+        .expect("failed to execute the binary")
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+#[test]
+fn test_run() {
+    let path = fixture("run.bb", BB2);
+    let output = rstm(&["run", path.to_str().unwrap(), "--input", ""]);
+    assert! { output.status.success() }
+    let out = stdout(&output);
+    assert! { out.contains("outcome: halted") }
+    assert! { out.contains("steps:   6") }
+    assert! { out.contains("tape:    1111") }
+    // exceeding the step limit results in a distinct exit code
+    let output = rstm(&["run", path.to_str().unwrap(), "-i", "", "-n", "3"]);
+    assert_eq! { output.status.code(), Some(2) }
+    assert! { stdout(&output).contains("outcome: step limit exceeded") }
+}
+
+#[test]
+fn test_run_declared_halt_states() {
+    // the `halt-accept` state of the `.tm` format accepts its input
+    let path = fixture("accept.tm", "0 1 1 r 0\n0 _ _ l halt-accept\n");
+    let output = rstm(&["run", path.to_str().unwrap(), "--input", "11"]);
+    assert! { output.status.success() }
+    assert! { stdout(&output).contains("outcome: accepted") }
+    // declared halting states halt even if they define rules
+    let path = fixture("halt.tm", "0 _ 1 r halt\nhalt _ 1 r 0\n");
+    let output = rstm(&["run", path.to_str().unwrap(), "--input", ""]);
+    let out = stdout(&output);
+    assert! { out.contains("outcome: halted") }
+    assert! { out.contains("steps:   1") }
+    let output = rstm(&["lint", path.to_str().unwrap()]);
+    assert! { stdout(&output).contains("note: halting states: halt") }
+}
+
+#[test]
+fn test_trace() {
+    let path = fixture("trace.tm", "0 _ 1 r 1\n1 _ 1 l halt\n");
+    let output = rstm(&["trace", path.to_str().unwrap(), "--input", "_"]);
+    assert! { output.status.success() }
+    let out = stdout(&output);
+    assert! { out.contains("0  0            [_]") }
+    assert! { out.contains("1  1            1[_]") }
+    assert! { out.contains("2  halt         [1]1") }
+}
+
+#[test]
+fn test_convert_roundtrip() {
+    let path = fixture("convert.bb", BB2);
+    for ext in ["json", "yaml", "toml", "ron", "tm", "jff", "rstm"] {
+        let converted = path.with_extension(ext);
+        let output = rstm(&[
+            "convert",
+            path.to_str().unwrap(),
+            "-o",
+            converted.to_str().unwrap(),
+        ]);
+        assert! { output.status.success(), "failed to convert into {ext}" }
+        let output = rstm(&["convert", converted.to_str().unwrap(), "--to", "compact"]);
+        assert! { output.status.success(), "failed to convert from {ext}" }
+        assert_eq! { stdout(&output).trim(), BB2, "failed to round-trip {ext}" }
+    }
+}
+
+#[test]
+fn test_convert_halting_initial_state() {
+    // the initial state halts immediately, which the compact format cannot express
+    let path = fixture(
+        "halting.rstm",
+        "#[default_state(h)]\nrules: { (a, '1') -> Right(h, '1'), };\n",
+    );
+    let output = rstm(&["convert", path.to_str().unwrap(), "--to", "compact"]);
+    assert! { !output.status.success() }
+    assert! { String::from_utf8_lossy(&output.stderr).contains("initial state `h`") }
+}
+
+#[test]
+fn test_convert_notation_escapes() {
+    // symbols without a printable form are written using escapes the notation accepts
+    let contents = "#[default_state(a)]\nrules: { (a, '\\u{7f}') -> Right(b, '\\u{301}'), };\n";
+    let path = fixture("escapes.rstm", contents);
+    let converted = path.with_extension("escaped.rstm");
+    let output = rstm(&[
+        "convert",
+        path.to_str().unwrap(),
+        "-o",
+        converted.to_str().unwrap(),
+    ]);
+    assert! { output.status.success() }
+    let written = std::fs::read_to_string(&converted).unwrap();
+    assert! { written.contains(r"'\u{7f}'") }
+    let output = rstm(&["convert", converted.to_str().unwrap(), "--to", "notation"]);
+    assert! { output.status.success() }
+    assert_eq! { stdout(&output), written }
+}
+
+#[test]
+fn test_lint() {
+    let path = fixture("valid.tm", "0 _ 1 r 1\n1 _ 1 l halt\n2 _ _ r 0\n");
+    let output = rstm(&["lint", path.to_str().unwrap()]);
+    assert! { output.status.success() }
+    let out = stdout(&output);
+    assert! { out.contains("warning: the state `2` is unreachable from the initial state") }
+    assert! { out.contains("note: halting states: halt") }
+    // conflicting rules are reported as errors
+    let path = fixture(
+        "invalid.rstm",
+        "(a, 'x') -> Right(b, 'y'),\n(a, 'x') -> Left(b, 'y')",
+    );
+    let output = rstm(&["lint", path.to_str().unwrap()]);
+    assert! { !output.status.success() }
+}
+
+#[test]
+fn test_render() {
+    let path = fixture("render.bb", BB2);
+    let output = rstm(&["render", path.to_str().unwrap()]);
+    assert! { output.status.success() }
+    let out = stdout(&output);
+    assert! { out.starts_with("digraph machine {") }
+    assert! { out.contains(r#"s2 [label="Z", shape=doublecircle];"#) }
+    assert! { out.contains(r#"s0 -> s1 [label="_/1,R\n1/1,L"];"#) }
+
+    let output = rstm(&["render", path.to_str().unwrap(), "--diagram", "mermaid"]);
+    let out = stdout(&output);
+    assert! { out.starts_with("stateDiagram-v2") }
+    assert! { out.contains("s2 --> [*]") }
+}
+
+#[test]
+fn test_unknown_format() {
+    let path = fixture("program.txt", BB2);
+    let output = rstm(&["run", path.to_str().unwrap()]);
+    assert! { !output.status.success() }
+    assert! { String::from_utf8_lossy(&output.stderr).contains("unable to infer the format") }
+}
+
+#[test]
+fn test_debug() {
+    use std::io::Write;
+    use std::process::Stdio;
+
+    let path = fixture("debug.bb", BB2);
+    let mut child = Command::new(env!("CARGO_BIN_EXE_rstm"))
+        .args(["debug", path.to_str().unwrap()])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let script = "rule\ns\n\nbr state B\nc\nb 2\nw x\nback\nd\nc\nq\n";
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(script.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert! { output.status.success() }
+    let out = stdout(&output);
+    let lines = out.lines().collect::<Vec<_>>();
+    assert_eq! {
+        lines,
+        [
+            "     0  A            [[_]]",
+            "(A, _) -> Right(B, 1)",
+            "     1  B            1[[_]]",
+            // an empty line repeats the previous command
+            "     2  A            [[1]]1",
+            "breakpoint 1: state `B`",
+            "breakpoint 1 hit: state `B`",
+            "     3  B            [[_]]11",
+            "     1  B            1[[_]]",
+            "1[[x]]",
+            "     1  B            1[[_]]",
+            "deleted every breakpoint",
+            "the machine has stopped: halted",
+            "     6  Z            11[[1]]1",
+        ]
+    }
+}
+
+#[test]
+fn test_debug_reload() {
+    use std::io::{BufRead, BufReader, Write};
+    use std::process::Stdio;
+
+    let path = fixture("reload.bb", BB2);
+    let mut child = Command::new(env!("CARGO_BIN_EXE_rstm"))
+        .args(["debug", path.to_str().unwrap()])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let mut stdin = child.stdin.take().unwrap();
+    let mut stdout = BufReader::new(child.stdout.take().unwrap());
+    stdin.write_all(b"s\ns\n").unwrap();
+    stdin.flush().unwrap();
+    // wait for both steps to complete before changing the program
+    let mut lines = Vec::new();
+    for _ in 0..3 {
+        let mut line = String::new();
+        stdout.read_line(&mut line).unwrap();
+        lines.push(line.trim_end().to_string());
+    }
+    assert_eq! { lines.last().unwrap(), "     2  A            [[1]]1" }
+    std::fs::write(&path, "1RB1LB_0LA1RZ").unwrap();
+    // stepping back after a reload restores the configuration, but not the previous program
+    stdin.write_all(b"reload\nback\nrule\nq\n").unwrap();
+    drop(stdin);
+    assert! { child.wait().unwrap().success() }
+    let rest = stdout.lines().map(Result::unwrap).collect::<Vec<_>>();
+    assert_eq! {
+        rest[1..],
+        [
+            "     1  B            1[[_]]",
+            "(B, _) -> Left(A, _)",
+        ]
+    }
+}
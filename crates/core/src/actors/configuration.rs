@@ -0,0 +1,177 @@
+/*
+    Appellation: configuration <module>
+    Created At: 2026.10.19:10:02:17
+    Contrib: @FL03
+*/
+//! This module provides the [`Configuration`] of a machine, a lightweight stepping model used
+//! wherever machines are executed as plain values rather than driven by an engine, i.e. by
+//! the deciders and enumerators of the [`search`](crate::search) module, the language and
+//! compute APIs of [`Program`], and the `run` command of the CLI.
+//!
+//! The [`EngineBase`](super::EngineBase) is not reused here since it owns the program it
+//! executes and is generic over its driver and tape store, neither of which may be compared
+//! or hashed. Configurations, on the other hand, borrow the program on every step and
+//! derive [`Eq`] and [`Hash`], allowing the deciders to record and compare millions of
+//! snapshots cheaply. Both share the same semantics: the symbol under the head is read, the
+//! matching tail is written, and the tape grows with blank cells in either direction as the
+//! head moves beyond it; the tests ensure they remain in agreement.
+use crate::programs::Program;
+use crate::rules::Tail;
+use alloc::collections::VecDeque;
+use rstm_state::{Halting, RawState, State};
+
+/// The [`Configuration`] captures the complete, instantaneous description of a Turing machine
+/// operating on a two-way infinite tape; i.e. the current state, the contents of the tape,
+/// and the position of the head.
+///
+/// Unlike the [`EngineBase`](super::EngineBase), the tape of a configuration grows in either
+/// direction as needed, filling any new cells with the _blank_ symbol (`A::default()`).
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct Configuration<Q, A> {
+    /// the current state of the machine
+    pub(crate) state: State<Q>,
+    /// the visited portion of the tape
+    pub(crate) tape: VecDeque<A>,
+    /// the index of the head within the stored tape
+    pub(crate) head: usize,
+    /// the position of the first stored cell relative to the origin of the tape
+    pub(crate) offset: isize,
+    /// the number of steps taken thus far
+    pub(crate) steps: usize,
+}
+
+impl<Q, A> Configuration<Q, A>
+where
+    Q: RawState,
+{
+    /// returns a new configuration in the given state with a blank tape
+    pub fn new(state: Q) -> Self
+    where
+        A: Default,
+    {
+        Self::from_input(state, [])
+    }
+    /// returns a new configuration in the given state with the head positioned over the first
+    /// symbol of the given input
+    pub fn from_input<I>(state: Q, input: I) -> Self
+    where
+        A: Default,
+        I: IntoIterator<Item = A>,
+    {
+        let mut tape = input.into_iter().collect::<VecDeque<A>>();
+        if tape.is_empty() {
+            tape.push_back(A::default());
+        }
+        Self {
+            state: State(state),
+            tape,
+            head: 0,
+            offset: 0,
+            steps: 0,
+        }
+    }
+    /// returns a reference to the current state
+    pub const fn state(&self) -> &State<Q> {
+        &self.state
+    }
+    /// returns a reference to the visited portion of the tape
+    pub const fn tape(&self) -> &VecDeque<A> {
+        &self.tape
+    }
+    /// returns the number of steps taken thus far
+    pub const fn steps(&self) -> usize {
+        self.steps
+    }
+    /// returns the position of the head relative to the origin of the tape
+    pub const fn position(&self) -> isize {
+        self.offset + self.head as isize
+    }
+    /// returns the position of the leftmost visited cell relative to the origin of the tape
+    pub const fn leftmost(&self) -> isize {
+        self.offset
+    }
+    /// returns the position of the rightmost visited cell relative to the origin of the tape
+    pub fn rightmost(&self) -> isize {
+        self.offset + self.tape.len() as isize - 1
+    }
+    /// returns the index of the head within the stored portion of the tape
+    pub const fn head_index(&self) -> usize {
+        self.head
+    }
+    /// returns a reference to the symbol stored at the given position, if it has been visited
+    pub fn get(&self, position: isize) -> Option<&A> {
+        usize::try_from(position - self.offset)
+            .ok()
+            .and_then(|idx| self.tape.get(idx))
+    }
+    /// returns a reference to the symbol currently under the head
+    pub fn read(&self) -> &A {
+        &self.tape[self.head]
+    }
+    /// returns the number of non-blank symbols on the tape
+    pub fn sigma(&self) -> usize
+    where
+        A: Default + PartialEq,
+    {
+        let blank = A::default();
+        self.tape.iter().filter(|&s| s != &blank).count()
+    }
+    /// returns true if the current state is considered to be halted
+    pub fn is_halted(&self) -> bool
+    where
+        Q: Halting,
+    {
+        self.state.is_halted()
+    }
+    /// apply the given tail to the configuration; writing the symbol, updating the state, and
+    /// moving the head before incrementing the step counter.
+    pub fn apply(&mut self, tail: &Tail<Q, A>)
+    where
+        A: Clone + Default,
+        Q: Clone,
+    {
+        self.tape[self.head] = tail.write_symbol.clone();
+        self.state = tail.next_state.clone();
+        self.shift(tail.direction);
+        self.steps += 1;
+    }
+    /// execute a single step of the given program, returning an error if no rule was found
+    /// for the current head
+    pub fn step(&mut self, program: &Program<Q, A>) -> crate::Result<()>
+    where
+        A: Clone + Default + PartialEq,
+        Q: Clone + PartialEq,
+    {
+        let tail = program
+            .find_tail(self.state.view(), self.read())
+            .ok_or(crate::Error::NoRuleFound)?
+            .clone();
+        self.apply(&tail);
+        Ok(())
+    }
+    /// move the head in the given direction, growing the tape with a blank cell if necessary
+    pub fn shift(&mut self, direction: crate::Direction)
+    where
+        A: Default,
+    {
+        match direction {
+            crate::Direction::Left if self.head == 0 => {
+                self.tape.push_front(A::default());
+                self.offset -= 1;
+            }
+            crate::Direction::Left => self.head -= 1,
+            crate::Direction::Right => {
+                self.head += 1;
+                if self.head == self.tape.len() {
+                    self.tape.push_back(A::default());
+                }
+            }
+            crate::Direction::Stay => {}
+        }
+    }
+}
@@ -4,13 +4,14 @@
     Contrib: @FL03
 */
 #![allow(deprecated)]
-use crate::actors::{Actor, Driver, EngineBase};
+use crate::actors::{Actor, Driver, EngineBase, HeadDriver};
 use crate::error::Error;
 use crate::programs::Program;
 use crate::{Direction, Head, Tail};
 use alloc::string::String;
 use alloc::vec::Vec;
 use rstm_state::{Halting, RawState, State};
+use rstm_tape::ResizableData;
 use rstm_traits::{Handle, Read, Symbolic, TryExecute, TryStep};
 
 #[doc(hidden)]
@@ -410,6 +411,14 @@ where
     }
 }
 
+impl<Q, A> HeadDriver<Q> for TMH<Q, A> {
+    seal! {}
+
+    fn moving_head(&mut self) -> &mut Head<Q, usize> {
+        &mut self.head
+    }
+}
+
 impl<Q, A> Actor<Q, A> for TMH<Q, A>
 where
     Q: RawState,
@@ -486,10 +495,11 @@ where
     }
 }
 
-impl<Q, A> TryStep for EngineBase<TMH<Q, A>, Q, A>
+impl<Q, A, S> TryStep for EngineBase<TMH<Q, A>, Q, A, S>
 where
     Q: RawState + Clone + PartialEq,
     A: Symbolic,
+    S: ResizableData<Elem = A> + Extend<A>,
 {
     type Error = crate::Error;
     type Output = Head<Q, A>;
@@ -504,25 +514,7 @@ where
             let inputs = self.driver().tape().clone();
             self.extend_tape(inputs);
         }
-        // read the tape
-        let Head { state, symbol } = self.read_head()?;
-        // get a reference to the program
-        if let Some(program) = self.program() {
-            // use the program to find a tail for the current head
-            let tail = program
-                .find_tail(state, symbol)
-                .ok_or(crate::Error::NoRuleFound)?
-                .clone();
-            // increment the steps
-            self.next_cycle();
-            // process the instruction
-            let step = self.driver.head_mut().step(tail);
-            // apply the step
-            step.shift(&mut self.tape)
-        } else {
-            #[cfg(feature = "tracing")]
-            tracing::error!("No program loaded; cannot execute step.");
-            Err(crate::Error::NoProgram)
-        }
+        // apply the matching rule, recording the accesses with the tape
+        self.step_head()
     }
 }
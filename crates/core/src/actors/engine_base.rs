@@ -8,18 +8,22 @@ use crate::programs::Program;
 use crate::rules::Head;
 use alloc::vec::Vec;
 use rstm_state::RawState;
+use rstm_tape::{RawData, TapeBase};
 
 /// A type alias for an [`EngineBase`] instance configured with a _moving head_ model using
 /// the [`Head<Q, usize>`] structure to maintain the head's position on the tape.
-pub type MovingHead<Q, A> = EngineBase<Head<Q, usize>, Q, A>;
+pub type MovingHead<Q, A, S = Vec<A>> = EngineBase<Head<Q, usize>, Q, A, S>;
 
 /// The [`EngineBase`] implementation is designed as a type of runtime for executing various
 /// Turing machine models, or drivers, according to a specified set of rules encapsulated
-/// within a [`Program<Q, A>`].
-pub struct EngineBase<D, Q, A>
+/// within a [`Program<Q, A>`]. The engine is generic over the store `S` backing its
+/// [`TapeBase`], which defaults to a [`Vec`].
+#[derive(Clone)]
+pub struct EngineBase<D, Q, A, S = Vec<A>>
 where
     D: Driver<Q, A>,
     Q: RawState,
+    S: RawData<Elem = A>,
 {
     /// the actor that will be executing the program
     pub(crate) driver: D,
@@ -28,5 +32,5 @@ where
     /// the number of cycles executed; independent of the position of the head on the tape
     pub(crate) cycles: usize,
     /// the output tape captures the results of the execution
-    pub(crate) tape: Vec<A>,
+    pub(crate) tape: TapeBase<S, A>,
 }
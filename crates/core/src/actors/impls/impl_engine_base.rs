@@ -9,43 +9,52 @@ use crate::actors::Driver;
 use crate::error::Error;
 use crate::programs::Program;
 use crate::rules::{Head, Tail};
-use rstm_state::{Halting, RawState, State};
+use rstm_state::{HaltKind, Halting, RawState, State};
+use rstm_tape::{RawData, TapeBase};
 use rstm_traits::TryStep;
 
-impl<D, Q, A> EngineBase<D, Q, A>
+impl<D, Q, A, S> EngineBase<D, Q, A, S>
 where
     D: Driver<Q, A>,
     Q: RawState,
+    S: RawData<Elem = A>,
 {
     /// initialize a new instance of the engine using the default driver and given program
     pub fn from_program(program: Program<Q, A>) -> Self
     where
         D: Default,
+        S: Default,
     {
         Self {
             driver: D::default(),
-            tape: Vec::new(),
+            tape: TapeBase::default(),
             program: Some(program),
             cycles: 0,
         }
     }
-    /// initialize a new engine using the given driver and program
+    /// initialize a new engine using the given driver and input, with the head of the tape
+    /// beginning at the current position of the driver
     pub fn from_driver_with_input<I>(driver: D, input: I) -> Self
     where
         I: IntoIterator<Item = A>,
+        S: FromIterator<A>,
     {
+        let tape = TapeBase::from_iter(input).with_head(driver.current_position());
         Self {
             driver,
-            tape: Vec::from_iter(input),
+            tape,
             program: None,
             cycles: 0,
         }
     }
     /// initialize a new instance of the engine from the given driver
-    pub const fn from_driver(driver: D) -> Self {
+    pub fn from_driver(driver: D) -> Self
+    where
+        S: Default,
+    {
         Self {
             driver,
-            tape: Vec::new(),
+            tape: TapeBase::default(),
             program: None,
             cycles: 0,
         }
@@ -61,9 +70,10 @@ where
     pub fn with_tape<I>(self, input: I) -> Self
     where
         I: IntoIterator<Item = A>,
+        S: FromIterator<A>,
     {
         Self {
-            tape: Vec::from_iter(input),
+            tape: TapeBase::from_iter(input),
             ..self
         }
     }
@@ -71,9 +81,13 @@ where
     pub fn set_program(&mut self, program: Program<Q, A>) {
         self.program = Some(program);
     }
+    /// overwrite the number of cycles performed by the engine, e.g. when restoring a snapshot
+    pub const fn set_cycles(&mut self, cycles: usize) {
+        self.cycles = cycles;
+    }
     #[inline]
     /// consumes the engine to create another with the given driver
-    pub fn with_driver<D2>(self, driver: D2) -> EngineBase<D2, Q, A>
+    pub fn with_driver<D2>(self, driver: D2) -> EngineBase<D2, Q, A, S>
     where
         D2: Driver<Q, A>,
     {
@@ -96,7 +110,10 @@ where
     pub const fn cycles(&self) -> usize {
         self.cycles
     }
-    /// returns a reference to the actor
+    /// returns a reference to the actor; note that the position of the head is owned by the
+    /// tape (see [`current_position`](Self::current_position)), with the driver synchronized
+    /// after every step rather than whenever the tape is modified through
+    /// [`tape_mut`](Self::tape_mut).
     pub const fn driver(&self) -> &D {
         &self.driver
     }
@@ -113,27 +130,29 @@ where
         self.program.as_mut()
     }
     /// returns a reference to the output tape
-    pub const fn tape(&self) -> &Vec<A> {
+    pub const fn tape(&self) -> &TapeBase<S, A> {
         &self.tape
     }
     /// returns a mutable reference to the output tape
-    pub const fn tape_mut(&mut self) -> &mut Vec<A> {
+    pub const fn tape_mut(&mut self) -> &mut TapeBase<S, A> {
         &mut self.tape
     }
-    /// returns the current position of the driver
-    pub fn current_position(&self) -> usize {
-        self.driver().current_position()
+    /// returns the current position of the head, i.e. the index of the cell beneath it within
+    /// the tape. The tape is the sole owner of the position, ensuring it remains accurate
+    /// after the tape is grown or otherwise modified through [`tape_mut`](Self::tape_mut).
+    pub const fn current_position(&self) -> usize {
+        self.tape.head()
     }
     /// returns a view of the current state of the driver
     pub fn current_state(&self) -> State<&Q> {
         self.driver().current_state()
     }
     /// returns the length of the output tape
-    pub const fn len(&self) -> usize {
+    pub fn len(&self) -> usize {
         self.tape().len()
     }
     /// returns true if the output tape is empty
-    pub const fn is_empty(&self) -> bool {
+    pub fn is_empty(&self) -> bool {
         self.tape.is_empty() || self.program().is_none()
     }
     /// returns true if the engine has a program loaded
@@ -144,6 +163,7 @@ where
     pub fn extend_tape<I>(&mut self, iter: I)
     where
         I: IntoIterator<Item = A>,
+        S: Extend<A>,
     {
         self.tape_mut().extend(iter);
     }
@@ -155,23 +175,48 @@ where
     {
         self.program.as_ref()?.find_tail(state, symbol)
     }
+    /// returns the tail of the rule matching the current state and the symbol under the head,
+    /// if any
+    pub fn current_tail(&self) -> Option<&Tail<Q, A>>
+    where
+        Q: PartialEq,
+        A: PartialEq,
+    {
+        let symbol = self.tape.get(self.current_position())?;
+        self.program()?.find_tail(self.current_state(), symbol)
+    }
     /// increments the current epoch by a single unit indicating the end of a cycle or step
     pub const fn next_cycle(&mut self) {
         self.cycles += 1;
     }
     /// reset the engine by clearing the output tape, cycles, and program from the current
     /// instance
-    pub fn reset(&mut self) {
-        self.tape.clear();
+    pub fn reset(&mut self)
+    where
+        S: Default,
+    {
+        self.tape = TapeBase::default();
         self.cycles = 0;
         self.program = None;
     }
+    /// returns the kind of halt reached by the driver, if any. The halting states declared by
+    /// the program take precedence, falling back onto the [`Halting`] implementation of the
+    /// state otherwise.
+    pub fn halt_kind(&self) -> Option<HaltKind>
+    where
+        Q: Halting + PartialEq,
+    {
+        let state = self.driver().current_state();
+        self.program()
+            .and_then(|program| program.halt_kind(*state.get()))
+            .or_else(|| state.get().halt_kind())
+    }
     /// returns true if the driver is in a halted state
     pub fn is_halted(&self) -> bool
     where
-        Q: Halting,
+        Q: Halting + PartialEq,
     {
-        self.current_state().is_halted()
+        self.halt_kind().is_some()
     }
     /// execute a single step of the engine
     pub fn step<Z>(&mut self) -> crate::Result<Z>
@@ -187,7 +232,7 @@ where
     /// runs the program until termination (i.e., a halt state is reached, an error occurs, etc.)
     pub fn run(&mut self) -> crate::Result<()>
     where
-        Q: Halting,
+        Q: Halting + PartialEq,
         Self: TryStep<Output = Head<Q, A>, Error = crate::Error>,
     {
         let mut halted = false;
@@ -220,42 +265,62 @@ where
         }
     }
     /// a string representation of the driver's tape with the current head position highlighted
-    /// in brackets. `0, 1, 0, [1], 1, 0, 0` for a radius of `3`.
+    /// in brackets, displaying up to [`DEFAULT_DISPLAY_RADIUS`](crate::DEFAULT_DISPLAY_RADIUS)
+    /// cells on either side of the head.
     pub fn pretty_print(&self) -> String
     where
         A: core::fmt::Debug,
     {
-        let mut out = String::new();
-        let pos = self.current_position();
-        let (a, b) = crate::get_range_around(pos, self.len(), 3);
-        // print out the tape with the head position highlighted
-        for (i, c) in self.tape[a..=b].iter().enumerate() {
-            let idx = a + i;
-            let cell = if pos == idx || (idx == b && pos == (idx + 1)) {
-                format!("[[{c:?}]]")
-            } else {
-                format!("{c:?}")
-            };
-            out.push_str(&cell);
-        }
-        out
+        self.pretty_print_with_radius(crate::DEFAULT_DISPLAY_RADIUS)
+    }
+    /// a string representation of the driver's tape with the current head position highlighted
+    /// in brackets. `0, 1, 0, [1], 1, 0, 0` for a radius of `3`.
+    pub fn pretty_print_with_radius(&self, radius: usize) -> String
+    where
+        A: core::fmt::Debug,
+    {
+        self.print_with(radius, |c| format!("{c:?}"))
     }
     /// returns a string representation of the tape with the current head position highlighted
-    /// in brackets.
+    /// in brackets, displaying up to [`DEFAULT_DISPLAY_RADIUS`](crate::DEFAULT_DISPLAY_RADIUS)
+    /// cells on either side of the head.
     pub fn print(&self) -> String
     where
         A: core::fmt::Display,
+    {
+        self.print_with_radius(crate::DEFAULT_DISPLAY_RADIUS)
+    }
+    /// returns a string representation of the tape with the current head position highlighted
+    /// in brackets, displaying up to `radius` cells on either side of the head.
+    pub fn print_with_radius(&self, radius: usize) -> String
+    where
+        A: core::fmt::Display,
+    {
+        self.print_with(radius, |c| format!("{c}"))
+    }
+    /// returns a string representation of the tape with the current head position highlighted
+    /// in brackets, using the given function to display each of the `radius` cells on either
+    /// side of the head.
+    pub fn print_with<F>(&self, radius: usize, f: F) -> String
+    where
+        F: Fn(&A) -> String,
     {
         let mut out = String::new();
+        if self.tape.is_empty() {
+            return out;
+        }
         let pos = self.current_position();
-        let (a, b) = crate::get_range_around(pos, self.len(), 3);
-        // print out the tape with the head position highlighted
-        for (i, c) in self.tape[a..=b].iter().enumerate() {
-            let idx = a + i;
+        let (a, b) = crate::get_range_around(pos, self.len(), radius);
+        // print out the window of the tape with the head position highlighted, indexing each
+        // cell rather than iterating from the start of the tape
+        for idx in a..=b {
+            let Some(c) = self.tape.get(idx) else {
+                continue;
+            };
             let cell = if pos == idx || (idx == b && pos == (idx + 1)) {
-                format!("[[{c}]]")
+                format!("[[{}]]", f(c))
             } else {
-                format!("{c}")
+                f(c)
             };
             out.push_str(&cell);
         }
@@ -264,7 +329,9 @@ where
     /// read the current symbol at the head of the tape into the internal buffer
     pub fn read(&self) -> crate::Result<&A> {
         let pos = self.current_position();
-        Ok(&self.tape[pos])
+        self.tape
+            .get(pos)
+            .ok_or_else(|| Error::index_out_of_bounds(pos, self.len()))
     }
     /// read and return the current head of the machine
     pub fn read_head(&self) -> crate::Result<Head<&Q, &A>> {
@@ -5,17 +5,19 @@
 */
 
 use crate::actors::engine_base::EngineBase;
-use crate::actors::{Driver, Executor};
+use crate::actors::{Driver, Executor, HeadDriver};
 use crate::programs::Program;
-use crate::rules::Head;
+use crate::rules::{Head, Tail};
 use rstm_state::{Halting, RawState};
+use rstm_tape::{RawData, ResizableData};
 use rstm_traits::{Reader, Symbolic, TryExecute, TryStep};
 
-impl<D, Q, A> Reader<A> for EngineBase<D, Q, A>
+impl<D, Q, A, S> Reader<A> for EngineBase<D, Q, A, S>
 where
     Q: RawState + PartialEq,
     A: PartialEq,
     D: Driver<Q, A>,
+    S: RawData<Elem = A>,
 {
     type Error = crate::Error;
 
@@ -24,11 +26,12 @@ where
     }
 }
 
-impl<D, Q, A, X, Y, E> TryExecute<X> for EngineBase<D, Q, A>
+impl<D, Q, A, S, X, Y, E> TryExecute<X> for EngineBase<D, Q, A, S>
 where
     Q: RawState + PartialEq,
     A: PartialEq,
     D: Driver<Q, A> + TryExecute<X, Output = Y, Error = E>,
+    S: RawData<Elem = A>,
 {
     type Error = E;
     type Output = Y;
@@ -38,11 +41,12 @@ where
     }
 }
 
-impl<D, Q, A> Executor<Q, A> for EngineBase<D, Q, A>
+impl<D, Q, A, S> Executor<Q, A> for EngineBase<D, Q, A, S>
 where
     D: Driver<Q, A>,
     Q: Halting + RawState + PartialEq,
     A: PartialEq,
+    S: RawData<Elem = A>,
     Self: TryStep<Output = Head<Q, A>, Error = crate::Error>,
 {
     type Driver = D;
@@ -58,10 +62,11 @@ where
     }
 }
 
-impl<Q, A> TryStep for EngineBase<Head<Q, usize>, Q, A>
+impl<Q, A, S> TryStep for EngineBase<Head<Q, usize>, Q, A, S>
 where
     A: Symbolic,
     Q: RawState + Clone + PartialEq,
+    S: ResizableData<Elem = A>,
 {
     type Error = crate::Error;
     type Output = Head<Q, A>;
@@ -69,44 +74,71 @@ where
     fn try_step(&mut self) -> Result<Self::Output, Self::Error> {
         #[cfg(feature = "tracing")]
         tracing::info! { "{}", self.print() };
-        // if the output tape is empty, initialize it from the driver's tape
         if self.tape().is_empty() {
             #[cfg(feature = "tracing")]
             tracing::error! { "No inputs loaded on the tape" };
             return Err(crate::Error::TapeIsEmpty);
         }
-        // read the tape
-        let Head {
-            state,
-            symbol: &pos,
-        } = self.driver.view();
-        let current_symbol = &self.tape[pos];
+        self.step_head()
+    }
+}
+
+impl<D, Q, A, S> EngineBase<D, Q, A, S>
+where
+    D: Driver<Q, A> + HeadDriver<Q>,
+    A: Symbolic,
+    Q: RawState + Clone + PartialEq,
+    S: ResizableData<Elem = A>,
+{
+    /// applies the rule matching the head of the driver to the tape, recording the accesses
+    /// with the tape before synchronizing the position of the driver; this is shared by every
+    /// driver moving a [`Head<Q, usize>`] across the tape.
+    pub(crate) fn step_head(&mut self) -> crate::Result<Head<Q, A>> {
+        // read the symbol under the head
+        let symbol = self.read()?.clone();
         // get a reference to the program
-        if let Some(program) = self.program() {
-            // use the program to find a tail for the current head
-            let tail = program
-                .find_tail(state, current_symbol)
-                .ok_or(crate::Error::NoRuleFound)?
-                .clone();
-            // increment the steps
-            self.next_cycle();
-            // process the instruction
-            let step = self.driver.step(tail);
-            // apply the step
-            return step.shift(&mut self.tape);
-        }
-        // if there is no program loaded, return an error
-        #[cfg(feature = "tracing")]
-        tracing::error!("No program loaded; cannot execute step.");
-        Err(crate::Error::NoProgram)
+        let Some(program) = self.program.as_ref() else {
+            // if there is no program loaded, return an error
+            #[cfg(feature = "tracing")]
+            tracing::error!("No program loaded; cannot execute step.");
+            return Err(crate::Error::NoProgram);
+        };
+        let head = self.driver.moving_head();
+        // use the program to find a tail for the current head
+        let Tail {
+            direction,
+            next_state,
+            write_symbol,
+        } = program
+            .find_tail(head.state.view(), &symbol)
+            .ok_or(crate::Error::NoRuleFound)?
+            .clone();
+        // record the read with the tape, should it be collecting metadata
+        let step = self.cycles;
+        self.tape.record_read(step);
+        // increment the steps
+        self.next_cycle();
+        // update the state of the driver, capturing the previous head
+        let prev = Head {
+            state: self.driver.moving_head().replace_state(next_state),
+            symbol,
+        };
+        // write the new symbol before moving the head, growing the tape as needed
+        self.tape.write(write_symbol);
+        self.tape.record_write(step);
+        self.tape.shift(direction);
+        // keep the position of the driver in sync with the head of the tape
+        self.driver.moving_head().symbol = self.tape.head();
+        Ok(prev)
     }
 }
 
-impl<D, Q, A> Iterator for EngineBase<D, Q, A>
+impl<D, Q, A, S> Iterator for EngineBase<D, Q, A, S>
 where
     Q: 'static + Halting + RawState + Clone + PartialEq,
     A: Symbolic,
     D: Driver<Q, A>,
+    S: RawData<Elem = A>,
     Self: TryStep<Output = Head<Q, A>>,
 {
     type Item = Head<Q, A>;
@@ -7,6 +7,7 @@ use crate::actors::EngineBase;
 use crate::programs::Program;
 use crate::rules::Head;
 use rstm_state::RawState;
+use rstm_tape::{RawData, TapeBase};
 
 impl<Q, A> EngineBase<Head<Q, usize>, Q, A>
 where
@@ -19,25 +20,46 @@ where
     where
         Q: Clone + Default,
     {
+        Self::from_program_with_tape(program, TapeBase::default())
+    }
+    /// initialize a new instance of the TMH engine from the given state and input
+    pub fn from_state_with_input<I>(state: Q, input: I) -> Self
+    where
+        I: IntoIterator<Item = A>,
+    {
+        Self::from_state_with_tape(state, TapeBase::from_iter(input))
+    }
+}
+
+impl<Q, A, S> EngineBase<Head<Q, usize>, Q, A, S>
+where
+    Q: RawState + PartialEq,
+    A: PartialEq,
+    S: RawData<Elem = A>,
+{
+    /// initialize a new instance of a turing machine with a moving head using the given
+    /// program and tape, beginning from the initial state of the program.
+    pub fn from_program_with_tape(program: Program<Q, A>, tape: TapeBase<S, A>) -> Self
+    where
+        Q: Clone + Default,
+    {
+        let state = program.initial_state().cloned().unwrap_or_default();
         Self {
             driver: Head {
-                state: program.initial_state().cloned().unwrap_or_default(),
-                symbol: 0,
+                state,
+                symbol: tape.head(),
             },
-            tape: Vec::new(),
+            tape,
             program: Some(program),
             cycles: 0,
         }
     }
-    /// initialize a new instance of the TMH engine from the given state and input
-    pub fn from_state_with_input<I>(state: Q, input: I) -> Self
-    where
-        I: IntoIterator<Item = A>,
-    {
-        let driver = Head::new(state, 0);
+    /// initialize a new instance of the TMH engine from the given state and tape
+    pub fn from_state_with_tape(state: Q, tape: TapeBase<S, A>) -> Self {
+        let driver = Head::new(state, tape.head());
         Self {
             driver,
-            tape: Vec::from_iter(input),
+            tape,
             program: None,
             cycles: 0,
         }
@@ -0,0 +1,199 @@
+/*
+    Appellation: linear_bounded <module>
+    Created At: 2026.10.19:20:31:48
+    Contrib: @FL03
+*/
+use crate::programs::Program;
+use crate::search::{Certificate, Decision};
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use rstm_state::{HaltKind, Halting, RawState, State};
+use rstm_tape::{BoundedTape, RawData, RawDataMut};
+
+/// The [`LinearBounded`] driver executes a program as a linear bounded automaton, confining
+/// the head to its input by way of a [`BoundedTape`]. Any attempt to move the head beyond, or
+/// overwrite, either end marker is reported as an error rather than growing the tape.
+///
+/// Since the tape never grows, the machine only admits finitely many configurations, making
+/// its halting problem decidable; see [`decide`](LinearBounded::decide).
+#[derive(Clone, Debug)]
+pub struct LinearBounded<Q, A, S = Vec<A>>
+where
+    Q: RawState,
+    S: RawData<Elem = A>,
+{
+    /// the program being executed
+    pub(crate) program: Program<Q, A>,
+    /// the current state of the machine
+    pub(crate) state: State<Q>,
+    /// the input, framed by the end markers
+    pub(crate) tape: BoundedTape<S, A>,
+    /// the number of steps taken thus far
+    pub(crate) steps: usize,
+}
+
+impl<Q, A> LinearBounded<Q, A>
+where
+    Q: RawState + Clone,
+{
+    /// returns a new machine executing the program upon the given input, framed by the left
+    /// and right markers.
+    pub fn from_input<I>(program: Program<Q, A>, left: A, input: I, right: A) -> crate::Result<Self>
+    where
+        I: IntoIterator<Item = A>,
+    {
+        let tape = BoundedTape::new(left, input.into_iter().collect(), right);
+        Self::from_tape(program, tape)
+    }
+}
+
+impl<Q, A, S> LinearBounded<Q, A, S>
+where
+    Q: RawState,
+    S: RawData<Elem = A>,
+{
+    /// returns a new machine executing the program upon the given tape, beginning in the
+    /// initial state of the program; an error is returned should the program not declare
+    /// one.
+    pub fn from_tape(program: Program<Q, A>, tape: BoundedTape<S, A>) -> crate::Result<Self>
+    where
+        Q: Clone,
+    {
+        let state = program
+            .initial_state()
+            .cloned()
+            .ok_or(crate::Error::NoInitialState)?;
+        Ok(Self::from_state_with_tape(program, state, tape))
+    }
+    /// returns a new machine executing the program upon the given tape, beginning in the
+    /// given state.
+    pub const fn from_state_with_tape(
+        program: Program<Q, A>,
+        state: State<Q>,
+        tape: BoundedTape<S, A>,
+    ) -> Self {
+        Self {
+            program,
+            state,
+            tape,
+            steps: 0,
+        }
+    }
+    /// returns a reference to the program
+    pub const fn program(&self) -> &Program<Q, A> {
+        &self.program
+    }
+    /// returns a reference to the current state
+    pub const fn state(&self) -> &State<Q> {
+        &self.state
+    }
+    /// returns a reference to the tape
+    pub const fn tape(&self) -> &BoundedTape<S, A> {
+        &self.tape
+    }
+    /// returns the number of steps taken thus far
+    pub const fn steps(&self) -> usize {
+        self.steps
+    }
+    /// returns the kind of halt reached by the machine, if any, preferring the halting states
+    /// declared by the program over those reported by the state itself.
+    pub fn halt_kind(&self) -> Option<HaltKind>
+    where
+        Q: Halting + PartialEq,
+    {
+        self.program
+            .halt_kind(self.state.get())
+            .or_else(|| self.state.get().halt_kind())
+    }
+    /// returns true if the current state is considered to be halted
+    pub fn is_halted(&self) -> bool
+    where
+        Q: Halting + PartialEq,
+    {
+        self.halt_kind().is_some()
+    }
+}
+
+impl<Q, A, S> LinearBounded<Q, A, S>
+where
+    Q: RawState + Clone + Halting + PartialEq,
+    A: Clone + PartialEq,
+    S: RawDataMut<Elem = A>,
+{
+    /// execute a single step of the program; the tape is left untouched should the step
+    /// violate the bounds of the tape.
+    pub fn step(&mut self) -> crate::Result<()> {
+        if self.is_halted() {
+            return Err(crate::Error::Halted);
+        }
+        let tail = self
+            .program
+            .find_tail(self.state.view(), self.tape.read())
+            .ok_or(crate::Error::NoRuleFound)?
+            .clone();
+        let prev = self.tape.try_write(tail.write_symbol)?;
+        if let Err(error) = self.tape.try_shift(tail.direction) {
+            // restore the cell beneath the head before reporting the violation
+            self.tape.try_write(prev)?;
+            return Err(error.into());
+        }
+        self.state = tail.next_state;
+        self.steps += 1;
+        Ok(())
+    }
+    /// run the machine until it halts, returning an error upon reaching a head without a
+    /// rule or violating the bounds of the tape. The machine may run indefinitely; consider
+    /// [`decide`](LinearBounded::decide) to determine whether it halts beforehand.
+    pub fn run(&mut self) -> crate::Result<()> {
+        while !self.is_halted() {
+            self.step()?;
+        }
+        Ok(())
+    }
+    /// decides whether the machine halts by simulating it until it either halts, in which
+    /// case the number of steps taken is reported, or repeats a configuration, in which case
+    /// it never halts. Reaching a head without a rule is treated as halting while violating
+    /// the bounds of the tape is reported as an error.
+    ///
+    /// Since the configuration space of the machine is finite, this always terminates,
+    /// albeit after as many as `|Q| * (n + 2) * |Γ|^n` steps.
+    pub fn decide(&self) -> crate::Result<Decision>
+    where
+        Q: Ord,
+        A: Ord,
+        S: Clone,
+    {
+        let mut machine = self.clone();
+        let mut seen = BTreeMap::new();
+        loop {
+            let snapshot = (
+                machine.state.clone(),
+                machine.tape.position(),
+                machine.tape.iter().cloned().collect::<Vec<_>>(),
+            );
+            if let Some(start) = seen.insert(snapshot, machine.steps) {
+                return Ok(Decision::NeverHalts(Certificate::Cycler {
+                    start,
+                    period: machine.steps - start,
+                }));
+            }
+            if machine.is_halted() {
+                return Ok(Decision::Halts(machine.steps));
+            }
+            match machine.step() {
+                Ok(()) => {}
+                Err(crate::Error::NoRuleFound) => return Ok(Decision::Halts(machine.steps)),
+                Err(error) => return Err(error),
+            }
+        }
+    }
+    /// returns true if the machine halts; see [`decide`](LinearBounded::decide)
+    pub fn halts(&self) -> crate::Result<bool>
+    where
+        Q: Ord,
+        A: Ord,
+        S: Clone,
+    {
+        self.decide().map(|decision| decision.is_halts())
+    }
+}
@@ -4,10 +4,10 @@
     Contrib: @FL03
 */
 //! actors for modular Turing machine implementations
-#[cfg(feature = "alloc")]
-pub use self::engine_base::*;
 #[doc(inline)]
 pub use self::traits::*;
+#[cfg(feature = "alloc")]
+pub use self::{configuration::*, engine_base::*, linear_bounded::*};
 
 pub mod drivers {
     #[doc(inline)]
@@ -16,7 +16,11 @@ pub mod drivers {
     mod tmh;
 }
 
+#[cfg(feature = "alloc")]
+pub mod configuration;
 pub mod engine_base;
+#[cfg(feature = "alloc")]
+pub mod linear_bounded;
 
 mod impls {
     mod impl_engine_base;
@@ -36,7 +40,7 @@ mod traits {
 #[allow(unused_imports)]
 pub(crate) mod prelude {
     pub use super::drivers::*;
-    #[cfg(feature = "alloc")]
-    pub use super::engine_base::*;
     pub use super::traits::*;
+    #[cfg(feature = "alloc")]
+    pub use super::{configuration::*, engine_base::*, linear_bounded::*};
 }
@@ -54,6 +54,22 @@ where
     }
 }
 
+/// [`HeadDriver`] is implemented by the drivers moving a [`Head<Q, usize>`] across the tape,
+/// allowing each of them to share a single implementation of a step.
+pub trait HeadDriver<Q> {
+    private! {}
+    /// returns a mutable reference to the head of the driver
+    fn moving_head(&mut self) -> &mut Head<Q, usize>;
+}
+
+impl<Q> HeadDriver<Q> for Head<Q, usize> {
+    seal! {}
+
+    fn moving_head(&mut self) -> &mut Head<Q, usize> {
+        self
+    }
+}
+
 impl<Q, A> Driver<Q, A> for Head<Q, usize>
 where
     Q: RawState,
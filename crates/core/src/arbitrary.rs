@@ -0,0 +1,316 @@
+/*
+    Appellation: arbitrary <module>
+    Created At: 2026.10.19:00:48:02
+    Contrib: @FL03
+*/
+//! this module provides generators for property testing, implementing the `Arbitrary` traits
+//! of both [`proptest`] and [`quickcheck`] for the [`Head`], [`Tail`], [`Rule`], and
+//! [`Program`] types alongside the [`ProgramParams`] for generating programs with a given
+//! number of states and symbols. See [`rstm_tape::arbitrary`] for the generators of the
+//! [`Direction`](crate::Direction) and tapes.
+use crate::programs::{CANONICAL_HALT, Program};
+use crate::rules::{Rule, Tail};
+use alloc::vec::Vec;
+
+/// [`ProgramParams`] describes the programs to generate over the states `0..states` and
+/// symbols `0..symbols`, where every transition may also move onto the [`CANONICAL_HALT`]
+/// state. The programs always begin in the state `0`.
+///
+/// A _total_ program defines at least one rule for every head, while a _deterministic_
+/// program defines at most one; by default, programs are neither.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ProgramParams {
+    pub(crate) states: usize,
+    pub(crate) symbols: usize,
+    pub(crate) total: bool,
+    pub(crate) deterministic: bool,
+}
+
+impl ProgramParams {
+    /// returns a new instance generating programs with the given number of (non-halting)
+    /// states and symbols, both of which must be non-zero
+    pub const fn new(states: usize, symbols: usize) -> Self {
+        assert!(
+            states > 0 && symbols > 0,
+            "expected at least one state and symbol"
+        );
+        Self {
+            states,
+            symbols,
+            total: false,
+            deterministic: false,
+        }
+    }
+    /// consumes the instance to create another generating total programs if `total` is true
+    pub const fn with_total(self, total: bool) -> Self {
+        Self { total, ..self }
+    }
+    /// consumes the instance to create another generating deterministic programs if
+    /// `deterministic` is true
+    pub const fn with_deterministic(self, deterministic: bool) -> Self {
+        Self {
+            deterministic,
+            ..self
+        }
+    }
+    /// returns the number of (non-halting) states
+    pub const fn states(&self) -> usize {
+        self.states
+    }
+    /// returns the number of symbols
+    pub const fn symbols(&self) -> usize {
+        self.symbols
+    }
+    /// returns true if the generated programs are total
+    pub const fn is_total(&self) -> bool {
+        self.total
+    }
+    /// returns true if the generated programs are deterministic
+    pub const fn is_deterministic(&self) -> bool {
+        self.deterministic
+    }
+    /// returns the range of the number of rules generated for each head
+    const fn rules_per_head(&self) -> core::ops::RangeInclusive<usize> {
+        let min = if self.total { 1 } else { 0 };
+        let max = if self.deterministic { 1 } else { 2 };
+        min..=max
+    }
+    /// maps the given index onto a state, where the final index denotes the halting state
+    const fn state(&self, idx: usize) -> usize {
+        if idx == self.states {
+            CANONICAL_HALT
+        } else {
+            idx
+        }
+    }
+    /// assembles a program from the tails generated for each head, in order
+    fn assemble(&self, tails: Vec<Vec<Tail<usize, usize>>>) -> Program<usize, usize> {
+        let heads = (0..self.states).flat_map(|q| (0..self.symbols).map(move |a| (q, a)));
+        heads
+            .zip(tails)
+            .flat_map(|((q, a), tails)| {
+                tails.into_iter().map(move |tail| {
+                    Rule::from_parts(
+                        q,
+                        a,
+                        tail.direction,
+                        tail.next_state.value(),
+                        tail.write_symbol,
+                    )
+                })
+            })
+            .collect::<Program<usize, usize>>()
+            .with_default_state(0)
+    }
+    #[cfg(feature = "proptest")]
+    /// returns a [`Strategy`](proptest::strategy::Strategy) generating programs according
+    /// to the parameters
+    pub fn strategy(&self) -> impl proptest::strategy::Strategy<Value = Program<usize, usize>> {
+        use proptest::prelude::*;
+        let params = *self;
+        let tail = (
+            any::<crate::Direction>(),
+            0..=params.states,
+            0..params.symbols,
+        )
+            .prop_map(move |(direction, next, write)| {
+                Tail::new(direction, params.state(next), write)
+            });
+        let heads = params.states * params.symbols;
+        proptest::collection::vec(
+            proptest::collection::vec(tail, params.rules_per_head()),
+            heads,
+        )
+        .prop_map(move |tails| params.assemble(tails))
+    }
+    #[cfg(feature = "quickcheck")]
+    /// generates a program according to the parameters
+    pub fn generate(&self, g: &mut quickcheck::Gen) -> Program<usize, usize> {
+        use quickcheck::Arbitrary;
+        let range = self.rules_per_head();
+        let pick = |g: &mut quickcheck::Gen, n: usize| usize::arbitrary(g) % n;
+        let mut tails = Vec::with_capacity(self.states * self.symbols);
+        for _ in 0..self.states * self.symbols {
+            let count = range.start() + pick(g, range.end() - range.start() + 1);
+            let rules = (0..count)
+                .map(|_| {
+                    let direction = crate::Direction::arbitrary(g);
+                    let next = self.state(pick(g, self.states + 1));
+                    Tail::new(direction, next, pick(g, self.symbols))
+                })
+                .collect();
+            tails.push(rules);
+        }
+        self.assemble(tails)
+    }
+}
+
+#[cfg(feature = "proptest")]
+mod impl_proptest {
+    use crate::programs::Program;
+    use crate::rules::{Head, Rule, Tail};
+    use proptest::arbitrary::{Arbitrary, any};
+    use proptest::strategy::{BoxedStrategy, Strategy};
+    use rstm_state::RawState;
+
+    impl<Q, A> Arbitrary for Head<Q, A>
+    where
+        Q: 'static + Arbitrary + RawState,
+        A: 'static + Arbitrary,
+    {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: ()) -> Self::Strategy {
+            any::<(Q, A)>()
+                .prop_map(|(state, symbol)| Head::new(state, symbol))
+                .boxed()
+        }
+    }
+
+    impl<Q, A> Arbitrary for Tail<Q, A>
+    where
+        Q: 'static + Arbitrary + RawState,
+        A: 'static + Arbitrary,
+    {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: ()) -> Self::Strategy {
+            any::<(crate::Direction, Q, A)>()
+                .prop_map(|(direction, state, symbol)| Tail::new(direction, state, symbol))
+                .boxed()
+        }
+    }
+
+    impl<Q, A> Arbitrary for Rule<Q, A>
+    where
+        Q: 'static + Arbitrary + RawState,
+        A: 'static + Arbitrary,
+    {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: ()) -> Self::Strategy {
+            any::<(Head<Q, A>, Tail<Q, A>)>()
+                .prop_map(|(head, tail)| Rule { head, tail })
+                .boxed()
+        }
+    }
+
+    impl<Q, A> Arbitrary for Program<Q, A>
+    where
+        Q: 'static + Arbitrary + Clone + RawState,
+        A: 'static + Arbitrary,
+    {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: ()) -> Self::Strategy {
+            any::<alloc::vec::Vec<Rule<Q, A>>>()
+                .prop_map(super::from_rules)
+                .boxed()
+        }
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+mod impl_quickcheck {
+    use crate::programs::Program;
+    use crate::rules::{Head, Rule, Tail};
+    use alloc::boxed::Box;
+    use alloc::vec::Vec;
+    use quickcheck::{Arbitrary, Gen};
+    use rstm_state::RawState;
+
+    impl<Q, A> Arbitrary for Head<Q, A>
+    where
+        Q: Arbitrary + RawState,
+        A: Arbitrary,
+    {
+        fn arbitrary(g: &mut Gen) -> Self {
+            Head::new(Q::arbitrary(g), A::arbitrary(g))
+        }
+
+        fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+            let parts = (self.state.get().clone(), self.symbol.clone());
+            Box::new(
+                parts
+                    .shrink()
+                    .map(|(state, symbol)| Head::new(state, symbol)),
+            )
+        }
+    }
+
+    impl<Q, A> Arbitrary for Tail<Q, A>
+    where
+        Q: Arbitrary + RawState,
+        A: Arbitrary,
+    {
+        fn arbitrary(g: &mut Gen) -> Self {
+            Tail::new(
+                crate::Direction::arbitrary(g),
+                Q::arbitrary(g),
+                A::arbitrary(g),
+            )
+        }
+
+        fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+            let parts = (
+                self.direction,
+                self.next_state.get().clone(),
+                self.write_symbol.clone(),
+            );
+            Box::new(
+                parts
+                    .shrink()
+                    .map(|(direction, state, symbol)| Tail::new(direction, state, symbol)),
+            )
+        }
+    }
+
+    impl<Q, A> Arbitrary for Rule<Q, A>
+    where
+        Q: Arbitrary + RawState,
+        A: Arbitrary,
+    {
+        fn arbitrary(g: &mut Gen) -> Self {
+            Rule {
+                head: Head::arbitrary(g),
+                tail: Tail::arbitrary(g),
+            }
+        }
+
+        fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+            let parts = (self.head.clone(), self.tail.clone());
+            Box::new(parts.shrink().map(|(head, tail)| Rule { head, tail }))
+        }
+    }
+
+    impl<Q, A> Arbitrary for Program<Q, A>
+    where
+        Q: Arbitrary + RawState,
+        A: Arbitrary,
+    {
+        fn arbitrary(g: &mut Gen) -> Self {
+            super::from_rules(Vec::arbitrary(g))
+        }
+
+        fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+            Box::new(self.rules().clone().shrink().map(super::from_rules))
+        }
+    }
+}
+
+/// returns a program from the given rules, beginning in the state of the first rule, if any
+fn from_rules<Q, A>(rules: Vec<Rule<Q, A>>) -> Program<Q, A>
+where
+    Q: Clone + rstm_state::RawState,
+{
+    let initial = rules.first().map(|rule| rule.head.state.get().clone());
+    let program = Program::from_rules(rules);
+    match initial {
+        Some(initial) => program.with_default_state(initial),
+        None => program,
+    }
+}
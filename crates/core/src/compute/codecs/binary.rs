@@ -0,0 +1,143 @@
+/*
+    Appellation: binary <module>
+    Created At: 2026.10.19:23:12:27
+    Contrib: @FL03
+*/
+use crate::compute::TapeCodec;
+use alloc::vec::Vec;
+use rstm_traits::Symbolic;
+
+/// [`Endian`] enumerates the orders in which the digits of a number may be written onto the
+/// tape, from left to right.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    strum::AsRefStr,
+    strum::Display,
+    strum::EnumCount,
+    strum::EnumIs,
+    strum::EnumString,
+    strum::VariantNames,
+)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[strum(serialize_all = "snake_case")]
+pub enum Endian {
+    /// the most significant digit is written first
+    #[default]
+    Big,
+    /// the least significant digit is written first
+    Little,
+}
+
+/// The [`Binary`] codec encodes the naturals in base two using the given symbols for the
+/// digits `0` and `1`. Zero is encoded as a single `0`, while the empty word decodes to zero,
+/// allowing the blank symbol to double as the digit `0`. In that case, the output of a machine
+/// spans every visited cell on the side of its least significant digit, as the blanks there
+/// are indistinguishable from trailing zeros.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct Binary<A> {
+    pub(crate) zero: A,
+    pub(crate) one: A,
+    pub(crate) endian: Endian,
+}
+
+impl<A> Binary<A> {
+    /// returns a new binary codec using the given digits and order
+    pub const fn new(zero: A, one: A, endian: Endian) -> Self {
+        Self { zero, one, endian }
+    }
+    /// returns a new binary codec writing the most significant digit first
+    pub const fn big(zero: A, one: A) -> Self {
+        Self::new(zero, one, Endian::Big)
+    }
+    /// returns a new binary codec writing the least significant digit first
+    pub const fn little(zero: A, one: A) -> Self {
+        Self::new(zero, one, Endian::Little)
+    }
+    /// returns a reference to the symbol used for the digit `0`
+    pub const fn zero(&self) -> &A {
+        &self.zero
+    }
+    /// returns a reference to the symbol used for the digit `1`
+    pub const fn one(&self) -> &A {
+        &self.one
+    }
+    /// returns the order in which the digits are written
+    pub const fn endian(&self) -> Endian {
+        self.endian
+    }
+}
+
+impl<A> TapeCodec<A> for Binary<A>
+where
+    A: Symbolic,
+{
+    type Value = usize;
+
+    fn encode(&self, value: &usize) -> Vec<A> {
+        let width = (usize::BITS - value.leading_zeros()).max(1);
+        let digit = |bit: u32| {
+            if value >> bit & 1 == 1 {
+                self.one.clone()
+            } else {
+                self.zero.clone()
+            }
+        };
+        match self.endian {
+            Endian::Big => (0..width).rev().map(digit).collect(),
+            Endian::Little => (0..width).map(digit).collect(),
+        }
+    }
+
+    fn decode(&self, cells: &[A]) -> Option<usize> {
+        let bit = |s: &A| {
+            if s == &self.one {
+                Some(1)
+            } else if s == &self.zero {
+                Some(0)
+            } else {
+                None
+            }
+        };
+        let push = |acc: usize, s: &A| acc.checked_mul(2)?.checked_add(bit(s)?);
+        match self.endian {
+            Endian::Big => cells.iter().try_fold(0, push),
+            Endian::Little => cells.iter().rev().try_fold(0, push),
+        }
+    }
+
+    fn region<'a>(&self, tape: &'a [A], head: usize) -> &'a [A]
+    where
+        A: Default + PartialEq,
+    {
+        let _ = head;
+        let blank = A::default();
+        let start = tape.iter().position(|s| s != &blank);
+        let end = tape.iter().rposition(|s| s != &blank);
+        match (start, end) {
+            // the blanks beyond the least significant digit are zeros
+            (Some(start), Some(end)) if self.zero == blank => match self.endian {
+                Endian::Big => &tape[start..],
+                Endian::Little => &tape[..=end],
+            },
+            (Some(start), Some(end)) => &tape[start..=end],
+            _ => &[],
+        }
+    }
+}
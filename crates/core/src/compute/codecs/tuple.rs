@@ -0,0 +1,64 @@
+/*
+    Appellation: tuple <module>
+    Created At: 2026.10.19:23:15:48
+    Contrib: @FL03
+*/
+use crate::compute::TapeCodec;
+use alloc::vec::Vec;
+use rstm_traits::Symbolic;
+
+/// The [`Tuple`] codec encodes a sequence of values using the inner codec, delimiting each of
+/// them with the separator; e.g. `(2, 3)` is encoded as `11#111` by a [`Tuple`] of
+/// [`Unary`](super::Unary) numbers separated by `#`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct Tuple<C, A> {
+    pub(crate) codec: C,
+    pub(crate) separator: A,
+}
+
+impl<C, A> Tuple<C, A> {
+    /// returns a new codec encoding each component with the given codec, delimited by the
+    /// separator
+    pub const fn new(codec: C, separator: A) -> Self {
+        Self { codec, separator }
+    }
+    /// returns a reference to the codec used for each component
+    pub const fn codec(&self) -> &C {
+        &self.codec
+    }
+    /// returns a reference to the separator
+    pub const fn separator(&self) -> &A {
+        &self.separator
+    }
+}
+
+impl<C, A> TapeCodec<A> for Tuple<C, A>
+where
+    A: Symbolic,
+    C: TapeCodec<A>,
+{
+    type Value = Vec<C::Value>;
+
+    fn encode(&self, value: &Vec<C::Value>) -> Vec<A> {
+        let mut cells = Vec::new();
+        for (i, component) in value.iter().enumerate() {
+            if i > 0 {
+                cells.push(self.separator.clone());
+            }
+            cells.extend(self.codec.encode(component));
+        }
+        cells
+    }
+
+    fn decode(&self, cells: &[A]) -> Option<Vec<C::Value>> {
+        cells
+            .split(|s| s == &self.separator)
+            .map(|component| self.codec.decode(component))
+            .collect()
+    }
+}
@@ -0,0 +1,47 @@
+/*
+    Appellation: unary <module>
+    Created At: 2026.10.19:23:10:02
+    Contrib: @FL03
+*/
+use crate::compute::TapeCodec;
+use alloc::vec;
+use alloc::vec::Vec;
+use rstm_traits::Symbolic;
+
+/// The [`Unary`] codec encodes the natural `n` as a run of `n` marks; zero is thus encoded as
+/// the empty word.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct Unary<A> {
+    pub(crate) mark: A,
+}
+
+impl<A> Unary<A> {
+    /// returns a new unary codec using the given mark
+    pub const fn new(mark: A) -> Self {
+        Self { mark }
+    }
+    /// returns a reference to the mark
+    pub const fn mark(&self) -> &A {
+        &self.mark
+    }
+}
+
+impl<A> TapeCodec<A> for Unary<A>
+where
+    A: Symbolic,
+{
+    type Value = usize;
+
+    fn encode(&self, value: &usize) -> Vec<A> {
+        vec![self.mark.clone(); *value]
+    }
+
+    fn decode(&self, cells: &[A]) -> Option<usize> {
+        cells.iter().all(|s| s == &self.mark).then_some(cells.len())
+    }
+}
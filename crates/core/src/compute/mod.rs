@@ -0,0 +1,36 @@
+/*
+    Appellation: compute <module>
+    Created At: 2026.10.19:23:06:42
+    Contrib: @FL03
+*/
+//! this module provides the tools necessary for treating machines as computing functions over
+//! the naturals; most notably, the [`TapeCodec`] trait used to encode the input of a machine
+//! onto its tape before decoding its output once it halts. See
+//! [`compute`](crate::programs::Program::compute) for more information.
+#[doc(inline)]
+pub use self::{
+    codecs::{Binary, Endian, Tuple, Unary},
+    traits::*,
+};
+
+pub mod codecs {
+    //! the codecs implemented by the crate
+    #[doc(inline)]
+    pub use self::{binary::*, tuple::*, unary::*};
+
+    mod binary;
+    mod tuple;
+    mod unary;
+}
+
+mod traits {
+    #[doc(inline)]
+    pub use self::codec::*;
+
+    mod codec;
+}
+
+pub(crate) mod prelude {
+    pub use super::codecs::*;
+    pub use super::traits::*;
+}
@@ -0,0 +1,35 @@
+/*
+    Appellation: codec <module>
+    Created At: 2026.10.19:23:08:15
+    Contrib: @FL03
+*/
+use alloc::vec::Vec;
+
+/// The [`TapeCodec`] trait establishes a common interface for encoding values onto the tape
+/// of a machine and decoding them from it.
+pub trait TapeCodec<A> {
+    /// the type of value encoded by the codec
+    type Value;
+    /// returns the symbols encoding the given value
+    fn encode(&self, value: &Self::Value) -> Vec<A>;
+    /// decodes the value from the given symbols, returning `None` should they not form a
+    /// valid encoding.
+    fn decode(&self, cells: &[A]) -> Option<Self::Value>;
+    /// returns the region of the tape holding the output of the machine given the visited
+    /// cells and the index of the head among them; by default, this is the span of cells
+    /// between the leftmost and rightmost non-blank symbols.
+    fn region<'a>(&self, tape: &'a [A], head: usize) -> &'a [A]
+    where
+        A: Default + PartialEq,
+    {
+        let _ = head;
+        let blank = A::default();
+        match tape.iter().position(|s| s != &blank) {
+            Some(start) => {
+                let end = tape.iter().rposition(|s| s != &blank).unwrap_or(start);
+                &tape[start..=end]
+            }
+            None => &[],
+        }
+    }
+}
@@ -26,23 +26,45 @@ pub enum Error {
     NothingToRead,
     #[error("No program or ruleset has been loaded into the actor.")]
     NoProgram,
+    #[error("The program does not declare an initial state.")]
+    NoInitialState,
     #[error("No rule found associated with the current state and symbol.")]
     NoRuleFound,
     #[error("The index ({idx}) is out of bounds for a tape of length {len}")]
     IndexOutOfBounds { idx: usize, len: usize },
     #[error("Attempted to perform an operation in a halted state.")]
     Halted,
-    #[error("Unable to parse a rule from the given information.")]
-    ParseRuleError,
+    #[error("The machine did not halt within {0} steps.")]
+    StepLimitExceeded(usize),
+    #[error("Unable to assign an id to more than {0} states.")]
+    TooManyStates(usize),
+    #[error("Unable to decode the output of the machine from its tape.")]
+    DecodeError,
+    #[error("Unable to parse a rule at position {pos}: {reason}")]
+    ParseRuleError { pos: usize, reason: &'static str },
+    #[error("Syntax error at line {line}, column {column}: {reason}")]
+    SyntaxError {
+        line: usize,
+        column: usize,
+        reason: &'static str,
+    },
+    #[error("The program cannot be represented by the format: {reason}")]
+    Unrepresentable { reason: &'static str },
     #[error("An invalid direction was specified.")]
     InvalidDirection,
     #[error("Unable to downcast {0:?} into type {1:?}.")]
     DowncastFailure(core::any::TypeId, core::any::TypeId),
     #[error("No symbol found at position {0}")]
     NoSymbolFoundAt(usize),
+    #[error("Unable to infer the format of the file from its extension.")]
+    UnknownFormat,
+    #[error("Support for the {0} format has not been enabled.")]
+    UnsupportedFormat(crate::programs::ProgramFormat),
     // internal errors
     #[error(transparent)]
     StateError(#[from] rstm_state::StateError),
+    #[error(transparent)]
+    TapeError(#[from] rstm_tape::TapeError),
     // external errors
     #[error(transparent)]
     AnyError(#[from] anyhow::Error),
@@ -52,6 +74,24 @@ pub enum Error {
     #[cfg(feature = "json")]
     #[error(transparent)]
     JsonError(#[from] serde_json::Error),
+    #[cfg(feature = "ron")]
+    #[error(transparent)]
+    RonError(#[from] ron::Error),
+    #[cfg(feature = "ron")]
+    #[error(transparent)]
+    RonSpannedError(#[from] ron::error::SpannedError),
+    #[cfg(feature = "toml")]
+    #[error(transparent)]
+    TomlDeError(#[from] toml::de::Error),
+    #[cfg(feature = "toml")]
+    #[error(transparent)]
+    TomlSerError(#[from] toml::ser::Error),
+    #[cfg(feature = "xml")]
+    #[error(transparent)]
+    XmlError(#[from] quick_xml::Error),
+    #[cfg(feature = "yaml")]
+    #[error(transparent)]
+    YamlError(#[from] serde_yaml::Error),
     // Core Errors
     #[error(transparent)]
     FmtError(#[from] core::fmt::Error),
@@ -77,6 +117,22 @@ impl Error {
     pub const fn index_out_of_bounds(idx: usize, len: usize) -> Self {
         Self::IndexOutOfBounds { idx, len }
     }
+    /// a functional constructor for the [`ParseRuleError`](Error::ParseRuleError) variant
+    pub const fn parse_rule_error(pos: usize, reason: &'static str) -> Self {
+        Self::ParseRuleError { pos, reason }
+    }
+    /// a functional constructor for the [`SyntaxError`](Error::SyntaxError) variant
+    pub const fn syntax_error(line: usize, column: usize, reason: &'static str) -> Self {
+        Self::SyntaxError {
+            line,
+            column,
+            reason,
+        }
+    }
+    /// a functional constructor for the [`Unrepresentable`](Error::Unrepresentable) variant
+    pub const fn unrepresentable(reason: &'static str) -> Self {
+        Self::Unrepresentable { reason }
+    }
     #[cfg(feature = "alloc")]
     /// a utility function facilitating the creation of the [`BoxError`](Error::BoxError)
     /// variant
@@ -130,17 +186,30 @@ pub enum ErrorKind {
     ExitWithoutHalting,
     NothingToRead,
     NoProgram,
+    NoInitialState,
     NoRuleFound,
     IndexOutOfBounds,
     Halted,
+    StepLimitExceeded,
+    TooManyStates,
+    DecodeError,
     ParseRuleError,
+    SyntaxError,
+    Unrepresentable,
     InvalidDirection,
     DowncastFailure,
     NoSymbolFoundAt,
+    UnknownFormat,
+    UnsupportedFormat,
     StateError,
+    TapeError,
     AnyError,
     DeserializeError,
     JsonError,
+    RonError,
+    TomlError,
+    XmlError,
+    YamlError,
     FmtError,
     NetworkAddressParseError,
     Utf8Error,
@@ -0,0 +1,251 @@
+/*
+    Appellation: compact <module>
+    Created At: 2026.10.19:12:18:02
+    Contrib: @FL03
+*/
+//! This module implements the compact text format used throughout the busy beaver community
+//! to exchange machines, e.g. `1RB1LB_1LA1RZ`.
+//!
+//! Each state, beginning with `A`, is written as a sequence of transitions separated by an
+//! underscore (`_`); the _i_-th transition of a state is taken upon reading the symbol _i_.
+//! Every transition consists of three characters: the symbol to write, the direction to move
+//! in (`L`, `R` or `S`), and the next state. Undefined transitions are written as `---` while
+//! the halting state is denoted by `Z`, which maps onto [`CANONICAL_HALT`].
+//!
+//! The first state (`A`, or `0`) is always taken to be the initial state of the machine.
+//!
+//! Programs are parsed directly into a [`Program`], while writing one requires a
+//! [`CompactProgram`] ensuring that it fits within the limits of the format.
+use crate::error::Error;
+use crate::programs::{CANONICAL_HALT, Program};
+use crate::rules::{Direction, Rule};
+use alloc::vec::Vec;
+use rstm_state::State;
+
+/// the character used to denote the halting state
+pub const COMPACT_HALT: char = 'Z';
+/// the sequence used to denote an undefined transition
+pub const COMPACT_UNDEFINED: &str = "---";
+/// the maximum number of states supported by the compact format (i.e. `A` through `Y`)
+pub const COMPACT_MAX_STATES: usize = 25;
+/// the maximum number of symbols supported by the compact format (i.e. `0` through `9`)
+pub const COMPACT_MAX_SYMBOLS: usize = 10;
+
+/// [`CompactProgram`] wraps a program known to be representable by the compact format, i.e.
+/// one using at most [`COMPACT_MAX_STATES`] states and [`COMPACT_MAX_SYMBOLS`] symbols, so
+/// that it may always be displayed.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct CompactProgram {
+    pub(crate) program: Program<usize, usize>,
+    pub(crate) states: usize,
+    pub(crate) symbols: usize,
+}
+
+/// parses a program written in the compact format
+pub fn parse_compact(input: &str) -> crate::Result<Program<usize, usize>> {
+    // account for any leading whitespace when reporting positions
+    let base = input.len() - input.trim_start().len();
+    let input = input.trim();
+    if input.is_empty() {
+        return Err(Error::parse_rule_error(base, "expected at least one state"));
+    }
+    let mut rules = Vec::new();
+    let mut symbols = None;
+    let mut pos = base;
+    let mut states = 0;
+    for (state, segment) in input.split('_').enumerate() {
+        if state >= COMPACT_MAX_STATES {
+            return Err(Error::parse_rule_error(pos, "too many states"));
+        }
+        if let Some(idx) = segment.find(|c: char| !c.is_ascii()) {
+            return Err(Error::parse_rule_error(pos + idx, "unexpected character"));
+        }
+        let bytes = segment.as_bytes();
+        if bytes.is_empty() || bytes.len() % 3 != 0 {
+            return Err(Error::parse_rule_error(
+                pos,
+                "expected a sequence of three-character transitions",
+            ));
+        }
+        let k = *symbols.get_or_insert(bytes.len() / 3);
+        if bytes.len() / 3 != k {
+            return Err(Error::parse_rule_error(
+                pos,
+                "expected each state to define the same number of symbols",
+            ));
+        }
+        if k > COMPACT_MAX_SYMBOLS {
+            return Err(Error::parse_rule_error(pos, "too many symbols"));
+        }
+        for (symbol, chunk) in bytes.chunks(3).enumerate() {
+            let at = pos + 3 * symbol;
+            if chunk == COMPACT_UNDEFINED.as_bytes() {
+                continue;
+            }
+            let write = match (chunk[0] as char).to_digit(10) {
+                Some(w) if (w as usize) < k => w as usize,
+                Some(_) => return Err(Error::parse_rule_error(at, "symbol out of range")),
+                None => {
+                    return Err(Error::parse_rule_error(
+                        at,
+                        "expected a digit for the symbol to write",
+                    ));
+                }
+            };
+            let direction = match chunk[1] {
+                b'L' => Direction::Left,
+                b'R' => Direction::Right,
+                b'S' => Direction::Stay,
+                _ => {
+                    return Err(Error::parse_rule_error(
+                        at + 1,
+                        "expected a direction (`L`, `R`, or `S`)",
+                    ));
+                }
+            };
+            let next = match chunk[2] {
+                b'Z' => CANONICAL_HALT,
+                c @ b'A'..=b'Y' => (c - b'A') as usize,
+                _ => {
+                    return Err(Error::parse_rule_error(
+                        at + 2,
+                        "expected a state (`A` through `Y`) or `Z` to halt",
+                    ));
+                }
+            };
+            rules.push((
+                at + 2,
+                Rule::from_parts(state, symbol, direction, next, write),
+            ));
+        }
+        pos += segment.len() + 1;
+        states += 1;
+    }
+    // ensure every transition targets a defined state
+    if let Some((at, _)) = rules.iter().find(|(_, rule)| {
+        *rule.next_state().get() != CANONICAL_HALT && *rule.next_state().get() >= states
+    }) {
+        return Err(Error::parse_rule_error(*at, "undefined state"));
+    }
+    let rules = rules.into_iter().map(|(_, rule)| rule).collect::<Vec<_>>();
+    Ok(Program::from_rules(rules).with_default_state(0))
+}
+
+/*
+ ************* Implementations *************
+*/
+
+impl core::str::FromStr for Program<usize, usize> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_compact(s)
+    }
+}
+
+impl Program<usize, usize> {
+    /// returns the program in the compact format, which may then be displayed; an error is
+    /// returned whenever the program exceeds the number of states or symbols supported by
+    /// the format.
+    pub fn to_compact(&self) -> crate::Result<CompactProgram> {
+        CompactProgram::new(self.clone())
+    }
+}
+
+impl CompactProgram {
+    /// returns a new instance from the given program, ensuring that it may be written in the
+    /// compact format
+    pub fn new(program: Program<usize, usize>) -> crate::Result<Self> {
+        let (mut states, mut symbols) = (1, 1);
+        for rule in program.iter() {
+            states = states.max(*rule.state().get() + 1);
+            if *rule.next_state().get() != CANONICAL_HALT {
+                states = states.max(*rule.next_state().get() + 1);
+            }
+            symbols = symbols
+                .max(*rule.symbol() + 1)
+                .max(*rule.write_symbol() + 1);
+        }
+        if states > COMPACT_MAX_STATES {
+            return Err(Error::unrepresentable(
+                "the compact format supports at most 25 states",
+            ));
+        }
+        if symbols > COMPACT_MAX_SYMBOLS {
+            return Err(Error::unrepresentable(
+                "the compact format supports at most 10 symbols",
+            ));
+        }
+        Ok(Self {
+            program,
+            states,
+            symbols,
+        })
+    }
+    /// returns a reference to the program
+    pub const fn program(&self) -> &Program<usize, usize> {
+        &self.program
+    }
+    /// consumes the instance to return the program
+    pub fn into_program(self) -> Program<usize, usize> {
+        self.program
+    }
+    /// returns the number of states written for the program
+    pub const fn states(&self) -> usize {
+        self.states
+    }
+    /// returns the number of symbols written for each state
+    pub const fn symbols(&self) -> usize {
+        self.symbols
+    }
+}
+
+impl core::fmt::Display for CompactProgram {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for state in 0..self.states {
+            if state > 0 {
+                f.write_str("_")?;
+            }
+            for symbol in 0..self.symbols {
+                let Some(tail) = self.program.find_tail(State(&state), &symbol) else {
+                    f.write_str(COMPACT_UNDEFINED)?;
+                    continue;
+                };
+                let next = match *tail.next_state.get() {
+                    CANONICAL_HALT => COMPACT_HALT,
+                    q => (b'A' + q as u8) as char,
+                };
+                write!(
+                    f,
+                    "{}{}{}",
+                    tail.write_symbol,
+                    tail.direction.as_char(),
+                    next
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl core::str::FromStr for CompactProgram {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_compact(s).and_then(Self::new)
+    }
+}
+
+impl TryFrom<Program<usize, usize>> for CompactProgram {
+    type Error = Error;
+
+    fn try_from(program: Program<usize, usize>) -> Result<Self, Self::Error> {
+        Self::new(program)
+    }
+}
+
+impl From<CompactProgram> for Program<usize, usize> {
+    fn from(compact: CompactProgram) -> Self {
+        compact.program
+    }
+}
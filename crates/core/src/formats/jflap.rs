@@ -0,0 +1,504 @@
+/*
+    Appellation: jflap <module>
+    Created At: 2026.10.19:13:48:20
+    Contrib: @FL03
+*/
+//! This module implements support for the XML-based `.jff` format used by
+//! [JFLAP](https://www.jflap.org) to describe both single and multi-tape Turing machines.
+//!
+//! Files are first read into a [`JflapMachine`], which faithfully models the contents of the
+//! file (including the layout of the states) so that it may be written back without losing
+//! any information. Single-tape machines may then be converted into a [`Program<String, char>`]
+//! using the names of the states, with the empty (blank) symbol mapping onto
+//! `char::default()`.
+use crate::error::Error;
+use crate::programs::Program;
+use crate::rules::{Direction, Rule};
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use quick_xml::escape::{escape, resolve_predefined_entity};
+use quick_xml::events::{BytesStart, Event};
+use rstm_state::{HaltKind, RawState};
+
+/// The [`JflapMachine`] models the contents of a JFLAP Turing machine
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct JflapMachine {
+    /// the number of tapes used by the machine
+    pub tapes: usize,
+    /// the states of the machine
+    pub states: Vec<JflapState>,
+    /// the transitions of the machine
+    pub transitions: Vec<JflapTransition>,
+}
+
+/// A [`JflapState`] describes a single state of a [`JflapMachine`]
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct JflapState {
+    /// the unique identifier of the state
+    pub id: usize,
+    /// the name of the state
+    pub name: String,
+    /// the horizontal position of the state within the editor
+    pub x: f64,
+    /// the vertical position of the state within the editor
+    pub y: f64,
+    /// true if the state is the initial state of the machine
+    pub initial: bool,
+    /// true if the state is a final (accepting) state
+    pub accept: bool,
+}
+
+/// A [`JflapTransition`] connects two states, defining an action for each tape
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct JflapTransition {
+    /// the identifier of the state the transition begins from
+    pub from: usize,
+    /// the identifier of the state the transition leads to
+    pub to: usize,
+    /// the action taken on each tape
+    pub tapes: Vec<JflapAction>,
+}
+
+/// The [`JflapAction`] describes what a transition reads, writes, and how it moves on a
+/// single tape; `None` denotes the blank symbol.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct JflapAction {
+    pub read: Option<char>,
+    pub write: Option<char>,
+    pub direction: Direction,
+}
+
+/// a minimal representation of an XML element
+struct Element {
+    name: String,
+    attrs: Vec<(String, String)>,
+    text: String,
+    children: Vec<Element>,
+    /// the (1-based) line and column of the opening tag
+    pos: (usize, usize),
+}
+
+/// tracks the (1-based) line and column of increasing byte offsets within the input
+struct Locator<'a> {
+    input: &'a str,
+    offset: usize,
+    line: usize,
+    column: usize,
+}
+
+impl JflapMachine {
+    /// parses a machine from the contents of a `.jff` file
+    pub fn from_xml(input: &str) -> crate::Result<Self> {
+        let root = Element::document(input)?;
+        let Some(structure) = root.child("structure") else {
+            return Err(Error::syntax_error(1, 1, "expected a `structure` element"));
+        };
+        match structure.child("type") {
+            Some(kind) if kind.text.trim() == "turing" => {}
+            Some(kind) => {
+                return Err(kind.error("expected a turing machine"));
+            }
+            None => return Err(structure.error("expected a `type`")),
+        }
+        let tapes = match structure.child("tapes") {
+            Some(tapes) => tapes
+                .parse::<usize>()
+                .filter(|&n| n > 0)
+                .ok_or(tapes.error("expected a positive number of tapes"))?,
+            None => 1,
+        };
+        let Some(automaton) = structure.child("automaton") else {
+            return Err(structure.error("expected an `automaton`"));
+        };
+        let mut states = Vec::new();
+        for state in automaton.children("state") {
+            let id = state
+                .attr("id")
+                .and_then(|id| id.trim().parse().ok())
+                .ok_or(state.error("expected a numeric `id`"))?;
+            let coord = |name| match state.child(name) {
+                Some(el) => el.parse::<f64>().ok_or(el.error("expected a coordinate")),
+                None => Ok(0.0),
+            };
+            states.push(JflapState {
+                id,
+                name: state
+                    .attr("name")
+                    .map(ToString::to_string)
+                    .unwrap_or_else(|| alloc::format!("q{id}")),
+                x: coord("x")?,
+                y: coord("y")?,
+                initial: state.child("initial").is_some(),
+                accept: state.child("final").is_some(),
+            });
+        }
+        let mut transitions = Vec::new();
+        for transition in automaton.children("transition") {
+            let endpoint = |name| {
+                transition
+                    .child(name)
+                    .and_then(|el| el.parse::<usize>())
+                    .ok_or(transition.error("expected a state id"))
+            };
+            let mut actions = vec![JflapAction::default(); tapes];
+            for el in &transition.children {
+                if !matches!(el.name.as_str(), "read" | "write" | "move") {
+                    continue;
+                }
+                let tape = match el.attr("tape") {
+                    Some(tape) => tape.trim().parse::<usize>().ok(),
+                    None => Some(1),
+                };
+                let Some(action) = tape.and_then(|t| actions.get_mut(t.wrapping_sub(1))) else {
+                    return Err(el.error("invalid tape"));
+                };
+                match el.name.as_str() {
+                    "read" => action.read = el.symbol()?,
+                    "write" => action.write = el.symbol()?,
+                    "move" => {
+                        action.direction = match el.text.trim() {
+                            "L" => Direction::Left,
+                            "R" => Direction::Right,
+                            "S" => Direction::Stay,
+                            _ => {
+                                return Err(el.error("expected a direction (`L`, `R`, or `S`)"));
+                            }
+                        }
+                    }
+                    _ => unreachable!(),
+                }
+            }
+            transitions.push(JflapTransition {
+                from: endpoint("from")?,
+                to: endpoint("to")?,
+                tapes: actions,
+            });
+        }
+        Ok(Self {
+            tapes,
+            states,
+            transitions,
+        })
+    }
+    /// creates a new, single-tape machine from the given program, marking its accepting
+    /// states as final; the states are laid out on a grid in order of their first appearance.
+    pub fn from_program<Q>(program: &Program<Q, char>) -> Self
+    where
+        Q: RawState + Clone + Ord + ToString,
+    {
+        let mut ids = BTreeMap::new();
+        let mut states = Vec::new();
+        let mut register = |state: &Q| {
+            let n = ids.len();
+            *ids.entry(state.clone()).or_insert_with(|| {
+                states.push(JflapState {
+                    id: n,
+                    name: state.to_string(),
+                    x: 100.0 + 150.0 * (n % 5) as f64,
+                    y: 100.0 + 150.0 * (n / 5) as f64,
+                    initial: program.initial_state().is_some_and(|q| q.get() == state),
+                    accept: program.halt_kind(state) == Some(HaltKind::Accept),
+                });
+                n
+            })
+        };
+        if let Some(initial) = program.initial_state() {
+            register(initial.get());
+        }
+        let transitions = program
+            .iter()
+            .map(|rule| JflapTransition {
+                from: register(rule.state().get()),
+                to: register(rule.next_state().get()),
+                tapes: vec![JflapAction {
+                    read: blank_to_none(*rule.symbol()),
+                    write: blank_to_none(*rule.write_symbol()),
+                    direction: rule.direction(),
+                }],
+            })
+            .collect();
+        for state in program.halt_states().accept() {
+            register(state.get());
+        }
+        Self {
+            tapes: 1,
+            states,
+            transitions,
+        }
+    }
+    /// converts a single-tape machine into a program, declaring its final states as accepting
+    pub fn to_program(&self) -> crate::Result<Program<String, char>> {
+        if self.tapes != 1 {
+            return Err("only single-tape machines may be converted into a program".into());
+        }
+        let names = self
+            .states
+            .iter()
+            .map(|s| (s.id, s.name.as_str()))
+            .collect::<BTreeMap<_, _>>();
+        if names.len() != self.states.len()
+            || names.values().collect::<BTreeSet<_>>().len() != names.len()
+        {
+            return Err("the states of the machine should have unique ids and names".into());
+        }
+        let name = |id: usize| {
+            names
+                .get(&id)
+                .map(|name| name.to_string())
+                .ok_or_else(|| Error::from(alloc::format!("undefined state {id}")))
+        };
+        let mut heads = BTreeSet::new();
+        let mut rules = Vec::new();
+        for transition in &self.transitions {
+            let action = *transition
+                .tapes
+                .first()
+                .ok_or("every transition should define an action for the tape")?;
+            let state = name(transition.from)?;
+            let symbol = action.read.unwrap_or_default();
+            if !heads.insert((state.clone(), symbol)) {
+                return Err(alloc::format!(
+                    "the machine is nondeterministic in state {state:?} reading {symbol:?}"
+                )
+                .into());
+            }
+            rules.push(Rule::from_parts(
+                state,
+                symbol,
+                action.direction,
+                name(transition.to)?,
+                action.write.unwrap_or_default(),
+            ));
+        }
+        let mut program = Program::from_rules(rules);
+        if let Some(initial) = self.states.iter().find(|s| s.initial) {
+            program = program.with_default_state(initial.name.clone());
+        }
+        let accept = self.states.iter().filter(|s| s.accept);
+        Ok(program.with_accept_states(accept.map(|s| s.name.clone())))
+    }
+    /// writes the machine using the `.jff` format
+    pub fn to_xml(&self) -> String {
+        let mut buf = String::new();
+        // writing into a string is infallible
+        let _ = self.write_xml(&mut buf);
+        buf
+    }
+    /// writes the machine into the given writer
+    pub fn write_xml<W: core::fmt::Write>(&self, w: &mut W) -> core::fmt::Result {
+        writeln!(
+            w,
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="no"?>"#
+        )?;
+        writeln!(w, "<structure>")?;
+        writeln!(w, "\t<type>turing</type>")?;
+        if self.tapes != 1 {
+            writeln!(w, "\t<tapes>{}</tapes>", self.tapes)?;
+        }
+        writeln!(w, "\t<automaton>")?;
+        for state in &self.states {
+            writeln!(
+                w,
+                "\t\t<state id=\"{}\" name=\"{}\">",
+                state.id,
+                escape(state.name.as_str())
+            )?;
+            writeln!(w, "\t\t\t<x>{:?}</x>", state.x)?;
+            writeln!(w, "\t\t\t<y>{:?}</y>", state.y)?;
+            if state.initial {
+                writeln!(w, "\t\t\t<initial/>")?;
+            }
+            if state.accept {
+                writeln!(w, "\t\t\t<final/>")?;
+            }
+            writeln!(w, "\t\t</state>")?;
+        }
+        for transition in &self.transitions {
+            writeln!(w, "\t\t<transition>")?;
+            writeln!(w, "\t\t\t<from>{}</from>", transition.from)?;
+            writeln!(w, "\t\t\t<to>{}</to>", transition.to)?;
+            for (i, action) in transition.tapes.iter().enumerate() {
+                let tape = if self.tapes == 1 {
+                    String::new()
+                } else {
+                    alloc::format!(" tape=\"{}\"", i + 1)
+                };
+                for (tag, symbol) in [("read", action.read), ("write", action.write)] {
+                    match symbol {
+                        Some(c) => {
+                            let mut s = [0u8; 4];
+                            let s = escape(&*c.encode_utf8(&mut s));
+                            writeln!(w, "\t\t\t<{tag}{tape}>{s}</{tag}>")?
+                        }
+                        None => writeln!(w, "\t\t\t<{tag}{tape}/>")?,
+                    }
+                }
+                writeln!(w, "\t\t\t<move{tape}>{}</move>", action.direction.as_char())?;
+            }
+            writeln!(w, "\t\t</transition>")?;
+        }
+        writeln!(w, "\t</automaton>")?;
+        writeln!(w, "</structure>")
+    }
+}
+
+/// maps `char::default()` onto `None`
+fn blank_to_none(symbol: char) -> Option<char> {
+    if symbol == char::default() {
+        None
+    } else {
+        Some(symbol)
+    }
+}
+
+impl Element {
+    /// parses the given document into a tree of elements rooted at an unnamed element
+    fn document(input: &str) -> crate::Result<Self> {
+        let mut reader = quick_xml::Reader::from_str(input);
+        let mut locator = Locator::new(input);
+        let mut stack = vec![Self::new(String::new(), (1, 1))];
+        loop {
+            let pos = locator.locate(reader.buffer_position() as usize);
+            let event = reader.read_event()?;
+            let top = stack
+                .last_mut()
+                .expect("the root element should never be closed");
+            match event {
+                Event::Start(e) => stack.push(Self::open(&e, pos)?),
+                Event::Empty(e) => top.children.push(Self::open(&e, pos)?),
+                Event::End(_) => {
+                    let el = stack.pop().expect("the reader should verify the structure");
+                    let Some(parent) = stack.last_mut() else {
+                        return Err(Error::syntax_error(pos.0, pos.1, "unexpected closing tag"));
+                    };
+                    parent.children.push(el);
+                }
+                Event::Text(t) => top
+                    .text
+                    .push_str(&t.decode().map_err(quick_xml::Error::from)?),
+                Event::CData(t) => top
+                    .text
+                    .push_str(&t.decode().map_err(quick_xml::Error::from)?),
+                Event::GeneralRef(r) => {
+                    let c = match r.resolve_char_ref()? {
+                        Some(c) => Some(c),
+                        None => {
+                            resolve_predefined_entity(&r.decode().map_err(quick_xml::Error::from)?)
+                                .and_then(|s| s.chars().next())
+                        }
+                    };
+                    let Some(c) = c else {
+                        return Err(Error::syntax_error(pos.0, pos.1, "unknown entity"));
+                    };
+                    top.text.push(c);
+                }
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+        if stack.len() != 1 {
+            let (line, column) = locator.locate(input.len());
+            return Err(Error::syntax_error(line, column, "unexpected end of input"));
+        }
+        Ok(stack.pop().expect("the root element should be present"))
+    }
+
+    fn new(name: String, pos: (usize, usize)) -> Self {
+        Self {
+            name,
+            attrs: Vec::new(),
+            text: String::new(),
+            children: Vec::new(),
+            pos,
+        }
+    }
+    /// creates a new element from the given opening tag
+    fn open(start: &BytesStart<'_>, pos: (usize, usize)) -> crate::Result<Self> {
+        let name = core::str::from_utf8(start.name().as_ref())?.to_string();
+        let mut el = Self::new(name, pos);
+        for attr in start.attributes() {
+            let attr = attr.map_err(quick_xml::Error::from)?;
+            let key = core::str::from_utf8(attr.key.as_ref())?.to_string();
+            el.attrs.push((key, attr.unescape_value()?.into_owned()));
+        }
+        Ok(el)
+    }
+    /// returns a syntax error located at the opening tag of the element
+    fn error(&self, reason: &'static str) -> Error {
+        Error::syntax_error(self.pos.0, self.pos.1, reason)
+    }
+    /// returns the value of the named attribute
+    fn attr(&self, name: &str) -> Option<&str> {
+        self.attrs
+            .iter()
+            .find_map(|(k, v)| (k == name).then_some(v.as_str()))
+    }
+    /// returns the first child with the given name
+    fn child<'a>(&'a self, name: &'a str) -> Option<&'a Self> {
+        self.children(name).next()
+    }
+    /// returns an iterator over the children with the given name
+    fn children<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Self> {
+        self.children.iter().filter(move |el| el.name == name)
+    }
+    /// parses the (trimmed) text of the element
+    fn parse<T: core::str::FromStr>(&self) -> Option<T> {
+        self.text.trim().parse().ok()
+    }
+    /// returns the symbol contained by the element; `None` denotes the blank symbol
+    fn symbol(&self) -> crate::Result<Option<char>> {
+        let mut chars = self.text.chars();
+        match (chars.next(), chars.next()) {
+            (None, _) => Ok(None),
+            (Some(c), None) => Ok(Some(c)),
+            _ => Err(self.error("expected a single character symbol")),
+        }
+    }
+}
+
+impl<'a> Locator<'a> {
+    const fn new(input: &'a str) -> Self {
+        Self {
+            input,
+            offset: 0,
+            line: 1,
+            column: 1,
+        }
+    }
+    /// returns the line and column of the given offset, which may not precede any offset
+    /// previously located
+    fn locate(&mut self, offset: usize) -> (usize, usize) {
+        let offset = offset.clamp(self.offset, self.input.len());
+        for c in self.input[self.offset..offset].chars() {
+            if c == '\n' {
+                self.line += 1;
+                self.column = 1;
+            } else {
+                self.column += 1;
+            }
+        }
+        self.offset = offset;
+        (self.line, self.column)
+    }
+}
@@ -0,0 +1,24 @@
+/*
+    Appellation: formats <module>
+    Created At: 2026.10.19:12:14:27
+    Contrib: @FL03
+*/
+//! this module implements support for the various textual formats commonly used to exchange
+//! Turing machines.
+//!
+//! - [`compact`]: the compact format used by the busy beaver community (e.g. `1RB1LB_1LA1RZ`)
+//! - [`jflap`]: the XML-based `.jff` format used by JFLAP (requires the `xml` feature)
+//! - [`notation`]: the notation of the [`program!`](crate::program) and [`rule!`](crate::rule)
+//!   macros
+//! - [`tm`]: the line-based `.tm` format used by popular online simulators
+#[cfg(feature = "xml")]
+#[doc(inline)]
+pub use self::jflap::*;
+#[doc(inline)]
+pub use self::{compact::*, notation::*, tm::*};
+
+pub mod compact;
+#[cfg(feature = "xml")]
+pub mod jflap;
+pub mod notation;
+pub mod tm;
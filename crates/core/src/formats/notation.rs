@@ -0,0 +1,372 @@
+/*
+    Appellation: notation <module>
+    Created At: 2026.10.19:15:32:46
+    Contrib: @FL03
+*/
+//! This module implements a runtime parser for the notation accepted by the [`rule!`],
+//! [`ruleset!`] and [`program!`] macros, enabling machines to be written as plain text using
+//! the same syntax found throughout the documentation:
+//!
+//! ```text
+//! // a simple, two-state machine
+//! #[default_state(0)]
+//! rules: {
+//!     (0, 0) -> Right(1, 1),
+//!     (0, 1) -> Left(-1, 0), /* halts */
+//!     (1, 0) -> Right(0, 1),
+//! };
+//! ```
+//!
+//! Both the `#[default_state(...)]` header and the surrounding `rules: { ... };` block are
+//! optional, so a bare list of rules is also accepted. Line (`//`) and block (`/* */`)
+//! comments may appear anywhere, while states and symbols are parsed using their [`FromStr`]
+//! implementation after stripping any surrounding quotes (i.e. `'a'` or `"a"`). Quoted
+//! literals accept the same escapes as Rust, including unicode escapes such as `'\u{7f}'`, so
+//! the [`Debug`](core::fmt::Debug) representation of any `char` or `String` may be used.
+//!
+//! [`rule!`]: crate::rule
+//! [`ruleset!`]: crate::ruleset
+//! [`program!`]: crate::program
+use crate::error::Error;
+use crate::programs::Program;
+use crate::rules::{Direction, Rule};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::iter::Peekable;
+use core::str::{Chars, FromStr};
+use rstm_state::RawState;
+
+/// the characters treated as punctuation by the notation
+const PUNCTUATION: &[char] = &['(', ')', '[', ']', '{', '}', ',', ';', ':', '#'];
+
+/// parses a program written using the notation of the [`program!`](crate::program) macro
+pub fn parse_program<Q, A>(input: &str) -> crate::Result<Program<Q, A>>
+where
+    Q: RawState + FromStr + PartialEq,
+    A: FromStr + PartialEq,
+{
+    let mut parser = Parser::new(input)?;
+    // parse the (optional) header
+    let mut initial_state = None;
+    if parser.eat(&Token::Punct('#')) {
+        parser.expect('[', "expected `[` to begin the attribute")?;
+        match parser.next() {
+            Some((_, Token::Atom(name))) if name == "default_state" => {}
+            Some((at, _)) => return Err(at.error("unknown attribute; expected `default_state`")),
+            None => return Err(parser.end.error("expected an attribute")),
+        }
+        parser.expect('(', "expected `(`")?;
+        initial_state = Some(parser.value::<Q>("invalid state")?);
+        parser.expect(')', "expected `)`")?;
+        parser.expect(']', "expected `]` to close the attribute")?;
+    }
+    // parse the rules, optionally wrapped within a `rules: { ... }` block
+    let block = matches!(parser.peek(), Some(Token::Atom(name)) if name == "rules");
+    if block {
+        parser.next();
+        parser.expect(':', "expected `:`")?;
+        parser.expect('{', "expected `{` to begin the rules")?;
+    }
+    let mut rules = Vec::<Rule<Q, A>>::new();
+    while let Some(Token::Punct('(')) = parser.peek() {
+        let at = parser.position();
+        let rule = parser.rule::<Q, A>()?;
+        if rules.iter().any(|r| r.head == rule.head) {
+            return Err(at.error("duplicate rule"));
+        }
+        rules.push(rule);
+        if !parser.eat(&Token::Punct(',')) {
+            break;
+        }
+    }
+    if block {
+        parser.expect('}', "expected `}` to close the rules")?;
+        parser.eat(&Token::Punct(';'));
+    }
+    parser.finish()?;
+    let program = Program::from_rules(rules);
+    Ok(match initial_state {
+        Some(state) => program.with_default_state(state),
+        None => program,
+    })
+}
+
+/// parses a single rule written using the notation of the [`rule!`](crate::rule) macro
+pub fn parse_rule<Q, A>(input: &str) -> crate::Result<Rule<Q, A>>
+where
+    Q: RawState + FromStr,
+    A: FromStr,
+{
+    let mut parser = Parser::new(input)?;
+    let rule = parser.rule()?;
+    parser.finish()?;
+    Ok(rule)
+}
+
+/// the (1-based) line and column of a token within the input
+#[derive(Clone, Copy, Debug)]
+struct Position {
+    line: usize,
+    column: usize,
+}
+
+impl Position {
+    const fn error(self, reason: &'static str) -> Error {
+        Error::syntax_error(self.line, self.column, reason)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Token {
+    Arrow,
+    Atom(String),
+    Punct(char),
+}
+
+/// a cursor over the input tracking the current line and column
+struct Cursor<'a> {
+    chars: Peekable<Chars<'a>>,
+    pos: Position,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(input: &'a str) -> Self {
+        Self {
+            chars: input.chars().peekable(),
+            pos: Position { line: 1, column: 1 },
+        }
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.chars.peek().copied()
+    }
+    /// returns the character following the next one
+    fn peek2(&self) -> Option<char> {
+        self.chars.clone().nth(1)
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.chars.next()?;
+        if c == '\n' {
+            self.pos.line += 1;
+            self.pos.column = 1;
+        } else {
+            self.pos.column += 1;
+        }
+        Some(c)
+    }
+    /// consumes the `{...}` of a unicode escape (i.e. `\u{1F600}`), returning the character
+    /// it describes
+    fn unicode_escape(&mut self) -> Option<char> {
+        if self.bump()? != '{' {
+            return None;
+        }
+        let mut digits = String::new();
+        loop {
+            match self.bump()? {
+                '}' => break,
+                c if c.is_ascii_hexdigit() && digits.len() < 6 => digits.push(c),
+                _ => return None,
+            }
+        }
+        u32::from_str_radix(&digits, 16)
+            .ok()
+            .and_then(char::from_u32)
+    }
+    /// returns true if the cursor is positioned at the start of an arrow or a comment
+    fn at_delimiter(&self) -> bool {
+        matches!(
+            (self.chars.clone().next(), self.peek2()),
+            (Some('-'), Some('>')) | (Some('/'), Some('/' | '*'))
+        )
+    }
+}
+
+/// splits the input into tokens, discarding any whitespace or comments
+fn tokenize(input: &str) -> crate::Result<(Vec<(Position, Token)>, Position)> {
+    let mut cursor = Cursor::new(input);
+    let mut tokens = Vec::new();
+    while let Some(c) = cursor.peek() {
+        let at = cursor.pos;
+        match (c, cursor.peek2()) {
+            (c, _) if c.is_whitespace() => {
+                cursor.bump();
+            }
+            ('/', Some('/')) => {
+                while cursor.peek().is_some_and(|c| c != '\n') {
+                    cursor.bump();
+                }
+            }
+            ('/', Some('*')) => {
+                cursor.bump();
+                cursor.bump();
+                loop {
+                    match (cursor.bump(), cursor.peek()) {
+                        (Some('*'), Some('/')) => {
+                            cursor.bump();
+                            break;
+                        }
+                        (Some(_), _) => {}
+                        (None, _) => return Err(at.error("unterminated block comment")),
+                    }
+                }
+            }
+            ('-', Some('>')) => {
+                cursor.bump();
+                cursor.bump();
+                tokens.push((at, Token::Arrow));
+            }
+            (quote @ ('\'' | '"'), _) => {
+                cursor.bump();
+                let mut literal = String::new();
+                loop {
+                    match cursor.bump() {
+                        Some(c) if c == quote => break,
+                        Some('\\') => literal.push(match cursor.bump() {
+                            Some('n') => '\n',
+                            Some('r') => '\r',
+                            Some('t') => '\t',
+                            Some('0') => '\0',
+                            Some(c @ ('\\' | '\'' | '"')) => c,
+                            Some('u') => match cursor.unicode_escape() {
+                                Some(c) => c,
+                                None => return Err(at.error("invalid unicode escape")),
+                            },
+                            _ => return Err(at.error("invalid escape sequence")),
+                        }),
+                        Some(c) => literal.push(c),
+                        None => return Err(at.error("unterminated literal")),
+                    }
+                }
+                tokens.push((at, Token::Atom(literal)));
+            }
+            (c, _) if PUNCTUATION.contains(&c) => {
+                cursor.bump();
+                tokens.push((at, Token::Punct(c)));
+            }
+            _ => {
+                let mut atom = String::new();
+                while let Some(c) = cursor.peek() {
+                    if c.is_whitespace() || PUNCTUATION.contains(&c) || cursor.at_delimiter() {
+                        break;
+                    }
+                    atom.push(c);
+                    cursor.bump();
+                }
+                tokens.push((at, Token::Atom(atom)));
+            }
+        }
+    }
+    Ok((tokens, cursor.pos))
+}
+
+/// a simple, recursive descent parser over the tokens of the input
+struct Parser {
+    tokens: Vec<(Position, Token)>,
+    idx: usize,
+    end: Position,
+}
+
+impl Parser {
+    fn new(input: &str) -> crate::Result<Self> {
+        let (tokens, end) = tokenize(input)?;
+        Ok(Self {
+            tokens,
+            idx: 0,
+            end,
+        })
+    }
+    /// returns the position of the next token, or the end of the input
+    fn position(&self) -> Position {
+        self.tokens.get(self.idx).map_or(self.end, |(at, _)| *at)
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.idx).map(|(_, token)| token)
+    }
+
+    fn next(&mut self) -> Option<(Position, Token)> {
+        let next = self.tokens.get(self.idx).cloned();
+        self.idx += next.is_some() as usize;
+        next
+    }
+    /// consumes the next token if it matches the one given
+    fn eat(&mut self, token: &Token) -> bool {
+        let matches = self.peek() == Some(token);
+        self.idx += matches as usize;
+        matches
+    }
+
+    fn expect(&mut self, punct: char, reason: &'static str) -> crate::Result<()> {
+        let at = self.position();
+        if self.eat(&Token::Punct(punct)) {
+            Ok(())
+        } else {
+            Err(at.error(reason))
+        }
+    }
+    /// ensures the entire input has been consumed
+    fn finish(&self) -> crate::Result<()> {
+        match self.peek() {
+            Some(_) => Err(self.position().error("unexpected token")),
+            None => Ok(()),
+        }
+    }
+
+    fn value<T: FromStr>(&mut self, reason: &'static str) -> crate::Result<T> {
+        match self.next() {
+            Some((at, Token::Atom(atom))) => atom.parse().map_err(|_| at.error(reason)),
+            Some((at, _)) => Err(at.error(reason)),
+            None => Err(self.end.error(reason)),
+        }
+    }
+
+    fn direction(&mut self) -> crate::Result<Direction> {
+        const REASON: &str = "expected a direction (`Left`, `Right`, or `Stay`)";
+        match self.next() {
+            Some((at, Token::Atom(atom))) => match atom.as_str() {
+                "Left" | "left" | "L" | "l" => Ok(Direction::Left),
+                "Right" | "right" | "R" | "r" => Ok(Direction::Right),
+                "Stay" | "stay" | "S" | "s" => Ok(Direction::Stay),
+                _ => Err(at.error(REASON)),
+            },
+            Some((at, _)) => Err(at.error(REASON)),
+            None => Err(self.end.error(REASON)),
+        }
+    }
+    /// parses a single rule of the form `(state, symbol) -> Direction(next_state, write_symbol)`
+    fn rule<Q, A>(&mut self) -> crate::Result<Rule<Q, A>>
+    where
+        Q: RawState + FromStr,
+        A: FromStr,
+    {
+        let (state, symbol) = self.pair("invalid state", "invalid symbol")?;
+        let at = self.position();
+        if !self.eat(&Token::Arrow) {
+            return Err(at.error("expected `->`"));
+        }
+        let direction = self.direction()?;
+        let (next_state, write_symbol) = self.pair("invalid next state", "invalid symbol")?;
+        Ok(Rule::from_parts(
+            state,
+            symbol,
+            direction,
+            next_state,
+            write_symbol,
+        ))
+    }
+    /// parses a parenthesized pair of values, permitting a trailing comma
+    fn pair<Q, A>(&mut self, lhs: &'static str, rhs: &'static str) -> crate::Result<(Q, A)>
+    where
+        Q: FromStr,
+        A: FromStr,
+    {
+        self.expect('(', "expected `(`")?;
+        let q = self.value(lhs)?;
+        self.expect(',', "expected `,`")?;
+        let a = self.value(rhs)?;
+        self.eat(&Token::Punct(','));
+        self.expect(')', "expected `)`")?;
+        Ok((q, a))
+    }
+}
@@ -0,0 +1,245 @@
+/*
+    Appellation: tm <module>
+    Created At: 2026.10.19:13:05:12
+    Contrib: @FL03
+*/
+//! This module implements the line-based `.tm` format popularized by online Turing machine
+//! simulators, where each line defines a single transition:
+//!
+//! ```text
+//! ; <state> <read> <write> <dir> <next>
+//! 0 _ 1 r 1
+//! 1 * * l halt
+//! ```
+//!
+//! The underscore (`_`) denotes the blank symbol, which maps onto `char::default()`, while a
+//! semicolon (`;`) begins a comment running to the end of the line. The direction is one of
+//! `l`, `r` or `*` (stay). Wildcards (`*`) are also permitted within the remaining fields:
+//!
+//! - `<state>`: matches every (non-halting) state named by the program
+//! - `<read>`: matches every symbol of the alphabet
+//! - `<write>`: writes back the symbol that was read
+//! - `<next>`: remains in the current state
+//!
+//! Wildcards are expanded into concrete rules with explicit rules taking precedence over
+//! wildcard symbols, which in turn take precedence over wildcard states. States beginning
+//! with `halt` are declared as halting states of the program, where `halt-accept` and
+//! `halt-reject` respectively accept and reject their input, while the initial state is
+//! always `0`.
+use crate::error::Error;
+use crate::programs::Program;
+use crate::rules::{Direction, Rule};
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use rstm_state::HaltKind;
+
+/// the character used to denote the blank symbol
+pub const TM_BLANK: char = '_';
+/// the character used to denote a wildcard
+pub const TM_WILDCARD: char = '*';
+/// the character used to begin a comment
+pub const TM_COMMENT: char = ';';
+/// the prefix denoting a halting state
+pub const TM_HALT_PREFIX: &str = "halt";
+/// the name of the halting state accepting its input
+pub const TM_ACCEPT_STATE: &str = "halt-accept";
+/// the name of the halting state rejecting its input
+pub const TM_REJECT_STATE: &str = "halt-reject";
+/// the name of the initial state
+pub const TM_INITIAL_STATE: &str = "0";
+
+/// a single, possibly wildcarded, transition
+struct Pattern {
+    state: Option<String>,
+    symbol: Option<char>,
+    write: Option<char>,
+    direction: Direction,
+    next: Option<String>,
+}
+
+impl Pattern {
+    /// returns the precedence of the pattern, where lower values take priority
+    const fn precedence(&self) -> u8 {
+        (self.state.is_none() as u8) << 1 | self.symbol.is_none() as u8
+    }
+}
+
+/// parses a program written in the `.tm` format, expanding any wildcards over the given
+/// alphabet together with the blank symbol and every symbol mentioned by the program.
+pub fn parse_tm(input: &str, alphabet: &[char]) -> crate::Result<Program<String, char>> {
+    let mut patterns = Vec::new();
+    let mut seen = BTreeSet::new();
+    let mut pos = 0;
+    for line in input.split_inclusive('\n') {
+        let start = pos;
+        pos += line.len();
+        let line = line.split(TM_COMMENT).next().unwrap_or_default();
+        let mut tokens = line.split_whitespace().map(|token| {
+            (
+                start + token.as_ptr() as usize - line.as_ptr() as usize,
+                token,
+            )
+        });
+        let Some((at, state)) = tokens.next() else {
+            continue;
+        };
+        let mut field = |reason| tokens.next().ok_or(Error::parse_rule_error(at, reason));
+        let symbol = field("expected the symbol to read")?;
+        let write = field("expected the symbol to write")?;
+        let direction = field("expected a direction")?;
+        let next = field("expected the next state")?;
+        if let Some((extra, _)) = tokens.next() {
+            return Err(Error::parse_rule_error(extra, "unexpected token"));
+        }
+        let pattern = Pattern {
+            state: wildcard((at, state)).map(|(_, q)| q.to_string()),
+            symbol: wildcard(symbol).map(parse_symbol).transpose()?,
+            write: wildcard(write).map(parse_symbol).transpose()?,
+            direction: match direction.1 {
+                "l" | "L" => Direction::Left,
+                "r" | "R" => Direction::Right,
+                "*" => Direction::Stay,
+                _ => {
+                    return Err(Error::parse_rule_error(
+                        direction.0,
+                        "expected a direction (`l`, `r`, or `*`)",
+                    ));
+                }
+            },
+            next: wildcard(next).map(|(_, q)| q.to_string()),
+        };
+        if !seen.insert((pattern.state.clone(), pattern.symbol)) {
+            return Err(Error::parse_rule_error(at, "duplicate transition"));
+        }
+        patterns.push(pattern);
+    }
+    // collect the states and symbols wildcards are expanded over
+    let (halting, states) = patterns
+        .iter()
+        .flat_map(|p| [p.state.as_ref(), p.next.as_ref()])
+        .flatten()
+        .cloned()
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .partition::<BTreeSet<_>, _>(|q| q.starts_with(TM_HALT_PREFIX));
+    let symbols = patterns
+        .iter()
+        .flat_map(|p| [p.symbol, p.write])
+        .flatten()
+        .chain(alphabet.iter().copied())
+        .chain([char::default()])
+        .collect::<BTreeSet<_>>();
+    // expand the patterns in order of precedence
+    patterns.sort_by_key(Pattern::precedence);
+    let mut rules = BTreeMap::new();
+    for pattern in &patterns {
+        let heads = match &pattern.state {
+            Some(state) => vec![state],
+            None => states.iter().collect(),
+        };
+        for state in heads {
+            let reads = match pattern.symbol {
+                Some(symbol) => vec![symbol],
+                None => symbols.iter().copied().collect(),
+            };
+            for symbol in reads {
+                rules.entry((state.clone(), symbol)).or_insert_with(|| {
+                    let next = pattern.next.clone().unwrap_or_else(|| state.clone());
+                    (pattern.direction, next, pattern.write.unwrap_or(symbol))
+                });
+            }
+        }
+    }
+    let rules = rules
+        .into_iter()
+        .map(|((state, symbol), (direction, next, write))| {
+            Rule::from_parts(state, symbol, direction, next, write)
+        })
+        .collect::<Vec<_>>();
+    let mut program = Program::from_rules(rules).with_default_state(TM_INITIAL_STATE.to_string());
+    for state in halting {
+        let kind = match state.as_str() {
+            TM_ACCEPT_STATE => HaltKind::Accept,
+            TM_REJECT_STATE => HaltKind::Reject,
+            _ => HaltKind::Halt,
+        };
+        program.set_halt_state(state, kind);
+    }
+    Ok(program)
+}
+
+/// writes the given program using the `.tm` format; an error is returned if any of the
+/// states or symbols cannot be represented by the format. Since the format always begins in
+/// the state `0`, the initial state of the program is written as `0`, exchanging names with
+/// any other state named `0`.
+pub fn to_tm(program: &Program<String, char>) -> crate::Result<String> {
+    use core::fmt::Write;
+
+    let initial = program.initial_state().map(|q| q.get().as_str());
+    fn rename<'a>(state: &'a str, initial: Option<&'a str>) -> &'a str {
+        match initial {
+            Some(initial) if state == initial => TM_INITIAL_STATE,
+            Some(initial) if state == TM_INITIAL_STATE => initial,
+            _ => state,
+        }
+    }
+    let mut buf = String::new();
+    for rule in program.iter() {
+        let state = format_state(rename(rule.state().get(), initial))?;
+        let next = format_state(rename(rule.next_state().get(), initial))?;
+        let symbol = format_symbol(*rule.symbol())?;
+        let write = format_symbol(*rule.write_symbol())?;
+        let direction = match rule.direction() {
+            Direction::Left => 'l',
+            Direction::Right => 'r',
+            Direction::Stay => TM_WILDCARD,
+        };
+        writeln!(buf, "{state} {symbol} {write} {direction} {next}")?;
+    }
+    Ok(buf)
+}
+
+/// returns `None` if the token is a wildcard
+fn wildcard(token: (usize, &str)) -> Option<(usize, &str)> {
+    if token.1 == "*" { None } else { Some(token) }
+}
+
+/// parses a single symbol, mapping the blank onto `char::default()`
+fn parse_symbol((at, token): (usize, &str)) -> crate::Result<char> {
+    let mut chars = token.chars();
+    match (chars.next(), chars.next()) {
+        (Some(TM_BLANK), None) => Ok(char::default()),
+        (Some(c), None) => Ok(c),
+        _ => Err(Error::parse_rule_error(
+            at,
+            "expected a single character symbol",
+        )),
+    }
+}
+
+/// ensures the state is representable within the format
+fn format_state(state: &str) -> crate::Result<&str> {
+    let invalid = state.is_empty()
+        || state == "*"
+        || state.contains(|c: char| c.is_whitespace() || c == TM_COMMENT);
+    if invalid {
+        return Err(Error::unrepresentable(
+            "states must be non-empty without any whitespace, comments, or wildcards",
+        ));
+    }
+    Ok(state)
+}
+
+/// ensures the symbol is representable within the format, mapping `char::default()` onto the
+/// blank
+fn format_symbol(symbol: char) -> crate::Result<char> {
+    match symbol {
+        c if c == char::default() => Ok(TM_BLANK),
+        TM_BLANK | TM_WILDCARD | TM_COMMENT => Err(Error::unrepresentable(
+            "symbols may not be reserved by the `.tm` format",
+        )),
+        c if c.is_whitespace() => Err(Error::unrepresentable("symbols may not be whitespace")),
+        c => Ok(c),
+    }
+}
@@ -56,13 +56,27 @@ pub(crate) mod macros {
 #[doc(inline)]
 pub use rstm_state as state;
 #[doc(inline)]
+pub use rstm_tape as tape;
+#[doc(inline)]
 pub use rstm_traits as traits;
 // modules
 pub mod actors;
+#[cfg(any(feature = "proptest", feature = "quickcheck"))]
+pub mod arbitrary;
+#[cfg(feature = "alloc")]
+pub mod compute;
 pub mod error;
+#[cfg(feature = "alloc")]
+pub mod formats;
+#[cfg(feature = "alloc")]
+pub mod machines;
 pub mod motion;
 pub mod programs;
+#[cfg(all(feature = "alloc", feature = "rand"))]
+pub mod random;
 pub mod rules;
+#[cfg(feature = "alloc")]
+pub mod search;
 
 mod utils {
     #[doc(inline)]
@@ -84,7 +98,7 @@ pub use self::{
 #[cfg(feature = "macros")]
 pub use rstm_state::s;
 #[doc(inline)]
-pub use rstm_state::{Halt, HaltState, Halting, HaltingState, RawState, State, StateExt};
+pub use rstm_state::{Halt, HaltKind, HaltState, Halting, HaltingState, RawState, State, StateExt};
 // prelude
 #[doc(hidden)]
 pub mod prelude {
@@ -97,9 +111,13 @@ pub mod prelude {
     pub use crate::{rules, ruleset};
 
     pub use crate::actors::prelude::*;
+    #[cfg(feature = "alloc")]
+    pub use crate::compute::prelude::*;
     pub use crate::motion::prelude::*;
     pub use crate::programs::prelude::*;
     pub use crate::rules::prelude::*;
+    #[cfg(feature = "alloc")]
+    pub use crate::search::prelude::*;
     pub use crate::utils::*;
 }
 
@@ -0,0 +1,54 @@
+/*
+    Appellation: arithmetic <module>
+    Created At: 2026.10.19:23:41:05
+    Contrib: @FL03
+*/
+use super::from_table;
+use crate::programs::{CANONICAL_HALT, Program};
+use crate::rules::Direction::{Left, Right, Stay};
+
+/// returns a machine adding two naturals written in unary using the mark `1` and delimited by
+/// the separator `2`; i.e. the [`Tuple`](crate::compute::Tuple) of [`Unary`] numbers
+/// `Tuple::new(Unary::new(1), 2)`. The machine replaces the separator with a mark before
+/// erasing the final mark, leaving the sum behind in `a + b + 3` steps.
+///
+/// ```rust
+/// use rstm_core::compute::{Tuple, Unary};
+///
+/// let adder = rstm_core::machines::unary_adder();
+/// let sum = adder.compute(&Tuple::new(Unary::new(1), 2), &vec![2, 3], 100).unwrap();
+/// assert_eq!(sum, vec![5]);
+/// ```
+///
+/// [`Unary`]: crate::compute::Unary
+pub fn unary_adder() -> Program<usize, usize> {
+    from_table(
+        0,
+        [
+            (0, 1, Right, 0, 1),
+            (0, 2, Right, 1, 1),
+            (1, 1, Right, 1, 1),
+            (1, 0, Left, 2, 0),
+            (2, 1, Stay, CANONICAL_HALT, 0),
+        ],
+    )
+}
+
+/// returns a machine incrementing a natural written in binary, most significant digit first,
+/// using the symbols `'0'` and `'1'`; i.e. the [`Binary`](crate::compute::Binary) codec
+/// `Binary::big('0', '1')`. The machine moves onto the least significant digit before
+/// carrying leftwards, writing a new digit onto the blank preceding the number should it
+/// overflow.
+pub fn binary_incrementer() -> Program<usize, char> {
+    from_table(
+        0,
+        [
+            (0, '0', Right, 0, '0'),
+            (0, '1', Right, 0, '1'),
+            (0, '\0', Left, 1, '\0'),
+            (1, '1', Left, 1, '0'),
+            (1, '0', Stay, CANONICAL_HALT, '1'),
+            (1, '\0', Stay, CANONICAL_HALT, '1'),
+        ],
+    )
+}
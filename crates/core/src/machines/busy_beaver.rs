@@ -0,0 +1,72 @@
+/*
+    Appellation: busy_beaver <module>
+    Created At: 2026.10.19:23:36:51
+    Contrib: @FL03
+*/
+use crate::formats::parse_compact;
+use crate::programs::Program;
+
+/// A [`Champion`] records the known busy beaver champion, i.e. the machine running for the
+/// most steps before halting, among the _n_-state, two-symbol machines.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Champion {
+    pub(crate) states: usize,
+    pub(crate) notation: &'static str,
+    pub(crate) steps: usize,
+    pub(crate) sigma: usize,
+}
+
+/// the busy beaver champions for two through five states, written in the
+/// [`compact`](crate::formats::compact) format
+pub const BUSY_BEAVERS: [Champion; 4] = [
+    Champion::new(2, "1RB1LB_1LA1RZ", 6, 4),
+    Champion::new(3, "1RB1RZ_1LB0RC_1LC1LA", 21, 5),
+    Champion::new(4, "1RB1LB_1LA0LC_1RZ1LD_1RD0RA", 107, 13),
+    Champion::new(5, "1RB1LC_1RC1RB_1RD0LE_1LA1LD_1RZ0LA", 47_176_870, 4_098),
+];
+
+impl Champion {
+    const fn new(states: usize, notation: &'static str, steps: usize, sigma: usize) -> Self {
+        Self {
+            states,
+            notation,
+            steps,
+            sigma,
+        }
+    }
+    /// returns the number of (non-halting) states
+    pub const fn states(&self) -> usize {
+        self.states
+    }
+    /// returns the machine written in the compact format
+    pub const fn notation(&self) -> &'static str {
+        self.notation
+    }
+    /// returns the number of steps taken before halting, counting the halting transition,
+    /// when started on a blank tape
+    pub const fn steps(&self) -> usize {
+        self.steps
+    }
+    /// returns the number of non-blank symbols left on the tape once the machine halts
+    pub const fn sigma(&self) -> usize {
+        self.sigma
+    }
+    /// returns the program of the champion
+    pub fn program(&self) -> Program<usize, usize> {
+        parse_compact(self.notation).expect("the champions are written in the compact format")
+    }
+}
+
+/// returns the record of the busy beaver champion with the given number of states, if known
+pub const fn champion(states: usize) -> Option<&'static Champion> {
+    match states {
+        2..=5 => Some(&BUSY_BEAVERS[states - 2]),
+        _ => None,
+    }
+}
+
+/// returns the busy beaver champion with the given number of states, if known; see
+/// [`BUSY_BEAVERS`] for their expected behavior.
+pub fn busy_beaver(states: usize) -> Option<Program<usize, usize>> {
+    champion(states).map(Champion::program)
+}
@@ -0,0 +1,89 @@
+/*
+    Appellation: languages <module>
+    Created At: 2026.10.19:23:49:12
+    Contrib: @FL03
+*/
+use super::from_table;
+use crate::programs::Program;
+use crate::rules::Direction::{Left, Right, Stay};
+
+/// the accepting state of the recognizers within this module
+pub const ACCEPT: usize = 100;
+/// the rejecting state of the recognizers within this module
+pub const REJECT: usize = 101;
+
+/// returns a machine recognizing the palindromes over `{a, b}`, including the empty word.
+/// The machine repeatedly erases the first symbol of the word, comparing it against the last
+/// before erasing it in turn, accepting once the word has been consumed and rejecting upon
+/// the first mismatch.
+pub fn palindromes() -> Program<usize, char> {
+    const START: usize = 0;
+    const SEEK_A: usize = 1;
+    const SEEK_B: usize = 2;
+    const MATCH_A: usize = 3;
+    const MATCH_B: usize = 4;
+    const REWIND: usize = 5;
+    from_table(
+        START,
+        [
+            (START, '\0', Stay, ACCEPT, '\0'),
+            (START, 'a', Right, SEEK_A, '\0'),
+            (START, 'b', Right, SEEK_B, '\0'),
+            // move onto the last symbol of the word
+            (SEEK_A, 'a', Right, SEEK_A, 'a'),
+            (SEEK_A, 'b', Right, SEEK_A, 'b'),
+            (SEEK_A, '\0', Left, MATCH_A, '\0'),
+            (SEEK_B, 'a', Right, SEEK_B, 'a'),
+            (SEEK_B, 'b', Right, SEEK_B, 'b'),
+            (SEEK_B, '\0', Left, MATCH_B, '\0'),
+            // compare the last symbol against the first; a blank implies a word of odd length
+            (MATCH_A, 'a', Left, REWIND, '\0'),
+            (MATCH_A, 'b', Stay, REJECT, 'b'),
+            (MATCH_A, '\0', Stay, ACCEPT, '\0'),
+            (MATCH_B, 'a', Stay, REJECT, 'a'),
+            (MATCH_B, 'b', Left, REWIND, '\0'),
+            (MATCH_B, '\0', Stay, ACCEPT, '\0'),
+            // return to the first symbol of the remaining word
+            (REWIND, 'a', Left, REWIND, 'a'),
+            (REWIND, 'b', Left, REWIND, 'b'),
+            (REWIND, '\0', Right, START, '\0'),
+        ],
+    )
+    .with_accept_states([ACCEPT])
+    .with_reject_states([REJECT])
+}
+
+/// returns a machine recognizing the language `0ⁿ1ⁿ` for `n ≥ 0` over `{0, 1}`. The machine
+/// repeatedly marks the leftmost `0` (i.e. `X`) alongside the leftmost `1` (i.e. `Y`),
+/// accepting once every symbol has been marked in pairs.
+pub fn zeros_then_ones() -> Program<usize, char> {
+    const START: usize = 0;
+    const FIND_ONE: usize = 1;
+    const REWIND: usize = 2;
+    const VERIFY: usize = 3;
+    from_table(
+        START,
+        [
+            (START, '0', Right, FIND_ONE, 'X'),
+            (START, 'Y', Right, VERIFY, 'Y'),
+            (START, '1', Stay, REJECT, '1'),
+            (START, '\0', Stay, ACCEPT, '\0'),
+            // mark the leftmost unmarked one
+            (FIND_ONE, '0', Right, FIND_ONE, '0'),
+            (FIND_ONE, 'Y', Right, FIND_ONE, 'Y'),
+            (FIND_ONE, '1', Left, REWIND, 'Y'),
+            (FIND_ONE, '\0', Stay, REJECT, '\0'),
+            // return to the rightmost marked zero
+            (REWIND, '0', Left, REWIND, '0'),
+            (REWIND, 'Y', Left, REWIND, 'Y'),
+            (REWIND, 'X', Right, START, 'X'),
+            // ensure nothing but marked ones remain
+            (VERIFY, 'Y', Right, VERIFY, 'Y'),
+            (VERIFY, '0', Stay, REJECT, '0'),
+            (VERIFY, '1', Stay, REJECT, '1'),
+            (VERIFY, '\0', Stay, ACCEPT, '\0'),
+        ],
+    )
+    .with_accept_states([ACCEPT])
+    .with_reject_states([REJECT])
+}
@@ -0,0 +1,38 @@
+/*
+    Appellation: machines <module>
+    Created At: 2026.10.19:23:34:18
+    Contrib: @FL03
+*/
+//! this module provides a library of well-known machines, each documenting its expected
+//! behavior, for use as fixtures, examples, and regression tests for the engine.
+//!
+//! - [`busy_beaver`]: the busy beaver champions for two through five states
+//! - [`unary_adder`] and [`binary_incrementer`]: machines computing simple arithmetic
+//! - [`string_copier`]: a machine duplicating its input
+//! - [`palindromes`] and [`zeros_then_ones`]: recognizers for classic languages
+//! - [`universal`]: a small universal machine simulating any binary machine
+#[doc(inline)]
+pub use self::{arithmetic::*, busy_beaver::*, languages::*, strings::*, universal::*};
+
+mod arithmetic;
+mod busy_beaver;
+mod languages;
+mod strings;
+mod universal;
+
+use crate::programs::Program;
+use crate::rules::{Direction, Rule};
+use rstm_state::RawState;
+
+/// builds a program from the given table of transitions, beginning in the given state
+pub(crate) fn from_table<Q, A, I>(initial: Q, table: I) -> Program<Q, A>
+where
+    Q: RawState,
+    I: IntoIterator<Item = (Q, A, Direction, Q, A)>,
+{
+    table
+        .into_iter()
+        .map(|(q, a, d, p, b)| Rule::from_parts(q, a, d, p, b))
+        .collect::<Program<Q, A>>()
+        .with_default_state(initial)
+}
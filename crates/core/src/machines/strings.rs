@@ -0,0 +1,62 @@
+/*
+    Appellation: strings <module>
+    Created At: 2026.10.19:23:44:37
+    Contrib: @FL03
+*/
+use super::from_table;
+use crate::programs::{CANONICAL_HALT, Program};
+use crate::rules::Direction::{Left, Right};
+
+/// returns a machine copying a word over `{a, b}`, leaving `w#w` on the tape for the input
+/// `w`. The machine marks each symbol of the word in turn (i.e. `A` or `B`), carrying it to
+/// the end of the copy, before restoring the original and halting with the head over the first
+/// symbol of the word. For a word of length _n_, the machine runs in _O(n²)_ steps.
+pub fn string_copier() -> Program<usize, char> {
+    const SEEK_END: usize = 0;
+    const REWIND: usize = 1;
+    const PICK: usize = 2;
+    const CARRY_A: usize = 3;
+    const WRITE_A: usize = 4;
+    const CARRY_B: usize = 5;
+    const WRITE_B: usize = 6;
+    const RETURN: usize = 7;
+    const RESTORE: usize = 8;
+    from_table(
+        SEEK_END,
+        [
+            // append the separator to the end of the word before returning to its start
+            (SEEK_END, 'a', Right, SEEK_END, 'a'),
+            (SEEK_END, 'b', Right, SEEK_END, 'b'),
+            (SEEK_END, '\0', Left, REWIND, '#'),
+            (REWIND, 'a', Left, REWIND, 'a'),
+            (REWIND, 'b', Left, REWIND, 'b'),
+            (REWIND, '\0', Right, PICK, '\0'),
+            // mark the next symbol of the word, carrying it to the end of the copy
+            (PICK, 'a', Right, CARRY_A, 'A'),
+            (PICK, 'b', Right, CARRY_B, 'B'),
+            (PICK, '#', Left, RESTORE, '#'),
+            (CARRY_A, 'a', Right, CARRY_A, 'a'),
+            (CARRY_A, 'b', Right, CARRY_A, 'b'),
+            (CARRY_A, '#', Right, WRITE_A, '#'),
+            (WRITE_A, 'a', Right, WRITE_A, 'a'),
+            (WRITE_A, 'b', Right, WRITE_A, 'b'),
+            (WRITE_A, '\0', Left, RETURN, 'a'),
+            (CARRY_B, 'a', Right, CARRY_B, 'a'),
+            (CARRY_B, 'b', Right, CARRY_B, 'b'),
+            (CARRY_B, '#', Right, WRITE_B, '#'),
+            (WRITE_B, 'a', Right, WRITE_B, 'a'),
+            (WRITE_B, 'b', Right, WRITE_B, 'b'),
+            (WRITE_B, '\0', Left, RETURN, 'b'),
+            // return to the last marked symbol
+            (RETURN, 'a', Left, RETURN, 'a'),
+            (RETURN, 'b', Left, RETURN, 'b'),
+            (RETURN, '#', Left, RETURN, '#'),
+            (RETURN, 'A', Right, PICK, 'A'),
+            (RETURN, 'B', Right, PICK, 'B'),
+            // unmark the word before halting at its start
+            (RESTORE, 'A', Left, RESTORE, 'a'),
+            (RESTORE, 'B', Left, RESTORE, 'b'),
+            (RESTORE, '\0', Right, CANONICAL_HALT, '\0'),
+        ],
+    )
+}
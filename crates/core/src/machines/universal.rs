@@ -0,0 +1,258 @@
+/*
+    Appellation: universal <module>
+    Created At: 2026.10.19:23:55:40
+    Contrib: @FL03
+*/
+use super::from_table;
+use crate::programs::{CANONICAL_HALT, Program};
+use crate::rules::Direction::{self, Left, Right, Stay};
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// the symbols written by the [`universal`] machine, excluding the blank
+const SYMBOLS: [char; 17] = [
+    '^', '|', '>', '-', '*', ',', '#', '0', '1', 'o', 'i', 'L', 'R', 'u', 'x', 'a', 'b',
+];
+
+// the states of the universal machine
+const SCAN_HEAD: usize = 0;
+const FETCH_0: usize = 1;
+const FETCH_1: usize = 2;
+const SKIP_ENTRY: usize = 3;
+const ENTRY: usize = 4;
+const DIR_0: usize = 5;
+const DIR_1: usize = 6;
+const GOTO_0L: usize = 7;
+const GOTO_0R: usize = 8;
+const GOTO_1L: usize = 9;
+const GOTO_1R: usize = 10;
+const MARK_L: usize = 11;
+const MARK_R: usize = 12;
+const SHIFT_0: usize = 13;
+const SHIFT_1: usize = 14;
+const SHIFT_A: usize = 15;
+const SHIFT_B: usize = 16;
+const SEEK_MARK: usize = 17;
+const SKIP_DIR: usize = 18;
+const COUNT: usize = 19;
+const REWIND_CURSOR: usize = 20;
+const SEEK_CURSOR: usize = 21;
+const REWIND_FIRST: usize = 22;
+const NEXT_SEP: usize = 23;
+const REWIND_X: usize = 24;
+const FIND_X: usize = 25;
+const RESTORE: usize = 26;
+const REWIND_CLEAR: usize = 27;
+const CLEAR: usize = 28;
+const REWIND_SET: usize = 29;
+const SET: usize = 30;
+const FLAG: usize = 31;
+const REWIND_CYCLE: usize = 32;
+
+/// returns a small universal machine simulating any two-symbol machine, as encoded by
+/// [`encode_universal`], before halting once the simulated machine halts; the resulting tape
+/// of the simulated machine may then be recovered using [`decode_universal`].
+///
+/// The tape of the universal machine begins with the transition table of the simulated
+/// machine, followed by its tape:
+///
+/// ```text
+/// ^|*0Ru,1Lu|-1Lu,1R#a
+/// ```
+///
+/// Each state is written as a block beginning with `|` and a flag, where `*` marks the current
+/// state, followed by its transitions upon reading `0` and `1`, separated by `,`. Every
+/// transition consists of the symbol to write, the direction to move in, and the next state
+/// written in unary (i.e. `u` repeated _k_ times for the _k_-th block), where the halting
+/// state is written as the empty word. The simulated tape follows the `#`, where `a` and `b`
+/// denote the head over a `0` and `1`, respectively.
+///
+/// Each step of the simulated machine is carried out by fetching the symbol under the
+/// simulated head, reading the transition of the current state, applying it to the simulated
+/// tape, and finally moving the flag onto the next state by counting its unary digits. Moving
+/// the simulated head beyond the left end of its tape shifts the tape rightwards by a cell.
+pub fn universal() -> Program<usize, char> {
+    let mut table = Vec::new();
+    // move in the given direction, leaving every symbol other than the given ones untouched
+    let mut scan = |state: usize, direction: Direction, stops: &[char]| {
+        table.extend(
+            SYMBOLS
+                .iter()
+                .filter(|s| !stops.contains(s))
+                .map(|&s| (state, s, direction, state, s)),
+        );
+    };
+    scan(SCAN_HEAD, Right, &['a', 'b']);
+    scan(FETCH_0, Left, &['*']);
+    scan(FETCH_1, Left, &['*']);
+    scan(SKIP_ENTRY, Right, &[',']);
+    scan(GOTO_0L, Right, &['a', 'b']);
+    scan(GOTO_0R, Right, &['a', 'b']);
+    scan(GOTO_1L, Right, &['a', 'b']);
+    scan(GOTO_1R, Right, &['a', 'b']);
+    scan(SEEK_MARK, Left, &['o', 'i']);
+    scan(REWIND_CURSOR, Left, &['^']);
+    scan(SEEK_CURSOR, Right, &['>', '#']);
+    scan(REWIND_FIRST, Left, &['^']);
+    scan(NEXT_SEP, Right, &['|']);
+    scan(REWIND_X, Left, &['^']);
+    scan(FIND_X, Right, &['x']);
+    scan(REWIND_CLEAR, Left, &['^']);
+    scan(CLEAR, Right, &['*']);
+    scan(REWIND_SET, Left, &['^']);
+    scan(SET, Right, &['>', '#']);
+    scan(REWIND_CYCLE, Left, &['^']);
+    // the remaining transitions
+    table.extend([
+        // fetch the symbol under the simulated head before finding the current state
+        (SCAN_HEAD, 'a', Left, FETCH_0, 'a'),
+        (SCAN_HEAD, 'b', Left, FETCH_1, 'b'),
+        (FETCH_0, '*', Right, ENTRY, '*'),
+        (FETCH_1, '*', Right, SKIP_ENTRY, '*'),
+        (SKIP_ENTRY, ',', Right, ENTRY, ','),
+        // mark the transition while reading the symbol to write and the direction
+        (ENTRY, '0', Right, DIR_0, 'o'),
+        (ENTRY, '1', Right, DIR_1, 'i'),
+        (DIR_0, 'L', Right, GOTO_0L, 'L'),
+        (DIR_0, 'R', Right, GOTO_0R, 'R'),
+        (DIR_1, 'L', Right, GOTO_1L, 'L'),
+        (DIR_1, 'R', Right, GOTO_1R, 'R'),
+        // write the symbol under the simulated head before moving it
+        (GOTO_0L, 'a', Left, MARK_L, '0'),
+        (GOTO_0L, 'b', Left, MARK_L, '0'),
+        (GOTO_0R, 'a', Right, MARK_R, '0'),
+        (GOTO_0R, 'b', Right, MARK_R, '0'),
+        (GOTO_1L, 'a', Left, MARK_L, '1'),
+        (GOTO_1L, 'b', Left, MARK_L, '1'),
+        (GOTO_1R, 'a', Right, MARK_R, '1'),
+        (GOTO_1R, 'b', Right, MARK_R, '1'),
+        (MARK_L, '0', Left, SEEK_MARK, 'a'),
+        (MARK_L, '1', Left, SEEK_MARK, 'b'),
+        (MARK_L, '#', Right, SHIFT_A, '#'),
+        (MARK_R, '0', Left, SEEK_MARK, 'a'),
+        (MARK_R, '1', Left, SEEK_MARK, 'b'),
+        (MARK_R, '\0', Left, SEEK_MARK, 'a'),
+        // return to the marked transition, unmarking it before counting the next state
+        (SEEK_MARK, 'o', Right, SKIP_DIR, '0'),
+        (SEEK_MARK, 'i', Right, SKIP_DIR, '1'),
+        (SKIP_DIR, 'L', Right, COUNT, 'L'),
+        (SKIP_DIR, 'R', Right, COUNT, 'R'),
+        // advance the cursor by a block for every unary digit of the next state
+        (COUNT, 'x', Right, COUNT, 'x'),
+        (COUNT, 'u', Left, REWIND_CURSOR, 'x'),
+        (COUNT, ',', Left, RESTORE, ','),
+        (COUNT, '|', Left, RESTORE, '|'),
+        (COUNT, '>', Left, RESTORE, '>'),
+        (COUNT, '#', Left, RESTORE, '#'),
+        (REWIND_CURSOR, '^', Right, SEEK_CURSOR, '^'),
+        (SEEK_CURSOR, '>', Right, NEXT_SEP, '|'),
+        (SEEK_CURSOR, '#', Left, REWIND_FIRST, '#'),
+        (REWIND_FIRST, '^', Right, NEXT_SEP, '^'),
+        (NEXT_SEP, '|', Left, REWIND_X, '>'),
+        (REWIND_X, '^', Right, FIND_X, '^'),
+        (FIND_X, 'x', Right, COUNT, 'x'),
+        // restore the unary digits before moving the flag onto the cursor
+        (RESTORE, 'x', Left, RESTORE, 'u'),
+        (RESTORE, 'L', Left, REWIND_CLEAR, 'L'),
+        (RESTORE, 'R', Left, REWIND_CLEAR, 'R'),
+        (REWIND_CLEAR, '^', Right, CLEAR, '^'),
+        (CLEAR, '*', Left, REWIND_SET, '-'),
+        (REWIND_SET, '^', Right, SET, '^'),
+        (SET, '>', Right, FLAG, '|'),
+        (SET, '#', Stay, CANONICAL_HALT, '#'),
+        (FLAG, '-', Left, REWIND_CYCLE, '*'),
+        (REWIND_CYCLE, '^', Right, SCAN_HEAD, '^'),
+    ]);
+    // shift the simulated tape rightwards, carrying each symbol onto the next cell
+    for (state, carry) in [
+        (SHIFT_0, '0'),
+        (SHIFT_1, '1'),
+        (SHIFT_A, 'a'),
+        (SHIFT_B, 'b'),
+    ] {
+        for (next, symbol) in [
+            (SHIFT_0, '0'),
+            (SHIFT_1, '1'),
+            (SHIFT_A, 'a'),
+            (SHIFT_B, 'b'),
+        ] {
+            table.push((state, symbol, Right, next, carry));
+        }
+        table.push((state, '\0', Left, SEEK_MARK, carry));
+    }
+    from_table(SCAN_HEAD, table)
+}
+
+/// encodes the given two-symbol machine alongside its input onto the tape of the
+/// [`universal`] machine, returning `None` should the machine use any symbol other than `0`
+/// or `1`, stay in place, or leave a transition of a non-halting state undefined. The states
+/// without any rules are considered to be halting.
+pub fn encode_universal(program: &Program<usize, usize>, input: &[usize]) -> Option<Vec<char>> {
+    let initial = *program.initial_state()?.get();
+    // the initial state is always written as the first block
+    let mut states = vec![initial];
+    for rule in program.iter() {
+        let state = *rule.state().get();
+        if !states.contains(&state) {
+            states.push(state);
+        }
+    }
+    let bit = |symbol: usize| match symbol {
+        0 => Some('0'),
+        1 => Some('1'),
+        _ => None,
+    };
+    let mut tape = vec!['^'];
+    for (i, &state) in states.iter().enumerate() {
+        tape.extend(['|', if i == 0 { '*' } else { '-' }]);
+        for symbol in [0, 1] {
+            if symbol == 1 {
+                tape.push(',');
+            }
+            let rule = program
+                .iter()
+                .find(|rule| *rule.state().get() == state && *rule.symbol() == symbol)?;
+            tape.push(bit(*rule.write_symbol())?);
+            tape.push(match rule.direction() {
+                Direction::Left => 'L',
+                Direction::Right => 'R',
+                Direction::Stay => return None,
+            });
+            // the halting states, having no rules, are written as the empty word
+            let next = states
+                .iter()
+                .position(|q| q == rule.next_state().get())
+                .map_or(0, |idx| idx + 1);
+            tape.extend(core::iter::repeat_n('u', next));
+        }
+    }
+    tape.push('#');
+    match input.split_first() {
+        Some((&first, rest)) => {
+            tape.push(if bit(first)? == '1' { 'b' } else { 'a' });
+            for &symbol in rest {
+                tape.push(bit(symbol)?);
+            }
+        }
+        None => tape.push('a'),
+    }
+    Some(tape)
+}
+
+/// decodes the tape of the simulated machine from the tape of the [`universal`] machine,
+/// returning `None` should it not hold a valid encoding.
+pub fn decode_universal<'a, I>(tape: I) -> Option<Vec<usize>>
+where
+    I: IntoIterator<Item = &'a char>,
+{
+    tape.into_iter()
+        .skip_while(|&&s| s != '#')
+        .skip(1)
+        .take_while(|&&s| s != '\0')
+        .map(|s| match s {
+            '0' | 'a' => Some(0),
+            '1' | 'b' => Some(1),
+            _ => None,
+        })
+        .collect()
+}
@@ -11,6 +11,9 @@
 /// ```ignore
 /// program! {
 ///     #[default_state(initial_state)] // optional
+///     #[halt(state, ...)] // optional
+///     #[accept(state, ...)] // optional
+///     #[reject(state, ...)] // optional
 ///     rules: {(state, symbol) -> direction(next_state, write_symbol); ...};
 /// }
 /// ```
@@ -33,16 +36,40 @@
 ///     };
 /// };
 /// ```
+///
+/// ## Halting States
+///
+/// States may be explicitly declared as halting, accepting, or rejecting their input using
+/// the optional `halt`, `accept`, and `reject` attributes, respectively:
+///
+/// ```rust
+/// let program = rstm_core::program! {
+///     #[default_state(0)]
+///     #[accept(1)]
+///     #[reject(2)]
+///     rules: {
+///         (0, 0) -> Right(2, 0),
+///         (0, 1) -> Right(1, 1),
+///     };
+/// };
+/// assert_eq!(program.halt_kind(&1), Some(rstm_core::HaltKind::Accept));
+/// ```
 #[cfg(feature = "alloc")]
 #[macro_export]
 macro_rules! program {
     {
         $(#[default_state($ds:expr)])?
+        $(#[halt($($halt:expr),* $(,)?)])?
+        $(#[accept($($accept:expr),* $(,)?)])?
+        $(#[reject($($reject:expr),* $(,)?)])?
         rules: {$(($state:expr, $symbol:expr) -> $direction:ident($next:expr, $write:expr)),* $(,)?} $(;)?
     } => {
         $crate::programs::Program::from_iter(
             $crate::ruleset! [$(($state, $symbol) -> $direction($next, $write)),*]
         )
         $(.with_default_state($ds))?
+        $(.with_halt_states([$($halt),*]))?
+        $(.with_accept_states([$($accept),*]))?
+        $(.with_reject_states([$($reject),*]))?
     };
 }
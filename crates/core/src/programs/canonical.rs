@@ -0,0 +1,267 @@
+/*
+    Appellation: canonical <module>
+    Created At: 2026.10.19:09:12:40
+    Contrib: @FL03
+*/
+//! This module implements a canonical normal form for [`Program`]s alongside a stable
+//! fingerprint, enabling programs that only differ by the naming of their states or symbols
+//! to be identified with one another.
+use crate::programs::Program;
+use crate::rules::{Direction, Rule};
+use alloc::collections::{BTreeMap, VecDeque};
+use alloc::vec::Vec;
+use rstm_state::RawState;
+
+/// the label assigned to every _terminal_ state of a canonical program, i.e. states that do
+/// not define any rules of their own. Using `usize::MAX` ensures the label is considered
+/// halted by the [`Halting`](rstm_state::Halting) implementation for `usize`.
+pub const CANONICAL_HALT: usize = usize::MAX;
+
+impl<Q, A> Program<Q, A>
+where
+    Q: RawState + Clone + Ord,
+    A: Clone + Default + Ord,
+{
+    /// returns the canonical normal form of the program.
+    ///
+    /// The states are relabeled in breadth-first order starting from the initial state while
+    /// the symbols are labeled in order of their first appearance, with the blank symbol
+    /// (i.e. `A::default()`) always being assigned `0`. Rules belonging to states that are
+    /// unreachable from the initial state are dropped and every terminal state is mapped onto
+    /// [`CANONICAL_HALT`]. When `mirror` is true, the program is additionally normalized
+    /// w.r.t. its left-right mirror image.
+    ///
+    /// **Note**: the rules of each state are visited in order of their (already labeled) read
+    /// symbols. Whenever the next rule cannot be chosen without relying upon the naming of
+    /// the symbols, e.g. when every remaining rule reads a symbol that is yet to be labeled,
+    /// the choices are first narrowed down to those producing the smallest relabeled rule
+    /// before each of the remaining choices is explored and the smallest resulting program
+    /// is selected. Only rules that are indistinguishable by their own transitions are thus
+    /// explored separately; in the worst case, e.g. a state whose `k` rules each write their
+    /// own symbol and move in the same direction onto the same state, the cost grows with
+    /// `k!` and callers should avoid canonicalizing such programs over large alphabets.
+    pub fn canonicalize(&self, mirror: bool) -> Program<usize, usize> {
+        Program::from_rules(self.canonical_rules(mirror)).with_default_state(0)
+    }
+    /// computes a stable, 64-bit fingerprint of the program using its canonical form; two
+    /// programs that only differ by the naming of their states and symbols (and, optionally,
+    /// by being mirror images of each other) will always share the same fingerprint.
+    pub fn fingerprint(&self, mirror: bool) -> u64 {
+        use core::hash::{Hash, Hasher};
+
+        let canonical = self.canonicalize(mirror);
+        let mut hasher = StableHasher::new();
+        canonical.initial_state().hash(&mut hasher);
+        canonical.rules().hash(&mut hasher);
+        hasher.finish()
+    }
+    /// relabels the reachable rules of the program, returning them in sorted order
+    fn canonical_rules(&self, mirror: bool) -> Vec<Rule<usize, usize>> {
+        // group the rules by their current state, preserving their relative order
+        let mut by_state: BTreeMap<&Q, Vec<&Rule<Q, A>>> = BTreeMap::new();
+        for rule in self.iter() {
+            by_state.entry(rule.state().get()).or_default().push(rule);
+        }
+        let Some(initial) = self
+            .initial_state()
+            .or_else(|| self.iter().next().map(|rule| rule.state()))
+        else {
+            return Vec::new();
+        };
+        let blank = A::default();
+        let mut labeling = Labeling {
+            symbols: BTreeMap::from([(&blank, 0)]),
+            states: BTreeMap::new(),
+            queue: VecDeque::new(),
+            current: 0,
+            pending: Vec::new(),
+            rules: Vec::new(),
+        };
+        if by_state.contains_key(initial.get()) {
+            labeling.states.insert(initial.get(), 0);
+            labeling.queue.push_back(initial.get());
+        }
+        let mut best = None;
+        labeling.explore(&by_state, mirror, &mut best);
+        best.unwrap_or_default()
+    }
+}
+
+/// [`Labeling`] is the intermediate state of relabeling a program, which is forked whenever
+/// the next rule to visit cannot be chosen independently of the naming of the symbols
+#[derive(Clone)]
+struct Labeling<'a, Q, A>
+where
+    Q: RawState,
+{
+    symbols: BTreeMap<&'a A, usize>,
+    states: BTreeMap<&'a Q, usize>,
+    queue: VecDeque<&'a Q>,
+    current: usize,
+    pending: Vec<&'a Rule<Q, A>>,
+    rules: Vec<Rule<usize, usize>>,
+}
+
+impl<'a, Q, A> Labeling<'a, Q, A>
+where
+    Q: RawState + Clone + Ord,
+    A: Clone + Ord,
+{
+    /// visits the remaining rules, recording the smallest program labeled by any choice
+    fn explore(
+        mut self,
+        by_state: &BTreeMap<&'a Q, Vec<&'a Rule<Q, A>>>,
+        mirror: bool,
+        best: &mut Option<Vec<Rule<usize, usize>>>,
+    ) {
+        loop {
+            if self.pending.is_empty() {
+                let Some(q) = self.queue.pop_front() else {
+                    break;
+                };
+                self.current = self.states[q];
+                self.pending = by_state[q].clone();
+                continue;
+            }
+            // the candidates are the rules reading the symbol with the smallest label or,
+            // when none of their symbols are labeled, every remaining rule
+            let keys = self
+                .pending
+                .iter()
+                .map(|rule| self.symbols.get(rule.symbol()).copied())
+                .collect::<Vec<_>>();
+            let min = keys.iter().flatten().min().copied();
+            let mut candidates = (0..keys.len())
+                .filter(|&idx| keys[idx] == min)
+                .collect::<Vec<_>>();
+            // refine the candidates by the rules they would produce, which doesn't depend
+            // upon the naming of the symbols, before forking over those that remain
+            if candidates.len() > 1 {
+                let previews = candidates
+                    .iter()
+                    .map(|&idx| self.preview(by_state, idx, mirror))
+                    .collect::<Vec<_>>();
+                let min = previews.iter().min().copied();
+                candidates = candidates
+                    .into_iter()
+                    .zip(previews)
+                    .filter_map(|(idx, preview)| (Some(preview) == min).then_some(idx))
+                    .collect();
+            }
+            if let [idx] = candidates[..] {
+                self.visit(by_state, idx);
+            } else {
+                for idx in candidates {
+                    let mut fork = self.clone();
+                    fork.visit(by_state, idx);
+                    fork.explore(by_state, mirror, best);
+                }
+                return;
+            }
+        }
+        let mut rules = self.rules;
+        rules.sort();
+        if mirror {
+            let mut mirrored = rules
+                .iter()
+                .cloned()
+                .map(|mut rule| {
+                    rule.set_direction(rule.direction().reverse());
+                    rule
+                })
+                .collect::<Vec<_>>();
+            mirrored.sort();
+            rules = rules.min(mirrored);
+        }
+        if best.as_ref().is_none_or(|best| rules < *best) {
+            *best = Some(rules);
+        }
+    }
+    /// returns the labels of the next state and written symbol, alongside the direction
+    /// unless the program is to be mirrored, that visiting the pending rule at the given
+    /// index would produce
+    fn preview(
+        &self,
+        by_state: &BTreeMap<&'a Q, Vec<&'a Rule<Q, A>>>,
+        idx: usize,
+        mirror: bool,
+    ) -> (usize, usize, Option<Direction>) {
+        let rule = self.pending[idx];
+        let fresh = self.symbols.len() + usize::from(!self.symbols.contains_key(rule.symbol()));
+        let write = match self.symbols.get(rule.write_symbol()) {
+            Some(&label) => label,
+            None if rule.write_symbol() == rule.symbol() => self.symbols.len(),
+            None => fresh,
+        };
+        let next = rule.next_state().get();
+        let next = match self.states.get(next) {
+            Some(&label) => label,
+            None if by_state.contains_key(next) => self.states.len(),
+            None => CANONICAL_HALT,
+        };
+        (next, write, (!mirror).then(|| rule.direction()))
+    }
+    /// relabels the pending rule at the given index, enqueuing any newly reached states
+    fn visit(&mut self, by_state: &BTreeMap<&'a Q, Vec<&'a Rule<Q, A>>>, idx: usize) {
+        let rule = self.pending.remove(idx);
+        let symbol = label(&mut self.symbols, rule.symbol());
+        let write = label(&mut self.symbols, rule.write_symbol());
+        let next = rule.next_state().get();
+        let next_state = if by_state.contains_key(next) {
+            let n = self.states.len();
+            *self.states.entry(next).or_insert_with(|| {
+                self.queue.push_back(next);
+                n
+            })
+        } else {
+            CANONICAL_HALT
+        };
+        self.rules.push(Rule::from_parts(
+            self.current,
+            symbol,
+            rule.direction(),
+            next_state,
+            write,
+        ));
+    }
+}
+
+/// returns the label of the given key, assigning the next available label if necessary
+fn label<'a, T: Ord>(labels: &mut BTreeMap<&'a T, usize>, key: &'a T) -> usize {
+    let n = labels.len();
+    *labels.entry(key).or_insert(n)
+}
+
+/// A minimal implementation of the 64-bit FNV-1a hashing algorithm whose output does not
+/// depend on the platform, the compiler version, or any random seeds.
+struct StableHasher(u64);
+
+impl StableHasher {
+    const OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    const fn new() -> Self {
+        Self(Self::OFFSET)
+    }
+}
+
+impl core::hash::Hasher for StableHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= byte as u64;
+            self.0 = self.0.wrapping_mul(Self::PRIME);
+        }
+    }
+    // ensure the width of pointer-sized integers doesn't affect the output
+    fn write_usize(&mut self, i: usize) {
+        self.write(&(i as u64).to_le_bytes());
+    }
+
+    fn write_isize(&mut self, i: isize) {
+        self.write(&(i as i64).to_le_bytes());
+    }
+}
@@ -3,9 +3,11 @@
     Created At: 2026.01.11:12:33:32
     Contrib: @FL03
 */
-use crate::programs::{ProgramBase, RawRuleset, Ruleset};
+#[cfg(feature = "serde")]
+use crate::programs::ProgramFormat;
+use crate::programs::{HaltStates, ProgramBase, RawRuleset, Ruleset};
 use crate::rules::{Head, Instruction, Tail};
-use rstm_state::{IntoState, RawState, State};
+use rstm_state::{HaltKind, IntoState, RawState, State};
 
 impl<R, I, Q, A> ProgramBase<R, Q, A, I>
 where
@@ -18,6 +20,7 @@ where
         Self {
             rules,
             initial_state: None,
+            halt_states: HaltStates::new(),
             _marker: core::marker::PhantomData,
         }
     }
@@ -63,6 +66,57 @@ where
             ..self
         }
     }
+    /// returns a reference to the explicitly declared halting states
+    pub const fn halt_states(&self) -> &HaltStates<Q> {
+        &self.halt_states
+    }
+    /// declares the state as halting with the given kind
+    pub fn set_halt_state<U>(&mut self, state: U, kind: HaltKind)
+    where
+        U: IntoState<Q>,
+    {
+        self.halt_states.insert(state.into_state(), kind);
+    }
+    /// consumes the instance to create another declaring the given states as halting, without
+    /// accepting or rejecting their input
+    pub fn with_halt_states<U>(self, states: impl IntoIterator<Item = U>) -> Self
+    where
+        U: IntoState<Q>,
+    {
+        self.with_halt_kind(states, HaltKind::Halt)
+    }
+    /// consumes the instance to create another declaring the given states as accepting
+    pub fn with_accept_states<U>(self, states: impl IntoIterator<Item = U>) -> Self
+    where
+        U: IntoState<Q>,
+    {
+        self.with_halt_kind(states, HaltKind::Accept)
+    }
+    /// consumes the instance to create another declaring the given states as rejecting
+    pub fn with_reject_states<U>(self, states: impl IntoIterator<Item = U>) -> Self
+    where
+        U: IntoState<Q>,
+    {
+        self.with_halt_kind(states, HaltKind::Reject)
+    }
+    /// consumes the instance to create another declaring the given states as halting with the
+    /// given kind
+    fn with_halt_kind<U>(mut self, states: impl IntoIterator<Item = U>, kind: HaltKind) -> Self
+    where
+        U: IntoState<Q>,
+    {
+        states
+            .into_iter()
+            .for_each(|state| self.set_halt_state(state, kind));
+        self
+    }
+    /// returns the kind of halt explicitly declared for the given state, if any
+    pub fn halt_kind(&self, state: &Q) -> Option<HaltKind>
+    where
+        Q: PartialEq,
+    {
+        self.halt_states.kind_of(state)
+    }
     #[cfg(feature = "serde_json")]
     /// serializes the current instance into a JSON string
     pub fn to_json(&self) -> serde_json::Value
@@ -72,7 +126,8 @@ where
         serde_json::to_value(self).expect("Failed to serialize the Program instance")
     }
     #[cfg(all(feature = "json", feature = "std"))]
-    /// export the program to a `.json` file at the given path
+    /// export the program as JSON to the given path, regardless of its extension; this is
+    /// equivalent to calling [`save_as`](Self::save_as) with [`ProgramFormat::Json`].
     ///
     /// **note**: there are no checks to see if the file already exists; it will automatically
     /// be overwritten.
@@ -81,24 +136,97 @@ where
         P: AsRef<std::path::Path>,
         Self: serde::Serialize,
     {
-        let path = path.as_ref();
-        // ensure the filename ends with `.json`
-        if path.extension().map(|os| os.to_str()).flatten() != Some("json") {
-            #[cfg(feature = "tracing")]
-            tracing::error!(
-                "the provided path does not end with `.json`; consider changing the file extension"
-            );
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::InvalidInput,
-                "the provided path does not end with `.json`",
-            ));
-        }
-        let serialized = serde_json::to_string_pretty(self).unwrap();
+        self.save_as(path, ProgramFormat::Json)
+            .map_err(|error| match error {
+                crate::Error::IOError(error) => error,
+                error => std::io::Error::other(error),
+            })
+    }
+    #[cfg(all(feature = "serde", feature = "std"))]
+    /// load a program from the file at the given path, inferring the format from its extension
+    pub fn load<P>(path: P) -> crate::Result<Self>
+    where
+        P: AsRef<std::path::Path>,
+        Self: serde::de::DeserializeOwned,
+    {
+        let format = ProgramFormat::from_path(&path).ok_or(crate::Error::UnknownFormat)?;
+        Self::load_as(path, format)
+    }
+    #[cfg(all(feature = "serde", feature = "std"))]
+    /// load a program from the file at the given path using the specified format
+    pub fn load_as<P>(path: P, format: ProgramFormat) -> crate::Result<Self>
+    where
+        P: AsRef<std::path::Path>,
+        Self: serde::de::DeserializeOwned,
+    {
+        let contents = std::fs::read_to_string(path)?;
+        Self::deserialize_as(&contents, format)
+    }
+    #[cfg(all(feature = "serde", feature = "std"))]
+    /// save the program to the given path, inferring the format from its extension
+    ///
+    /// **note**: there are no checks to see if the file already exists; it will automatically
+    /// be overwritten.
+    pub fn save<P>(&self, path: P) -> crate::Result<()>
+    where
+        P: AsRef<std::path::Path>,
+        Self: serde::Serialize,
+    {
+        let format = ProgramFormat::from_path(&path).ok_or(crate::Error::UnknownFormat)?;
+        self.save_as(path, format)
+    }
+    #[cfg(all(feature = "serde", feature = "std"))]
+    /// save the program to the given path using the specified format, regardless of the
+    /// extension of the path
+    pub fn save_as<P>(&self, path: P, format: ProgramFormat) -> crate::Result<()>
+    where
+        P: AsRef<std::path::Path>,
+        Self: serde::Serialize,
+    {
+        let serialized = self.serialize_as(format)?;
         std::fs::write(path, serialized)?;
         #[cfg(feature = "tracing")]
-        tracing::info!("Program exported as JSON");
+        tracing::info!("Program saved as {format}");
         Ok(())
     }
+    #[cfg(feature = "serde")]
+    #[allow(unreachable_patterns, unused_variables)]
+    /// deserialize a program from the given string using the specified format
+    pub fn deserialize_as(input: &str, format: ProgramFormat) -> crate::Result<Self>
+    where
+        Self: serde::de::DeserializeOwned,
+    {
+        match format {
+            #[cfg(feature = "serde_json")]
+            ProgramFormat::Json => Ok(serde_json::from_str(input)?),
+            #[cfg(feature = "yaml")]
+            ProgramFormat::Yaml => Ok(serde_yaml::from_str(input)?),
+            #[cfg(feature = "toml")]
+            ProgramFormat::Toml => Ok(toml::from_str(input)?),
+            #[cfg(feature = "ron")]
+            ProgramFormat::Ron => Ok(ron::from_str(input)?),
+            _ => Err(crate::Error::UnsupportedFormat(format)),
+        }
+    }
+    #[cfg(feature = "serde")]
+    #[allow(unreachable_patterns)]
+    /// serialize the program into a string using the specified format
+    pub fn serialize_as(&self, format: ProgramFormat) -> crate::Result<alloc::string::String>
+    where
+        Self: serde::Serialize,
+    {
+        match format {
+            #[cfg(feature = "serde_json")]
+            ProgramFormat::Json => Ok(serde_json::to_string_pretty(self)?),
+            #[cfg(feature = "yaml")]
+            ProgramFormat::Yaml => Ok(serde_yaml::to_string(self)?),
+            #[cfg(feature = "toml")]
+            ProgramFormat::Toml => Ok(toml::to_string_pretty(self)?),
+            #[cfg(feature = "ron")]
+            ProgramFormat::Ron => Ok(ron::ser::to_string_pretty(self, Default::default())?),
+            _ => Err(crate::Error::UnsupportedFormat(format)),
+        }
+    }
 
     pub fn get_head(&self, head: &Head<Q, A>) -> Option<&Tail<Q, A>>
     where
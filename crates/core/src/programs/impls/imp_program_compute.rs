@@ -0,0 +1,56 @@
+/*
+    Appellation: imp_program_compute <module>
+    Created At: 2026.10.19:23:19:36
+    Contrib: @FL03
+*/
+use crate::actors::Configuration;
+use crate::compute::TapeCodec;
+use crate::programs::Program;
+use alloc::vec::Vec;
+use rstm_state::{Halting, RawState};
+
+impl<Q, A> Program<Q, A>
+where
+    Q: RawState + Clone + Halting + PartialEq,
+    A: Clone + Default + PartialEq,
+{
+    /// computes the output of the program for the given input; the input is encoded onto an
+    /// otherwise blank tape using the codec, beginning with the head over its first symbol,
+    /// and the output is decoded from the region of the tape defined by the codec once the
+    /// machine halts. Reaching a head without a rule is treated as halting, while running
+    /// for more than `step_limit` steps is reported as an error.
+    pub fn compute<C>(
+        &self,
+        codec: &C,
+        input: &C::Value,
+        step_limit: usize,
+    ) -> crate::Result<C::Value>
+    where
+        C: TapeCodec<A>,
+    {
+        let initial = self.initial_state().ok_or(crate::Error::NoInitialState)?;
+        let mut config = Configuration::from_input(initial.get().clone(), codec.encode(input));
+        loop {
+            let state = config.state().get();
+            if self
+                .halt_kind(state)
+                .or_else(|| state.halt_kind())
+                .is_some()
+            {
+                break;
+            }
+            if config.steps() >= step_limit {
+                return Err(crate::Error::StepLimitExceeded(step_limit));
+            }
+            match config.step(self) {
+                Ok(()) => {}
+                Err(crate::Error::NoRuleFound) => break,
+                Err(error) => return Err(error),
+            }
+        }
+        let tape = config.tape().iter().cloned().collect::<Vec<_>>();
+        codec
+            .decode(codec.region(&tape, config.head_index()))
+            .ok_or(crate::Error::DecodeError)
+    }
+}
@@ -0,0 +1,74 @@
+/*
+    Appellation: imp_program_language <module>
+    Created At: 2026.10.19:22:51:07
+    Contrib: @FL03
+*/
+use crate::actors::Configuration;
+use crate::programs::{Program, Verdict};
+use alloc::vec;
+use alloc::vec::Vec;
+use rstm_state::{HaltKind, Halting, RawState};
+
+impl<Q, A> Program<Q, A>
+where
+    Q: RawState + Clone + Halting + PartialEq,
+    A: Clone + Default + PartialEq,
+{
+    /// runs the program upon the given input, beginning with the head over its first symbol,
+    /// for at most `step_limit` steps before reporting its [`Verdict`]. The halting states
+    /// declared by the program take precedence over the [`Halting`] implementation of the
+    /// state; a machine halting without accepting is considered to reject its input.
+    pub fn accepts<I>(&self, input: I, step_limit: usize) -> crate::Result<Verdict>
+    where
+        I: IntoIterator<Item = A>,
+    {
+        let initial = self.initial_state().ok_or(crate::Error::NoInitialState)?;
+        let mut config = Configuration::from_input(initial.get().clone(), input);
+        loop {
+            let state = config.state().get();
+            let steps = config.steps();
+            match self.halt_kind(state).or_else(|| state.halt_kind()) {
+                Some(HaltKind::Accept) => return Ok(Verdict::Accept { steps }),
+                Some(HaltKind::Reject | HaltKind::Halt) => return Ok(Verdict::Reject { steps }),
+                None if steps >= step_limit => return Ok(Verdict::Timeout { steps }),
+                None => {}
+            }
+            if config.step(self).is_err() {
+                return Ok(Verdict::NoTransition { steps });
+            }
+        }
+    }
+    /// returns every word over the given alphabet of length at most `n` that is accepted by
+    /// the program within the step limit, in length-lexicographic order. Words for which the
+    /// machine times out are not considered to be accepted.
+    pub fn accepted_words(
+        &self,
+        alphabet: &[A],
+        n: usize,
+        step_limit: usize,
+    ) -> crate::Result<Vec<Vec<A>>> {
+        let n = if alphabet.is_empty() { 0 } else { n };
+        let mut words = Vec::new();
+        for len in 0..=n {
+            // the indices of each symbol of the current word within the alphabet
+            let mut digits = vec![0usize; len];
+            loop {
+                let word = digits
+                    .iter()
+                    .map(|&i| alphabet[i].clone())
+                    .collect::<Vec<_>>();
+                if self.accepts(word.clone(), step_limit)?.is_accept() {
+                    words.push(word);
+                }
+                match digits.iter().rposition(|&i| i + 1 < alphabet.len()) {
+                    Some(pos) => {
+                        digits[pos] += 1;
+                        digits[pos + 1..].fill(0);
+                    }
+                    None => break,
+                }
+            }
+        }
+        Ok(words)
+    }
+}
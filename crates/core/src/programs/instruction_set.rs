@@ -179,7 +179,7 @@ where
         self.iter().filter(|i| *i.head() == state).collect()
     }
     #[cfg(all(feature = "json", feature = "std"))]
-    /// export the program to a `.json` file at the given path
+    /// export the program as JSON to the given path, regardless of its extension
     ///
     /// **note**: there are no checks to see if the file already exists; it will automatically
     /// be overwritten.
@@ -189,19 +189,7 @@ where
         Q: serde::Serialize,
         A: serde::Serialize,
     {
-        let path = path.as_ref();
-        // ensure the filename ends with `.json`
-        if path.extension().map(|os| os.to_str()).flatten() != Some("json") {
-            #[cfg(feature = "tracing")]
-            tracing::error!(
-                "the provided path does not end with `.json`; consider changing the file extension"
-            );
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::InvalidInput,
-                "the provided path does not end with `.json`",
-            ));
-        }
-        let serialized = serde_json::to_string_pretty(self).unwrap();
+        let serialized = serde_json::to_string_pretty(self).map_err(std::io::Error::other)?;
         std::fs::write(path, serialized)?;
         #[cfg(feature = "tracing")]
         tracing::info!("Program exported as JSON");
@@ -0,0 +1,449 @@
+/*
+    Appellation: interner <module>
+    Created At: 2026.10.19:21:52:14
+    Contrib: @FL03
+*/
+//! This module provides an [`Interner`] for mapping the states and symbols of a program onto
+//! compact integer ids, enabling programs using readable states and symbols (e.g. `String`s)
+//! to be executed without cloning or comparing them on every step.
+use crate::programs::Program;
+use crate::rules::Rule;
+use alloc::collections::BTreeSet;
+use alloc::vec::Vec;
+use rstm_state::{Halting, RawState, State};
+
+#[cfg(feature = "hashbrown")]
+use hashbrown::{HashMap, HashSet};
+#[cfg(all(not(feature = "hashbrown"), feature = "std"))]
+use std::collections::{HashMap, HashSet};
+
+/// [`StateId`] is the compact id assigned to a state by an [`Interner`]. The most significant
+/// bit flags halting states, allowing the id to be checked for halting without resolving it,
+/// so the index of a state may not exceed [`StateId::MAX_INDEX`].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(transparent)
+)]
+#[repr(transparent)]
+pub struct StateId(pub(crate) u32);
+
+/// [`SymId`] is the compact id assigned to a symbol by an [`Interner`]; the blank (i.e.
+/// `A::default()`) is always assigned [`SymId::BLANK`].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(transparent)
+)]
+#[repr(transparent)]
+pub struct SymId(pub(crate) u32);
+
+/// [`InternTable`] assigns sequential indices to the values it is given, mapping them in
+/// either direction. It is serialized as the sequence of its values.
+#[derive(Clone, Debug)]
+pub struct InternTable<T> {
+    pub(crate) values: Vec<T>,
+    pub(crate) indices: HashMap<T, u32>,
+}
+
+/// The [`Interner`] maintains a pair of [`InternTable`]s mapping the states and symbols of a
+/// program onto [`StateId`]s and [`SymId`]s, respectively. Programs may be converted into
+/// their interned form, executed, and their results mapped back using the same interner.
+#[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(
+        bound(
+            serialize = "Q: serde::Serialize, A: serde::Serialize",
+            deserialize = "Q: Clone + Eq + core::hash::Hash + serde::Deserialize<'de>, \
+                A: Clone + Eq + core::hash::Hash + serde::Deserialize<'de>"
+        ),
+        rename_all = "snake_case"
+    )
+)]
+pub struct Interner<Q, A> {
+    pub(crate) states: InternTable<Q>,
+    /// the halting states, by index
+    pub(crate) halting: BTreeSet<u32>,
+    pub(crate) symbols: InternTable<A>,
+}
+
+impl StateId {
+    const HALT: u32 = 1 << 31;
+    /// the largest index that may be assigned to a state, as the most significant bit is
+    /// reserved for flagging halting states
+    pub const MAX_INDEX: u32 = Self::HALT - 1;
+    /// returns the id of the state with the given index, flagging it as halting when `halt`
+    /// is true; returns `None` should the index exceed [`MAX_INDEX`](Self::MAX_INDEX).
+    pub const fn checked(index: u32, halt: bool) -> Option<Self> {
+        if index > Self::MAX_INDEX {
+            None
+        } else if halt {
+            Some(Self(index | Self::HALT))
+        } else {
+            Some(Self(index))
+        }
+    }
+    /// returns the id of the non-halting state with the given index
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the index exceeds [`MAX_INDEX`](Self::MAX_INDEX).
+    pub const fn new(index: u32) -> Self {
+        match Self::checked(index, false) {
+            Some(id) => id,
+            None => panic!("the index of the state overlaps with the halt flag"),
+        }
+    }
+    /// returns the id of the halting state with the given index
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the index exceeds [`MAX_INDEX`](Self::MAX_INDEX).
+    pub const fn halt(index: u32) -> Self {
+        match Self::checked(index, true) {
+            Some(id) => id,
+            None => panic!("the index of the state overlaps with the halt flag"),
+        }
+    }
+    /// returns the index of the state within its table
+    pub const fn index(self) -> usize {
+        (self.0 & !Self::HALT) as usize
+    }
+    /// returns true if the id belongs to a halting state
+    pub const fn is_halt(self) -> bool {
+        self.0 & Self::HALT != 0
+    }
+}
+
+impl SymId {
+    /// the id of the blank symbol
+    pub const BLANK: Self = Self(0);
+    /// returns the id of the symbol with the given index
+    pub const fn new(index: u32) -> Self {
+        Self(index)
+    }
+    /// returns the index of the symbol within its table
+    pub const fn index(self) -> usize {
+        self.0 as usize
+    }
+    /// returns true if the id belongs to the blank symbol
+    pub const fn is_blank(self) -> bool {
+        self.0 == Self::BLANK.0
+    }
+}
+
+impl<T> InternTable<T> {
+    /// returns a new, empty table
+    pub fn new() -> Self {
+        Self {
+            values: Vec::new(),
+            indices: HashMap::new(),
+        }
+    }
+    /// returns the number of values within the table
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+    /// returns true if the table contains no values
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+    /// returns a reference to the value with the given index, if any
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.values.get(index)
+    }
+    /// returns the index of the given value, if it has been interned
+    pub fn index_of(&self, value: &T) -> Option<u32>
+    where
+        T: Eq + core::hash::Hash,
+    {
+        self.indices.get(value).copied()
+    }
+    /// interns the value, returning its index
+    pub fn intern(&mut self, value: &T) -> u32
+    where
+        T: Clone + Eq + core::hash::Hash,
+    {
+        if let Some(index) = self.index_of(value) {
+            return index;
+        }
+        let index = u32::try_from(self.values.len()).expect("the table is full");
+        self.values.push(value.clone());
+        self.indices.insert(value.clone(), index);
+        index
+    }
+    /// returns an iterator over the values of the table, in order of their indices
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
+        self.values.iter()
+    }
+}
+
+impl<Q, A> Interner<Q, A>
+where
+    Q: Clone + Eq + core::hash::Hash,
+    A: Clone + Default + Eq + core::hash::Hash,
+{
+    /// returns a new interner whose only symbol is the blank
+    pub fn new() -> Self {
+        let mut symbols = InternTable::new();
+        symbols.intern(&A::default());
+        Self {
+            states: InternTable::new(),
+            halting: BTreeSet::new(),
+            symbols,
+        }
+    }
+    /// returns a reference to the table of states
+    pub const fn states(&self) -> &InternTable<Q> {
+        &self.states
+    }
+    /// returns a reference to the table of symbols
+    pub const fn symbols(&self) -> &InternTable<A> {
+        &self.symbols
+    }
+    /// returns the id of the given state, if it has been interned
+    pub fn state_id(&self, state: &Q) -> Option<StateId> {
+        let index = self.states.index_of(state)?;
+        if self.halting.contains(&index) {
+            Some(StateId::halt(index))
+        } else {
+            Some(StateId::new(index))
+        }
+    }
+    /// returns the id of the given symbol, if it has been interned
+    pub fn symbol_id(&self, symbol: &A) -> Option<SymId> {
+        self.symbols.index_of(symbol).map(SymId)
+    }
+    /// returns a reference to the state with the given id, if any
+    pub fn state(&self, id: StateId) -> Option<&Q> {
+        self.states.get(id.index())
+    }
+    /// returns a reference to the symbol with the given id, if any
+    pub fn symbol(&self, id: SymId) -> Option<&A> {
+        self.symbols.get(id.index())
+    }
+    /// interns the state, flagging it as halting when `halt` is true; an error is returned
+    /// should the table of states already hold [`StateId::MAX_INDEX`] + 1 states.
+    pub fn intern_state(&mut self, state: &Q, halt: bool) -> crate::Result<StateId> {
+        let limit = StateId::MAX_INDEX as usize + 1;
+        if self.states.index_of(state).is_none() && self.states.len() >= limit {
+            return Err(crate::Error::TooManyStates(limit));
+        }
+        let index = self.states.intern(state);
+        if halt {
+            self.halting.insert(index);
+        }
+        Ok(self.state_id(state).expect("the state was just interned"))
+    }
+    /// interns the symbol, returning its id
+    pub fn intern_symbol(&mut self, symbol: &A) -> SymId {
+        SymId(self.symbols.intern(symbol))
+    }
+    /// interns each of the given symbols, e.g. the input of a program
+    pub fn intern_input<'a, I>(&mut self, input: I) -> Vec<SymId>
+    where
+        A: 'a,
+        I: IntoIterator<Item = &'a A>,
+    {
+        input
+            .into_iter()
+            .map(|symbol| self.intern_symbol(symbol))
+            .collect()
+    }
+    /// interns the program, returning an equivalent program operating upon the ids of its
+    /// states and symbols. Every state that does not define any rules, or that the program
+    /// explicitly declares as halting, is considered halting.
+    pub fn intern_program(
+        &mut self,
+        program: &Program<Q, A>,
+    ) -> crate::Result<Program<StateId, SymId>>
+    where
+        Q: RawState,
+    {
+        let active = program
+            .iter()
+            .map(|rule| rule.state().get())
+            .collect::<HashSet<_>>();
+        let state = |q: &State<Q>, interner: &mut Self| {
+            let q = q.get();
+            let halt = !active.contains(&q) || program.halt_kind(q).is_some();
+            interner.intern_state(q, halt)
+        };
+        let rules = program
+            .iter()
+            .map(|rule| {
+                let current = state(rule.state(), self)?;
+                let next = state(rule.next_state(), self)?;
+                Ok(Rule::from_parts(
+                    current,
+                    self.intern_symbol(rule.symbol()),
+                    rule.direction(),
+                    next,
+                    self.intern_symbol(rule.write_symbol()),
+                ))
+            })
+            .collect::<crate::Result<Vec<_>>>()?;
+        let mut interned = Program::from_rules(rules);
+        if let Some(initial) = program.initial_state() {
+            interned = interned.with_default_state(state(initial, self)?);
+        }
+        for (q, kind) in program.halt_states().iter() {
+            interned.set_halt_state(state(q, self)?, kind);
+        }
+        Ok(interned)
+    }
+    /// maps the interned program back onto the original states and symbols, returning
+    /// `None` should it contain an id unknown to the interner.
+    pub fn resolve_program(&self, program: &Program<StateId, SymId>) -> Option<Program<Q, A>>
+    where
+        Q: RawState,
+    {
+        let rules = program
+            .iter()
+            .map(|rule| {
+                Some(Rule::from_parts(
+                    self.state(*rule.state().get())?.clone(),
+                    self.symbol(*rule.symbol())?.clone(),
+                    rule.direction(),
+                    self.state(*rule.next_state().get())?.clone(),
+                    self.symbol(*rule.write_symbol())?.clone(),
+                ))
+            })
+            .collect::<Option<Vec<_>>>()?;
+        let mut resolved = Program::from_rules(rules);
+        if let Some(initial) = program.initial_state() {
+            resolved = resolved.with_default_state(self.state(*initial.get())?.clone());
+        }
+        for (q, kind) in program.halt_states().iter() {
+            resolved.set_halt_state(self.state(*q.get())?.clone(), kind);
+        }
+        Some(resolved)
+    }
+    /// maps the interned symbols back onto the original symbols, returning `None` should any
+    /// of them be unknown to the interner.
+    pub fn resolve_symbols<I>(&self, symbols: I) -> Option<Vec<A>>
+    where
+        I: IntoIterator<Item = SymId>,
+    {
+        symbols
+            .into_iter()
+            .map(|id| self.symbol(id).cloned())
+            .collect()
+    }
+}
+
+impl<Q, A> Program<Q, A>
+where
+    Q: RawState + Clone + Eq + core::hash::Hash,
+    A: Clone + Default + Eq + core::hash::Hash,
+{
+    /// interns the states and symbols of the program, returning the interned program
+    /// alongside the [`Interner`] needed to map its results back.
+    ///
+    /// Note that every state without any rules is interned as a halting state, so executing
+    /// the interned program halts upon reaching such a state whereas the original program
+    /// fails with [`NoRuleFound`](crate::Error::NoRuleFound). An error is returned should the
+    /// program use more states than a [`StateId`] is able to index.
+    pub fn intern(&self) -> crate::Result<(Program<StateId, SymId>, Interner<Q, A>)> {
+        let mut interner = Interner::new();
+        let program = interner.intern_program(self)?;
+        Ok((program, interner))
+    }
+}
+
+/*
+ ************* Implementations *************
+*/
+
+impl rstm_state::RawState for StateId {
+    fn __private__(&self) -> rstm_state::__private::Seal {
+        rstm_state::__private::Seal
+    }
+}
+
+impl Halting for StateId {
+    fn is_halted(&self) -> bool {
+        self.is_halt()
+    }
+}
+
+impl core::fmt::Display for StateId {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "q{}", self.index())
+    }
+}
+
+impl core::fmt::Display for SymId {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<T> Default for InternTable<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Q, A> Default for Interner<Q, A>
+where
+    Q: Clone + Eq + core::hash::Hash,
+    A: Clone + Default + Eq + core::hash::Hash,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FromIterator<T> for InternTable<T>
+where
+    T: Clone + Eq + core::hash::Hash,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut table = Self::new();
+        iter.into_iter().for_each(|value| {
+            table.intern(&value);
+        });
+        table
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T> serde::Serialize for InternTable<T>
+where
+    T: serde::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.values.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for InternTable<T>
+where
+    T: Clone + Eq + core::hash::Hash + serde::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let values = Vec::<T>::deserialize(deserializer)?;
+        let table = values.iter().cloned().collect::<Self>();
+        if table.len() != values.len() {
+            return Err(serde::de::Error::custom(
+                "the table contains duplicate values",
+            ));
+        }
+        Ok(table)
+    }
+}
@@ -6,18 +6,30 @@
 //! This module provides the [`ProgramBase`] implementation along with its associated aliases,
 //! supporting traits, and more.
 #[cfg(feature = "alloc")]
+pub use self::canonical::CANONICAL_HALT;
+#[cfg(feature = "alloc")]
 #[allow(deprecated)]
 pub use self::instruction_set::InstructionSet;
+#[cfg(any(feature = "hashbrown", feature = "std"))]
+pub use self::interner::*;
 #[doc(inline)]
 pub use self::{program_base::ProgramBase, traits::*, types::*};
 
+#[cfg(feature = "alloc")]
+mod canonical;
 mod instruction_set;
+#[cfg(any(feature = "hashbrown", feature = "std"))]
+pub mod interner;
 mod program_base;
 
 mod impls {
     mod imp_program_base;
     mod imp_program_base_ext;
     mod imp_program_base_repr;
+    #[cfg(feature = "alloc")]
+    mod imp_program_compute;
+    #[cfg(feature = "alloc")]
+    mod imp_program_language;
 }
 
 mod traits {
@@ -29,13 +41,18 @@ mod traits {
 
 mod types {
     #[doc(inline)]
-    pub use self::aliases::*;
+    pub use self::{aliases::*, format::*, halt_states::*, verdict::*};
 
     mod aliases;
+    mod format;
+    mod halt_states;
+    mod verdict;
 }
 
 #[doc(hidden)]
 pub(crate) mod prelude {
+    #[cfg(any(feature = "hashbrown", feature = "std"))]
+    pub use super::interner::*;
     pub use super::program_base::*;
     pub use super::traits::*;
     pub use super::types::*;
@@ -3,7 +3,7 @@
     Created At: 2026.01.11:12:34:11
     Contrib: @FL03
 */
-use super::RawRuleset;
+use super::{HaltStates, RawRuleset};
 use crate::rules::Instruction;
 use rstm_state::{RawState, State};
 
@@ -11,7 +11,10 @@ use rstm_state::{RawState, State};
 /// a Turing machine or similar computational model. It consists of an optional initial state,
 /// a set of rules (or instructions) used to indicate how the machine should *respond* under
 /// different *circumstances*, and a marker to associate the generic parameters with the struct.
-#[derive(Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+///
+/// Programs may also explicitly declare their halting states using [`HaltStates`], which take
+/// precedence over the [`Halting`](rstm_state::Halting) implementation of the state.
+#[derive(Clone, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
 #[cfg_attr(
     feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
@@ -25,6 +28,12 @@ where
     I: Instruction<Q, A>,
 {
     pub(crate) initial_state: Option<State<Q>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "HaltStates::is_empty")
+    )]
+    pub(crate) halt_states: HaltStates<Q>,
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) _marker: core::marker::PhantomData<(I, Q, A)>,
     pub(crate) rules: R,
 }
@@ -40,6 +49,7 @@ mod tests {
 
         let program = ProgramBase {
             initial_state: Some(State::new(0)),
+            halt_states: HaltStates::new(),
             _marker: core::marker::PhantomData,
             rules: [rule],
         };
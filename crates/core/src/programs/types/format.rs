@@ -0,0 +1,83 @@
+/*
+    Appellation: format <module>
+    Created At: 2026.10.19:14:51:09
+    Contrib: @FL03
+*/
+
+/// The [`ProgramFormat`] enumerates the serialization formats a
+/// [`ProgramBase`](crate::programs::ProgramBase) may be loaded from, or saved as. Each format
+/// is available so long as its corresponding feature (i.e. `json`, `yaml`, `toml`, or `ron`)
+/// is enabled.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    strum::AsRefStr,
+    strum::Display,
+    strum::EnumCount,
+    strum::EnumIs,
+    strum::EnumIter,
+    strum::VariantArray,
+    strum::VariantNames,
+)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "lowercase")
+)]
+#[strum(serialize_all = "lowercase")]
+pub enum ProgramFormat {
+    #[default]
+    Json,
+    Yaml,
+    Toml,
+    Ron,
+}
+
+impl ProgramFormat {
+    /// returns the format associated with the given file extension, if any
+    pub fn from_extension(ext: &str) -> Option<Self> {
+        match ext.to_ascii_lowercase().as_str() {
+            "json" => Some(Self::Json),
+            "yaml" | "yml" => Some(Self::Yaml),
+            "toml" => Some(Self::Toml),
+            "ron" => Some(Self::Ron),
+            _ => None,
+        }
+    }
+    #[cfg(feature = "std")]
+    /// returns the format associated with the extension of the given path, if any
+    pub fn from_path<P>(path: P) -> Option<Self>
+    where
+        P: AsRef<std::path::Path>,
+    {
+        path.as_ref()
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .and_then(Self::from_extension)
+    }
+    /// returns the canonical file extension of the format
+    pub const fn extension(&self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Yaml => "yaml",
+            Self::Toml => "toml",
+            Self::Ron => "ron",
+        }
+    }
+    /// returns true if support for the format has been enabled
+    pub const fn is_enabled(&self) -> bool {
+        match self {
+            Self::Json => cfg!(feature = "serde_json"),
+            Self::Yaml => cfg!(feature = "yaml"),
+            Self::Toml => cfg!(feature = "toml"),
+            Self::Ron => cfg!(feature = "ron"),
+        }
+    }
+}
@@ -0,0 +1,88 @@
+/*
+    Appellation: halt_states <module>
+    Created At: 2026.10.19:22:21:09
+    Contrib: @FL03
+*/
+use alloc::vec::Vec;
+use rstm_state::{HaltKind, State};
+
+/// [`HaltStates`] explicitly declares the states in which a program halts, distinguishing
+/// those that accept or reject their input from those that simply halt.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(default, deny_unknown_fields, rename_all = "snake_case")
+)]
+pub struct HaltStates<Q> {
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
+    pub(crate) halt: Vec<State<Q>>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
+    pub(crate) accept: Vec<State<Q>>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
+    pub(crate) reject: Vec<State<Q>>,
+}
+
+impl<Q> HaltStates<Q> {
+    /// returns a new instance without any halting states
+    pub const fn new() -> Self {
+        Self {
+            halt: Vec::new(),
+            accept: Vec::new(),
+            reject: Vec::new(),
+        }
+    }
+    /// returns true if no halting states have been declared
+    pub fn is_empty(&self) -> bool {
+        self.halt.is_empty() && self.accept.is_empty() && self.reject.is_empty()
+    }
+    /// returns a slice of the states that halt without accepting or rejecting their input
+    pub const fn halt(&self) -> &[State<Q>] {
+        self.halt.as_slice()
+    }
+    /// returns a slice of the accepting states
+    pub const fn accept(&self) -> &[State<Q>] {
+        self.accept.as_slice()
+    }
+    /// returns a slice of the rejecting states
+    pub const fn reject(&self) -> &[State<Q>] {
+        self.reject.as_slice()
+    }
+    /// returns an iterator over the declared states alongside their kind of halt
+    pub fn iter(&self) -> impl Iterator<Item = (&State<Q>, HaltKind)> {
+        let halt = self.halt.iter().map(|s| (s, HaltKind::Halt));
+        let accept = self.accept.iter().map(|s| (s, HaltKind::Accept));
+        let reject = self.reject.iter().map(|s| (s, HaltKind::Reject));
+        halt.chain(accept).chain(reject)
+    }
+    /// declares the state as halting with the given kind
+    pub fn insert(&mut self, state: State<Q>, kind: HaltKind) {
+        match kind {
+            HaltKind::Halt => self.halt.push(state),
+            HaltKind::Accept => self.accept.push(state),
+            HaltKind::Reject => self.reject.push(state),
+        }
+    }
+    /// returns the kind of halt declared for the given state, if any
+    pub fn kind_of(&self, state: &Q) -> Option<HaltKind>
+    where
+        Q: PartialEq,
+    {
+        let declared = |states: &[State<Q>]| states.iter().any(|s| s.get() == state);
+        if declared(&self.accept) {
+            Some(HaltKind::Accept)
+        } else if declared(&self.reject) {
+            Some(HaltKind::Reject)
+        } else if declared(&self.halt) {
+            Some(HaltKind::Halt)
+        } else {
+            None
+        }
+    }
+}
+
+impl<Q> Default for HaltStates<Q> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
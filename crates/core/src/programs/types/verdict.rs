@@ -0,0 +1,39 @@
+/*
+    Appellation: verdict <module>
+    Created At: 2026.10.19:22:48:31
+    Contrib: @FL03
+*/
+
+/// The [`Verdict`] enumerates the possible results of running a program upon an input word;
+/// see [`accepts`](crate::programs::Program::accepts).
+#[derive(
+    Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, strum::EnumCount, strum::EnumIs,
+)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Verdict {
+    /// the machine reached an accepting state after the given number of steps
+    Accept { steps: usize },
+    /// the machine reached a rejecting, or otherwise halting, state after the given number of
+    /// steps
+    Reject { steps: usize },
+    /// the machine reached a head without a rule after the given number of steps
+    NoTransition { steps: usize },
+    /// the machine was still running once the step limit was reached
+    Timeout { steps: usize },
+}
+
+impl Verdict {
+    /// returns the number of steps simulated
+    pub const fn steps(&self) -> usize {
+        match *self {
+            Self::Accept { steps }
+            | Self::Reject { steps }
+            | Self::NoTransition { steps }
+            | Self::Timeout { steps } => steps,
+        }
+    }
+}
@@ -0,0 +1,220 @@
+/*
+    Appellation: random <module>
+    Created At: 2026.10.19:02:14:51
+    Contrib: @FL03
+*/
+//! this module provides the [`ProgramGenerator`] for sampling random programs, whether for
+//! fuzzing the engine, gathering statistics on the halting behavior of random machines, or
+//! seeding a population for program search.
+use crate::programs::{CANONICAL_HALT, Program};
+use crate::rules::{Direction, Rule};
+use alloc::vec::Vec;
+use rand::distr::weighted::WeightedIndex;
+use rand::distr::{Bernoulli, Distribution};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// the directions in the order of their weights
+const DIRECTIONS: [Direction; 3] = [Direction::Left, Direction::Right, Direction::Stay];
+
+/// [`ProgramGenerator`] samples random programs over the states `0..states` and the given
+/// alphabet, always beginning in the state `0`.
+///
+/// Each head is assigned a single transition which halts with the _halting probability_,
+/// moving onto the [`CANONICAL_HALT`] state; otherwise, it moves onto one of the states
+/// chosen uniformly. The direction of every transition is drawn according to the direction
+/// weights while the symbol to write is chosen uniformly from the alphabet.
+///
+/// By default, the programs are _total_, defining a rule for every head; otherwise, the
+/// halting transitions are left undefined, as is customary for busy beavers. The generator
+/// may also guarantee that a halting transition is reachable from the initial state within
+/// the transition graph of the program.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProgramGenerator<A> {
+    pub(crate) states: usize,
+    pub(crate) alphabet: Vec<A>,
+    pub(crate) directions: [f64; 3],
+    pub(crate) halt_probability: f64,
+    pub(crate) total: bool,
+    pub(crate) reachable_halt: bool,
+}
+
+impl<A> ProgramGenerator<A> {
+    /// returns a new generator for programs with the given number of (non-halting) states
+    /// over the given alphabet, neither of which may be empty. By default, the head moves
+    /// left or right with equal probability and each transition halts with a probability of
+    /// `1 / (states + 1)`.
+    pub fn new<I>(states: usize, alphabet: I) -> Self
+    where
+        I: IntoIterator<Item = A>,
+    {
+        let alphabet = alphabet.into_iter().collect::<Vec<_>>();
+        assert!(
+            states > 0 && !alphabet.is_empty(),
+            "expected at least one state and symbol"
+        );
+        Self {
+            states,
+            alphabet,
+            directions: [1.0, 1.0, 0.0],
+            halt_probability: 1.0 / (states + 1) as f64,
+            total: true,
+            reachable_halt: false,
+        }
+    }
+    /// consumes the generator to create another drawing the directions with the given
+    /// weights, which must be non-negative and not all zero
+    pub fn with_direction_weights(self, left: f64, right: f64, stay: f64) -> Self {
+        let directions = [left, right, stay];
+        assert!(
+            directions.iter().all(|w| w.is_finite() && *w >= 0.0)
+                && directions.iter().any(|w| *w > 0.0),
+            "expected non-negative direction weights, at least one of which is positive"
+        );
+        Self { directions, ..self }
+    }
+    /// consumes the generator to create another whose transitions halt with the given
+    /// probability, which must lie within `[0, 1]`
+    pub fn with_halt_probability(self, halt_probability: f64) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&halt_probability),
+            "expected a halting probability within [0, 1]"
+        );
+        Self {
+            halt_probability,
+            ..self
+        }
+    }
+    /// consumes the generator to create another generating total programs if `total` is
+    /// true; otherwise, the halting transitions are left undefined
+    pub fn with_total(self, total: bool) -> Self {
+        Self { total, ..self }
+    }
+    /// consumes the generator to create another guaranteeing that a halting transition is
+    /// reachable from the initial state if `reachable_halt` is true
+    pub fn with_reachable_halt(self, reachable_halt: bool) -> Self {
+        Self {
+            reachable_halt,
+            ..self
+        }
+    }
+    /// returns the number of (non-halting) states
+    pub const fn states(&self) -> usize {
+        self.states
+    }
+    /// returns a slice of the symbols of the alphabet
+    pub const fn alphabet(&self) -> &[A] {
+        self.alphabet.as_slice()
+    }
+    /// returns the weights of moving left, right, and staying, respectively
+    pub const fn direction_weights(&self) -> [f64; 3] {
+        self.directions
+    }
+    /// returns the probability of any transition halting
+    pub const fn halt_probability(&self) -> f64 {
+        self.halt_probability
+    }
+    /// returns true if the generated programs are total
+    pub const fn is_total(&self) -> bool {
+        self.total
+    }
+    /// returns true if the generated programs are guaranteed to reach a halting transition
+    pub const fn has_reachable_halt(&self) -> bool {
+        self.reachable_halt
+    }
+    /// generates a program using the given random number generator
+    pub fn generate<R>(&self, rng: &mut R) -> Program<usize, A>
+    where
+        A: Clone,
+        R: Rng + ?Sized,
+    {
+        let directions = WeightedIndex::new(self.directions)
+            .expect("the direction weights are validated upon construction");
+        let halts = Bernoulli::new(self.halt_probability)
+            .expect("the halting probability is validated upon construction");
+        let symbols = self.alphabet.len();
+        // the transition of each head, in order, recording the index of the symbol to write
+        let mut table = (0..self.states * symbols)
+            .map(|_| {
+                let direction = DIRECTIONS[directions.sample(rng)];
+                let next = if halts.sample(rng) {
+                    CANONICAL_HALT
+                } else {
+                    rng.random_range(0..self.states)
+                };
+                (direction, next, rng.random_range(0..symbols))
+            })
+            .collect::<Vec<_>>();
+        if self.reachable_halt {
+            let reachable = self.reachable_heads(&table);
+            if !reachable.iter().any(|&idx| table[idx].1 == CANONICAL_HALT) {
+                let idx = reachable[rng.random_range(0..reachable.len())];
+                table[idx].1 = CANONICAL_HALT;
+            }
+        }
+        table
+            .into_iter()
+            .enumerate()
+            .filter(|(_, (_, next, _))| self.total || *next != CANONICAL_HALT)
+            .map(|(idx, (direction, next, write))| {
+                Rule::from_parts(
+                    idx / symbols,
+                    self.alphabet[idx % symbols].clone(),
+                    direction,
+                    next,
+                    self.alphabet[write].clone(),
+                )
+            })
+            .collect::<Program<usize, A>>()
+            .with_default_state(0)
+    }
+    /// generates a program from the given seed, always producing the same program for the
+    /// same seed. The programs are drawn using [`ChaCha8Rng`], whose output is fixed across
+    /// platforms and versions, so seeds remain reproducible.
+    pub fn generate_seeded(&self, seed: u64) -> Program<usize, A>
+    where
+        A: Clone,
+    {
+        self.generate(&mut ChaCha8Rng::seed_from_u64(seed))
+    }
+    /// generates a population of `n` programs from the given seed, drawn in order from a
+    /// single [`ChaCha8Rng`]
+    pub fn population(&self, n: usize, seed: u64) -> Vec<Program<usize, A>>
+    where
+        A: Clone,
+    {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        (0..n).map(|_| self.generate(&mut rng)).collect()
+    }
+    /// returns the indices of the heads reachable from the initial state within the table
+    fn reachable_heads(&self, table: &[(Direction, usize, usize)]) -> Vec<usize> {
+        let symbols = self.alphabet.len();
+        let mut visited = alloc::vec![false; self.states];
+        let mut stack = alloc::vec![0];
+        visited[0] = true;
+        let mut heads = Vec::new();
+        while let Some(q) = stack.pop() {
+            let row = q * symbols..(q + 1) * symbols;
+            for (idx, &(_, next, _)) in row.clone().zip(&table[row]) {
+                heads.push(idx);
+                if next != CANONICAL_HALT && !visited[next] {
+                    visited[next] = true;
+                    stack.push(next);
+                }
+            }
+        }
+        heads
+    }
+}
+
+impl<A> Distribution<Program<usize, A>> for ProgramGenerator<A>
+where
+    A: Clone,
+{
+    fn sample<R>(&self, rng: &mut R) -> Program<usize, A>
+    where
+        R: Rng + ?Sized,
+    {
+        self.generate(rng)
+    }
+}
@@ -41,10 +41,11 @@ mod traits {
 
 mod types {
     #[doc(inline)]
-    pub use self::{aliases::*, direction::*};
+    pub use self::aliases::*;
+    #[doc(inline)]
+    pub use rstm_tape::Direction;
 
     mod aliases;
-    mod direction;
 }
 // prelude (local)
 #[doc(hidden)]
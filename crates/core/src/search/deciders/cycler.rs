@@ -0,0 +1,67 @@
+/*
+    Appellation: cycler <module>
+    Created At: 2026.10.19:11:20:44
+    Contrib: @FL03
+*/
+use crate::actors::Configuration;
+use crate::programs::Program;
+use crate::search::utils::{Snapshot, advance};
+use crate::search::{Certificate, DEFAULT_STEP_LIMIT, Decider, Decision};
+use alloc::collections::BTreeMap;
+use rstm_state::{Halting, RawState};
+
+/// The [`Cycler`] decider proves a machine never halts by detecting an exact repetition of
+/// its configuration; i.e. the state, the position of the head, and the contents of the tape.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct Cycler {
+    pub(crate) step_limit: usize,
+}
+
+impl Cycler {
+    /// returns a new cycler that simulates machines for, at most, the given number of steps
+    pub const fn new(step_limit: usize) -> Self {
+        Self { step_limit }
+    }
+    /// returns the step limit
+    pub const fn step_limit(&self) -> usize {
+        self.step_limit
+    }
+}
+
+impl Default for Cycler {
+    fn default() -> Self {
+        Self::new(DEFAULT_STEP_LIMIT)
+    }
+}
+
+impl<Q, A> Decider<Q, A> for Cycler
+where
+    Q: RawState + Clone + Halting + Ord,
+    A: Clone + Default + Ord,
+{
+    fn decide(&self, program: &Program<Q, A>, config: &Configuration<Q, A>) -> Decision {
+        let limit = config.steps() + self.step_limit;
+        let mut config = config.clone();
+        let mut seen = BTreeMap::new();
+        loop {
+            let steps = config.steps();
+            if let Some(start) = seen.insert(Snapshot::capture(&config), steps) {
+                return Decision::NeverHalts(Certificate::Cycler {
+                    start,
+                    period: steps - start,
+                });
+            }
+            if steps >= limit {
+                return Decision::Unknown;
+            }
+            if !advance(program, &mut config) {
+                return Decision::Halts(config.steps());
+            }
+        }
+    }
+}
@@ -0,0 +1,118 @@
+/*
+    Appellation: translated_cycler <module>
+    Created At: 2026.10.19:11:34:19
+    Contrib: @FL03
+*/
+use crate::actors::Configuration;
+use crate::programs::Program;
+use crate::search::utils::{advance, is_frontier, windows_match};
+use crate::search::{Certificate, DEFAULT_STEP_LIMIT, Decider, Decision};
+use alloc::vec::Vec;
+use rstm_state::{Halting, RawState};
+
+/// The [`TranslatedCycler`] decider proves a machine never halts by detecting a configuration
+/// that repeats itself while shifted along the edge of the tape.
+///
+/// The decider records every configuration where the head reaches a previously unvisited
+/// cell with nothing but blanks beyond it, i.e. _record-breaking_ configurations. Whenever two
+/// records on the same side of the tape share both their state and the cells, relative to the
+/// head, visited in between them, the machine is bound to repeat the same behavior forever.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct TranslatedCycler {
+    pub(crate) step_limit: usize,
+}
+
+/// a record-breaking configuration
+struct Record<Q, A> {
+    config: Configuration<Q, A>,
+    right: bool,
+}
+
+impl TranslatedCycler {
+    /// returns a new decider that simulates machines for, at most, the given number of steps
+    pub const fn new(step_limit: usize) -> Self {
+        Self { step_limit }
+    }
+    /// returns the step limit
+    pub const fn step_limit(&self) -> usize {
+        self.step_limit
+    }
+}
+
+impl Default for TranslatedCycler {
+    fn default() -> Self {
+        Self::new(DEFAULT_STEP_LIMIT)
+    }
+}
+
+impl<Q, A> Decider<Q, A> for TranslatedCycler
+where
+    Q: RawState + Clone + Halting + PartialEq,
+    A: Clone + Default + PartialEq,
+{
+    fn decide(&self, program: &Program<Q, A>, config: &Configuration<Q, A>) -> Decision {
+        let base = config.steps();
+        let limit = base + self.step_limit;
+        let mut config = config.clone();
+        // the position of the head at every step, relative to the base
+        let mut positions = Vec::new();
+        let mut records: Vec<Record<Q, A>> = Vec::new();
+        let (mut min, mut max) = (config.position(), config.position());
+        loop {
+            let pos = config.position();
+            positions.push(pos);
+            let side = if pos > max {
+                Some(true)
+            } else if pos < min {
+                Some(false)
+            } else {
+                None
+            };
+            (min, max) = (min.min(pos), max.max(pos));
+            if let Some(right) = side.filter(|&right| is_frontier(&config, right)) {
+                // walk back through the previous records, tracking the furthest the head
+                // travelled behind the frontier since each of them
+                let steps = config.steps();
+                let mut extreme = pos;
+                let mut cursor = steps - base;
+                for record in records.iter().rev() {
+                    let t = record.config.steps() - base;
+                    for &p in &positions[t..cursor] {
+                        extreme = if right {
+                            extreme.min(p)
+                        } else {
+                            extreme.max(p)
+                        };
+                    }
+                    cursor = t;
+                    if record.right != right || record.config.state() != config.state() {
+                        continue;
+                    }
+                    let lookback = record.config.position().abs_diff(extreme);
+                    if windows_match(&record.config, &config, lookback, right) {
+                        return Decision::NeverHalts(Certificate::TranslatedCycler {
+                            start: record.config.steps(),
+                            period: steps - record.config.steps(),
+                            shift: pos - record.config.position(),
+                        });
+                    }
+                }
+                records.push(Record {
+                    config: config.clone(),
+                    right,
+                });
+            }
+            if config.steps() >= limit {
+                return Decision::Unknown;
+            }
+            if !advance(program, &mut config) {
+                return Decision::Halts(config.steps());
+            }
+        }
+    }
+}
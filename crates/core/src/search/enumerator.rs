@@ -0,0 +1,190 @@
+/*
+    Appellation: enumerator <module>
+    Created At: 2026.10.19:10:33:51
+    Contrib: @FL03
+*/
+use super::{Candidate, DEFAULT_STEP_LIMIT, Outcome};
+use crate::actors::Configuration;
+use crate::programs::{CANONICAL_HALT, Program};
+use crate::rules::{Direction, Head, Rule, Tail};
+use alloc::vec::Vec;
+use rstm_state::State;
+
+/// The [`Enumerator`] lazily enumerates all _n_-state, _k_-symbol machines in tree normal
+/// form (TNF).
+///
+/// Each machine begins with no transitions at all and is simulated on a blank tape until it
+/// either reaches a head without a transition, or one of the configured limits. Upon reaching
+/// an undefined head, the enumerator yields the machine that halts at that point before
+/// branching on every possible transition for it. To keep the tree small, states and symbols
+/// are introduced in order (i.e. the next unused state or symbol is the only new one that may
+/// be targeted) and the very first transition always moves to the right.
+///
+/// Halting is represented using an explicit rule transitioning into [`CANONICAL_HALT`] that
+/// writes `1` and moves right, and is counted as a step.
+#[derive(Clone, Debug)]
+pub struct Enumerator {
+    /// the number of (non-halting) states
+    pub(crate) states: usize,
+    /// the number of symbols, including the blank symbol `0`
+    pub(crate) symbols: usize,
+    /// the maximum number of steps to simulate each candidate for
+    pub(crate) step_limit: usize,
+    /// the maximum number of cells a candidate may visit
+    pub(crate) space_limit: usize,
+    /// the pending nodes of the tree
+    pub(crate) stack: Vec<Node>,
+}
+
+/// A [`Node`] of the enumeration tree; a partially defined machine alongside the
+/// configuration it has been simulated up to.
+#[derive(Clone, Debug)]
+pub(crate) struct Node {
+    /// the transitions of the machine, indexed by `state * symbols + symbol`
+    table: Vec<Option<Tail<usize, usize>>>,
+    /// the configuration of the machine
+    config: Configuration<usize, usize>,
+    /// the number of states in use
+    states_used: usize,
+    /// the number of symbols in use
+    symbols_used: usize,
+}
+
+impl Enumerator {
+    /// returns a new enumerator for machines with the given number of states and symbols
+    /// using the [`DEFAULT_STEP_LIMIT`] and an unbounded amount of space.
+    pub fn new(states: usize, symbols: usize) -> Self {
+        let mut stack = Vec::new();
+        if states > 0 && symbols > 0 {
+            stack.push(Node {
+                table: vec![None; states * symbols],
+                config: Configuration::new(0),
+                states_used: 1,
+                symbols_used: 1,
+            });
+        }
+        Self {
+            states,
+            symbols,
+            step_limit: DEFAULT_STEP_LIMIT,
+            space_limit: usize::MAX,
+            stack,
+        }
+    }
+    /// consumes the current instance to create another with the given step limit
+    pub fn with_step_limit(self, step_limit: usize) -> Self {
+        Self { step_limit, ..self }
+    }
+    /// consumes the current instance to create another with the given space limit; i.e. the
+    /// maximum number of tape cells a candidate may visit
+    pub fn with_space_limit(self, space_limit: usize) -> Self {
+        Self {
+            space_limit,
+            ..self
+        }
+    }
+    /// returns the number of states
+    pub const fn states(&self) -> usize {
+        self.states
+    }
+    /// returns the number of symbols
+    pub const fn symbols(&self) -> usize {
+        self.symbols
+    }
+    /// returns the step limit
+    pub const fn step_limit(&self) -> usize {
+        self.step_limit
+    }
+    /// returns the space limit
+    pub const fn space_limit(&self) -> usize {
+        self.space_limit
+    }
+    /// returns the tail used to halt the machine
+    fn halt_tail(&self) -> Tail<usize, usize> {
+        Tail {
+            direction: Direction::Right,
+            next_state: State(CANONICAL_HALT),
+            write_symbol: 1.min(self.symbols - 1),
+        }
+    }
+    /// converts the transition table into a program
+    fn program(&self, table: &[Option<Tail<usize, usize>>]) -> Program<usize, usize> {
+        let rules = table
+            .iter()
+            .enumerate()
+            .filter_map(|(i, tail)| {
+                tail.map(|tail| Rule::new(Head::new(i / self.symbols, i % self.symbols), tail))
+            })
+            .collect::<Vec<_>>();
+        Program::from_rules(rules).with_default_state(0)
+    }
+    /// simulate the given node until it reaches an undefined head or exhausts the limits,
+    /// queueing its children in the former case
+    fn explore(&mut self, mut node: Node) -> Candidate {
+        loop {
+            let steps = node.config.steps();
+            if steps >= self.step_limit {
+                return Candidate::new(self.program(&node.table), Outcome::Exceeded { steps });
+            }
+            if node.config.tape().len() > self.space_limit {
+                return Candidate::new(self.program(&node.table), Outcome::Undecided { steps });
+            }
+            let idx = *node.config.state().get() * self.symbols + *node.config.read();
+            match node.table[idx] {
+                Some(tail) => node.config.apply(&tail),
+                None => {
+                    self.branch(&node, idx);
+                    // halt on the undefined head
+                    let halt = self.halt_tail();
+                    node.table[idx] = Some(halt);
+                    node.config.apply(&halt);
+                    let outcome = Outcome::Halted {
+                        steps: node.config.steps(),
+                        sigma: node.config.sigma(),
+                    };
+                    return Candidate::new(self.program(&node.table), outcome);
+                }
+            }
+        }
+    }
+    /// push each of the possible definitions for the transition at `idx` onto the stack
+    fn branch(&mut self, node: &Node, idx: usize) {
+        let next_states = self.states.min(node.states_used + 1);
+        let next_symbols = self.symbols.min(node.symbols_used + 1);
+        // the first transition of a machine is always taken to move right
+        let directions: &[Direction] = if node.config.steps() == 0 {
+            &[Direction::Right]
+        } else {
+            &[Direction::Left, Direction::Right]
+        };
+        let mut children = Vec::with_capacity(next_states * next_symbols * directions.len());
+        for state in 0..next_states {
+            for symbol in 0..next_symbols {
+                for &direction in directions {
+                    let mut child = node.clone();
+                    child.table[idx] = Some(Tail {
+                        direction,
+                        next_state: State(state),
+                        write_symbol: symbol,
+                    });
+                    child.states_used = node.states_used.max(state + 1);
+                    child.symbols_used = node.symbols_used.max(symbol + 1);
+                    children.push(child);
+                }
+            }
+        }
+        // reverse the children so they are explored in order
+        self.stack.extend(children.into_iter().rev());
+    }
+}
+
+impl Iterator for Enumerator {
+    type Item = Candidate;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        Some(self.explore(node))
+    }
+}
+
+impl core::iter::FusedIterator for Enumerator {}
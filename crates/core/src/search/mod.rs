@@ -0,0 +1,54 @@
+/*
+    Appellation: search <module>
+    Created At: 2026.10.19:10:21:48
+    Contrib: @FL03
+*/
+//! this module provides the tools necessary for exploring the space of Turing machines; most
+//! notably, the [`Enumerator`] which lazily enumerates all _n_-state, _k_-symbol machines in
+//! tree normal form (TNF) alongside a collection of [`Decider`]s capable of proving that a
+//! machine never halts.
+#[doc(inline)]
+pub use self::{
+    deciders::{Cycler, TranslatedCycler},
+    enumerator::Enumerator,
+    traits::*,
+    types::*,
+};
+
+pub mod enumerator;
+
+mod utils;
+
+pub mod deciders {
+    //! the deciders implemented by the crate
+    #[doc(inline)]
+    pub use self::{cycler::*, translated_cycler::*};
+
+    mod cycler;
+    mod translated_cycler;
+}
+
+mod traits {
+    #[doc(inline)]
+    pub use self::decider::*;
+
+    mod decider;
+}
+
+mod types {
+    #[doc(inline)]
+    pub use self::{decision::*, outcome::*};
+
+    mod decision;
+    mod outcome;
+}
+
+/// the default number of steps a machine is simulated for before being set aside
+pub const DEFAULT_STEP_LIMIT: usize = 1_000;
+
+pub(crate) mod prelude {
+    pub use super::deciders::*;
+    pub use super::enumerator::*;
+    pub use super::traits::*;
+    pub use super::types::*;
+}
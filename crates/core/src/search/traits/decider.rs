@@ -0,0 +1,37 @@
+/*
+    Appellation: decider <module>
+    Created At: 2026.10.19:11:04:52
+    Contrib: @FL03
+*/
+use crate::actors::Configuration;
+use crate::programs::Program;
+use crate::search::Decision;
+use rstm_state::RawState;
+
+/// The [`Decider`] trait defines the interface for routines that attempt to determine
+/// whether or not a machine halts when started from some configuration.
+///
+/// Deciders proving that a machine never halts are expected to produce a
+/// [`Certificate`](crate::search::Certificate) that may be verified independently of the
+/// decider itself.
+pub trait Decider<Q, A>
+where
+    Q: RawState,
+{
+    /// attempt to decide whether the given program halts when started from the configuration
+    fn decide(&self, program: &Program<Q, A>, config: &Configuration<Q, A>) -> Decision;
+}
+
+/*
+ ************* Implementations *************
+*/
+
+impl<D, Q, A> Decider<Q, A> for &D
+where
+    D: Decider<Q, A> + ?Sized,
+    Q: RawState,
+{
+    fn decide(&self, program: &Program<Q, A>, config: &Configuration<Q, A>) -> Decision {
+        (*self).decide(program, config)
+    }
+}
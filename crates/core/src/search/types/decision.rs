@@ -0,0 +1,131 @@
+/*
+    Appellation: decision <module>
+    Created At: 2026.10.19:11:01:33
+    Contrib: @FL03
+*/
+use crate::actors::Configuration;
+use crate::programs::Program;
+use crate::search::utils::{Snapshot, advance, is_frontier, windows_match};
+use rstm_state::{Halting, RawState};
+
+/// The [`Decision`] enumerates the possible verdicts of a
+/// [`Decider`](crate::search::Decider).
+#[derive(
+    Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, strum::EnumCount, strum::EnumIs,
+)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Decision {
+    /// the machine halts after the given number of steps
+    Halts(usize),
+    /// the machine never halts, as evidenced by the certificate
+    NeverHalts(Certificate),
+    /// the decider was unable to reach a verdict
+    Unknown,
+}
+
+/// A [`Certificate`] is a compact witness to the fact that a machine never halts, which may
+/// be checked independently using [`verify`](Certificate::verify).
+///
+/// All steps are measured using the step counter of the configuration the machine was
+/// started from.
+#[derive(
+    Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, strum::EnumCount, strum::EnumIs,
+)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Certificate {
+    /// the configuration at step `start` reappears, exactly, after `period` steps
+    Cycler { start: usize, period: usize },
+    /// the configuration at step `start` reappears after `period` steps, translated by
+    /// `shift` cells along the edge of the tape; a positive shift indicates the machine is
+    /// moving to the right.
+    TranslatedCycler {
+        start: usize,
+        period: usize,
+        shift: isize,
+    },
+}
+
+impl Decision {
+    /// returns the certificate, if the machine was shown to never halt
+    pub const fn certificate(&self) -> Option<&Certificate> {
+        match self {
+            Self::NeverHalts(certificate) => Some(certificate),
+            _ => None,
+        }
+    }
+}
+
+impl Certificate {
+    /// returns the step at which the cycle begins
+    pub const fn start(&self) -> usize {
+        match *self {
+            Self::Cycler { start, .. } | Self::TranslatedCycler { start, .. } => start,
+        }
+    }
+    /// returns the length of the cycle, in steps
+    pub const fn period(&self) -> usize {
+        match *self {
+            Self::Cycler { period, .. } | Self::TranslatedCycler { period, .. } => period,
+        }
+    }
+    /// verifies the certificate by simulating the program from the given configuration,
+    /// returning true if it proves the machine never halts.
+    pub fn verify<Q, A>(&self, program: &Program<Q, A>, config: &Configuration<Q, A>) -> bool
+    where
+        Q: RawState + Clone + Halting + PartialEq,
+        A: Clone + Default + PartialEq,
+    {
+        let (start, period) = (self.start(), self.period());
+        if period == 0 || config.steps() > start {
+            return false;
+        }
+        let mut config = config.clone();
+        while config.steps() < start {
+            if !advance(program, &mut config) {
+                return false;
+            }
+        }
+        let first = config.clone();
+        match *self {
+            Self::Cycler { .. } => {
+                while config.steps() < start + period {
+                    if !advance(program, &mut config) {
+                        return false;
+                    }
+                }
+                Snapshot::capture(&first) == Snapshot::capture(&config)
+            }
+            Self::TranslatedCycler { shift, .. } => {
+                let right = shift > 0;
+                if shift == 0 || !is_frontier(&first, right) {
+                    return false;
+                }
+                // track the furthest the head travels behind the frontier
+                let mut extreme = first.position();
+                while config.steps() < start + period {
+                    if !advance(program, &mut config) {
+                        return false;
+                    }
+                    extreme = if right {
+                        extreme.min(config.position())
+                    } else {
+                        extreme.max(config.position())
+                    };
+                }
+                let lookback = first.position().abs_diff(extreme);
+                config.state() == first.state()
+                    && config.position() - first.position() == shift
+                    && is_frontier(&config, right)
+                    && windows_match(&first, &config, lookback, right)
+            }
+        }
+    }
+}
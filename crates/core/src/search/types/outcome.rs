@@ -0,0 +1,80 @@
+/*
+    Appellation: outcome <module>
+    Created At: 2026.10.19:10:24:05
+    Contrib: @FL03
+*/
+use crate::programs::Program;
+
+/// The [`Outcome`] enumerates the possible results of simulating a candidate machine within
+/// the limits of the search.
+#[derive(
+    Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, strum::EnumCount, strum::EnumIs,
+)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Outcome {
+    /// the machine halted after the given number of steps, leaving `sigma` non-blank symbols
+    /// on the tape
+    Halted { steps: usize, sigma: usize },
+    /// the machine was still running once the step limit was reached
+    Exceeded { steps: usize },
+    /// the machine outgrew the space limit before either halting or exceeding the step limit
+    Undecided { steps: usize },
+}
+
+/// A [`Candidate`] pairs a machine produced by the search with the [`Outcome`] of its
+/// simulation.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct Candidate {
+    pub(crate) program: Program<usize, usize>,
+    pub(crate) outcome: Outcome,
+}
+
+impl Outcome {
+    /// returns the number of steps simulated
+    pub const fn steps(&self) -> usize {
+        match *self {
+            Self::Halted { steps, .. } | Self::Exceeded { steps } | Self::Undecided { steps } => {
+                steps
+            }
+        }
+    }
+    /// returns the number of non-blank symbols left on the tape, if the machine halted
+    pub const fn sigma(&self) -> Option<usize> {
+        match *self {
+            Self::Halted { sigma, .. } => Some(sigma),
+            _ => None,
+        }
+    }
+}
+
+impl Candidate {
+    /// returns a new candidate from the given program and outcome
+    pub const fn new(program: Program<usize, usize>, outcome: Outcome) -> Self {
+        Self { program, outcome }
+    }
+    /// returns a reference to the program
+    pub const fn program(&self) -> &Program<usize, usize> {
+        &self.program
+    }
+    /// returns a copy of the outcome
+    pub const fn outcome(&self) -> Outcome {
+        self.outcome
+    }
+    /// consumes the candidate, returning the program
+    pub fn into_program(self) -> Program<usize, usize> {
+        self.program
+    }
+    /// returns true if the candidate halted
+    pub const fn is_halted(&self) -> bool {
+        self.outcome.is_halted()
+    }
+}
@@ -0,0 +1,92 @@
+/*
+    Appellation: utils <module>
+    Created At: 2026.10.19:11:12:08
+    Contrib: @FL03
+*/
+//! shared routines used by the deciders and their certificates
+use crate::actors::Configuration;
+use crate::programs::Program;
+use alloc::vec::Vec;
+use rstm_state::{Halting, RawState, State};
+
+/// A normalized view of a configuration whose tape has been trimmed of any leading or
+/// trailing blanks, making it suitable for detecting repeated configurations.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub(crate) struct Snapshot<Q, A> {
+    pub(crate) state: State<Q>,
+    pub(crate) position: isize,
+    pub(crate) start: isize,
+    pub(crate) cells: Vec<A>,
+}
+
+impl<Q, A> Snapshot<Q, A>
+where
+    Q: RawState + Clone,
+    A: Clone + Default + PartialEq,
+{
+    /// capture a snapshot of the given configuration
+    pub(crate) fn capture(config: &Configuration<Q, A>) -> Self {
+        let blank = A::default();
+        let tape = config.tape();
+        let first = tape.iter().position(|s| s != &blank);
+        let (start, cells) = match first {
+            Some(first) => {
+                let last = tape.iter().rposition(|s| s != &blank).unwrap_or(first);
+                let cells = tape.range(first..=last).cloned().collect();
+                (config.leftmost() + first as isize, cells)
+            }
+            None => (0, Vec::new()),
+        };
+        Self {
+            state: config.state().clone(),
+            position: config.position(),
+            start,
+            cells,
+        }
+    }
+}
+
+/// attempt to advance the configuration by a single step, returning `false` if the machine
+/// has halted; i.e. it has either entered a halted state or reached a head without a rule.
+pub(crate) fn advance<Q, A>(program: &Program<Q, A>, config: &mut Configuration<Q, A>) -> bool
+where
+    Q: RawState + Clone + Halting + PartialEq,
+    A: Clone + Default + PartialEq,
+{
+    !config.is_halted() && config.step(program).is_ok()
+}
+
+/// returns true if every cell strictly beyond the head, in the given direction, is blank
+pub(crate) fn is_frontier<Q, A>(config: &Configuration<Q, A>, right: bool) -> bool
+where
+    Q: RawState,
+    A: Default + PartialEq,
+{
+    let blank = A::default();
+    let head = config.head_index();
+    if right {
+        config.tape().range(head + 1..).all(|s| s == &blank)
+    } else {
+        config.tape().range(..head).all(|s| s == &blank)
+    }
+}
+
+/// returns true if the `len` cells extending behind each head (i.e. opposite to the given
+/// direction) are equal across both configurations, inclusive of the cells under the heads.
+pub(crate) fn windows_match<Q, A>(
+    lhs: &Configuration<Q, A>,
+    rhs: &Configuration<Q, A>,
+    len: usize,
+    right: bool,
+) -> bool
+where
+    Q: RawState,
+    A: Default + PartialEq,
+{
+    let blank = A::default();
+    let (p1, p2) = (lhs.position(), rhs.position());
+    (0..=len as isize).all(|k| {
+        let k = if right { -k } else { k };
+        lhs.get(p1 + k).unwrap_or(&blank) == rhs.get(p2 + k).unwrap_or(&blank)
+    })
+}
@@ -0,0 +1,129 @@
+/*
+    Appellation: arbitrary <tests>
+    Created At: 2026.10.19:01:02:37
+    Contrib: @FL03
+*/
+use rstm_core::arbitrary::ProgramParams;
+use rstm_core::programs::{CANONICAL_HALT, Program};
+use rstm_core::tape::DenseTape;
+use rstm_core::tape::arbitrary::TapeParams;
+use rstm_core::{Head, Rule};
+
+/// returns the number of rules defined for each head of the program
+fn rules_per_head(program: &Program<usize, usize>, params: &ProgramParams) -> Vec<usize> {
+    (0..params.states())
+        .flat_map(|q| (0..params.symbols()).map(move |a| (q, a)))
+        .map(|(q, a)| {
+            program
+                .iter()
+                .filter(|rule| *rule.state().get() == q && *rule.symbol() == a)
+                .count()
+        })
+        .collect()
+}
+
+/// returns true if every rule of the program stays within the bounds of the parameters
+fn is_bounded(program: &Program<usize, usize>, params: &ProgramParams) -> bool {
+    program.iter().all(|rule| {
+        let next = *rule.next_state().get();
+        (next < params.states() || next == CANONICAL_HALT)
+            && *rule.write_symbol() < params.symbols()
+    })
+}
+
+mod proptests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_total_deterministic_programs(
+            program in ProgramParams::new(3, 2).with_total(true).with_deterministic(true).strategy()
+        ) {
+            let params = ProgramParams::new(3, 2);
+            prop_assert!(rules_per_head(&program, &params).iter().all(|&n| n == 1));
+            prop_assert!(is_bounded(&program, &params));
+            prop_assert_eq!(program.initial_state().map(|q| *q.get()), Some(0));
+        }
+
+        #[test]
+        fn test_partial_programs(program in ProgramParams::new(2, 3).strategy()) {
+            let params = ProgramParams::new(2, 3);
+            prop_assert!(rules_per_head(&program, &params).iter().all(|&n| n <= 2));
+            prop_assert!(is_bounded(&program, &params));
+        }
+
+        #[test]
+        fn test_arbitrary_rules(rule in any::<Rule<u8, char>>(), head in any::<Head<u8, char>>()) {
+            let rebuilt = Rule::from_parts(
+                *rule.state().get(),
+                *rule.symbol(),
+                rule.direction(),
+                *rule.next_state().get(),
+                *rule.write_symbol(),
+            );
+            prop_assert_eq!(rule, rebuilt);
+            prop_assert_eq!(head, Head::new(*head.state.get(), head.symbol));
+        }
+
+        #[test]
+        fn test_arbitrary_programs(program in any::<Program<u8, u8>>()) {
+            let initial = program.iter().next().map(|rule| *rule.state().get());
+            prop_assert_eq!(program.initial_state().map(|q| *q.get()), initial);
+        }
+
+        #[test]
+        fn test_tapes(
+            tape in TapeParams::new(['a', 'b', 'c']).with_max_len(8).strategy(),
+            other in any::<DenseTape<u8>>(),
+        ) {
+            prop_assert!((1..=8).contains(&tape.len()));
+            prop_assert!(tape.iter().all(|s| ['a', 'b', 'c'].contains(s)));
+            prop_assert!(tape.head() < tape.len());
+            prop_assert!(other.head() < other.len());
+        }
+    }
+}
+
+mod quickchecks {
+    use super::*;
+    use quickcheck::{Arbitrary, Gen, QuickCheck};
+
+    #[test]
+    fn test_generated_programs() {
+        let mut g = Gen::new(16);
+        let params = ProgramParams::new(4, 2).with_total(true);
+        for _ in 0..64 {
+            let program = params.generate(&mut g);
+            assert! { rules_per_head(&program, &params).iter().all(|&n| (1..=2).contains(&n)) }
+            assert! { is_bounded(&program, &params) }
+        }
+        let params = ProgramParams::new(4, 2).with_deterministic(true);
+        for _ in 0..64 {
+            let program = params.generate(&mut g);
+            assert! { rules_per_head(&program, &params).iter().all(|&n| n <= 1) }
+        }
+    }
+
+    #[test]
+    fn test_arbitrary_programs() {
+        fn begins_with_first_rule(program: Program<u8, u8>) -> bool {
+            let initial = program.iter().next().map(|rule| *rule.state().get());
+            program.initial_state().map(|q| *q.get()) == initial
+        }
+        QuickCheck::new().quickcheck(begins_with_first_rule as fn(Program<u8, u8>) -> bool);
+    }
+
+    #[test]
+    fn test_tapes() {
+        let mut g = Gen::new(16);
+        let params = TapeParams::new([0u8, 1]).with_max_len(4);
+        for _ in 0..64 {
+            let tape = params.generate(&mut g);
+            assert! { (1..=4).contains(&tape.len()) && tape.head() < tape.len() }
+        }
+        // shrinking keeps the head upon the tape
+        let tape = DenseTape::<u8>::arbitrary(&mut g);
+        assert! { tape.shrink().all(|t| t.head() < t.len()) }
+    }
+}
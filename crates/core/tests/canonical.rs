@@ -0,0 +1,127 @@
+/*
+    Appellation: canonical <tests>
+    Created At: 2026.10.19:09:31:02
+    Contrib: @FL03
+*/
+use rstm_core::programs::CANONICAL_HALT;
+use rstm_core::{Direction, Program, Rule};
+
+/// the two-state, two-symbol busy beaver champion using character-based states and symbols
+fn bb2(a: char, b: char, halt: char, one: u8) -> Program<char, u8> {
+    Program::from_rules(vec![
+        Rule::from_parts(a, 0, Direction::Right, b, one),
+        Rule::from_parts(a, one, Direction::Left, b, one),
+        Rule::from_parts(b, 0, Direction::Left, a, one),
+        Rule::from_parts(b, one, Direction::Right, halt, one),
+    ])
+    .with_default_state(a)
+}
+
+#[test]
+fn test_canonical_renaming_invariance() {
+    let lhs = bb2('a', 'b', 'h', 1);
+    let rhs = bb2('x', 'q', 'z', 7);
+    let canonical = lhs.canonicalize(false);
+    assert_eq! { canonical, rhs.canonicalize(false) }
+    assert_eq! { canonical.initial_state().map(|s| *s.get()), Some(0) }
+    assert_eq! {
+        canonical.rules(),
+        &[
+            Rule::from_parts(0, 0, Direction::Right, 1, 1),
+            Rule::from_parts(0, 1, Direction::Left, 1, 1),
+            Rule::from_parts(1, 0, Direction::Left, 0, 1),
+            Rule::from_parts(1, 1, Direction::Right, CANONICAL_HALT, 1),
+        ]
+    }
+    assert_eq! { lhs.fingerprint(false), rhs.fingerprint(false) }
+}
+
+#[test]
+fn test_canonical_drops_unreachable_rules() {
+    let mut rules = bb2('a', 'b', 'h', 1).rules().clone();
+    rules.push(Rule::from_parts('u', 0, Direction::Left, 'a', 1));
+    let program = Program::from_rules(rules).with_default_state('a');
+    let canonical = program.canonicalize(false);
+    assert_eq! { canonical.len(), 4 }
+    assert_eq! { canonical, bb2('a', 'b', 'h', 1).canonicalize(false) }
+}
+
+#[test]
+fn test_canonical_mirror() {
+    let program = bb2('a', 'b', 'h', 1);
+    let mirrored = Program::from_rules(
+        program
+            .iter()
+            .cloned()
+            .map(|mut rule| {
+                rule.set_direction(rule.direction().reverse());
+                rule
+            })
+            .collect::<Vec<_>>(),
+    )
+    .with_default_state('a');
+    assert_ne! { program.canonicalize(false), mirrored.canonicalize(false) }
+    assert_ne! { program.fingerprint(false), mirrored.fingerprint(false) }
+    assert_eq! { program.canonicalize(true), mirrored.canonicalize(true) }
+    assert_eq! { program.fingerprint(true), mirrored.fingerprint(true) }
+}
+
+#[test]
+fn test_canonical_symbol_permutations() {
+    // the initial state reads the symbols `1` and `2` before either is ever written
+    let program = |one: u8, two: u8| {
+        Program::from_rules(vec![
+            Rule::from_parts('a', 0, Direction::Right, 'b', 0),
+            Rule::from_parts('a', one, Direction::Left, 'b', two),
+            Rule::from_parts('a', two, Direction::Right, 'a', one),
+            Rule::from_parts('b', 0, Direction::Left, 'a', one),
+            Rule::from_parts('b', one, Direction::Right, 'h', 0),
+            Rule::from_parts('b', two, Direction::Left, 'b', two),
+        ])
+        .with_default_state('a')
+    };
+    let lhs = program(1, 2);
+    let rhs = program(2, 1);
+    assert_eq! { lhs.canonicalize(false), rhs.canonicalize(false) }
+    assert_eq! { lhs.fingerprint(false), rhs.fingerprint(false) }
+    assert_eq! { lhs.fingerprint(true), rhs.fingerprint(true) }
+    // the result doesn't depend on the order of the rules either
+    let reversed =
+        Program::from_rules(rhs.iter().rev().cloned().collect::<Vec<_>>()).with_default_state('a');
+    assert_eq! { lhs.fingerprint(false), reversed.fingerprint(false) }
+}
+
+#[test]
+fn test_canonical_large_alphabet() {
+    // every rule of the initial state reads a fresh symbol yet differs in its transition, so
+    // the choices are refined without forking over every ordering of the symbols
+    let transitions = [
+        (Direction::Left, 'a', false),
+        (Direction::Right, 'a', false),
+        (Direction::Stay, 'a', false),
+        (Direction::Left, 'h', false),
+        (Direction::Right, 'h', false),
+        (Direction::Stay, 'h', false),
+        (Direction::Left, 'a', true),
+        (Direction::Right, 'a', true),
+        (Direction::Stay, 'a', true),
+    ];
+    let program = |relabel: fn(u8) -> u8| {
+        let mut rules = vec![Rule::from_parts('a', 0, Direction::Right, 'a', relabel(1))];
+        for (symbol, (direction, next, keep)) in (1..).zip(transitions) {
+            let write = if keep { relabel(symbol) } else { 0 };
+            rules.push(Rule::from_parts(
+                'a',
+                relabel(symbol),
+                direction,
+                next,
+                write,
+            ));
+        }
+        Program::from_rules(rules).with_default_state('a')
+    };
+    let lhs = program(|s| s);
+    let rhs = program(|s| 10 - s);
+    assert_eq! { lhs.canonicalize(false), rhs.canonicalize(false) }
+    assert_eq! { lhs.fingerprint(true), rhs.fingerprint(true) }
+}
@@ -0,0 +1,101 @@
+/*
+    Appellation: compute <tests>
+    Created At: 2026.10.19:23:24:10
+    Contrib: @FL03
+*/
+use rstm_core::compute::{Binary, TapeCodec, Tuple, Unary};
+use rstm_core::{Error, program};
+
+#[test]
+fn test_codecs() {
+    let unary = Unary::new(1usize);
+    assert_eq! { unary.encode(&3), vec![1, 1, 1] }
+    assert_eq! { unary.decode(&[1, 1]), Some(2) }
+    assert_eq! { unary.decode(&[1, 2]), None }
+
+    let big = Binary::big('0', '1');
+    let little = Binary::little('0', '1');
+    assert_eq! { big.encode(&6), vec!['1', '1', '0'] }
+    assert_eq! { little.encode(&6), vec!['0', '1', '1'] }
+    assert_eq! { big.encode(&0), vec!['0'] }
+    assert_eq! { big.decode(&['1', '0', '0']), Some(4) }
+    assert_eq! { little.decode(&['1', '0', '0']), Some(1) }
+    assert_eq! { big.decode(&['1', 'x']), None }
+
+    let pairs = Tuple::new(unary, 2usize);
+    assert_eq! { pairs.encode(&vec![2, 0, 1]), vec![1, 1, 2, 2, 1] }
+    assert_eq! { pairs.decode(&[1, 1, 2, 2, 1]), Some(vec![2, 0, 1]) }
+}
+
+#[test]
+fn test_compute_unary_addition() {
+    // joins the two runs of marks before erasing the last one
+    let adder = program! {
+        #[default_state(0)]
+        #[halt(3)]
+        rules: {
+            (0, 1) -> Right(0, 1),
+            (0, 2) -> Right(1, 1),
+            (1, 1) -> Right(1, 1),
+            (1, 0) -> Left(2, 0),
+            (2, 1) -> Stay(3, 0),
+        };
+    };
+    let codec = Tuple::new(Unary::new(1usize), 2);
+    for (a, b) in [(2, 3), (0, 4), (3, 0), (0, 0)] {
+        let sum = adder.compute(&codec, &vec![a, b], 100).unwrap();
+        assert_eq! { sum, vec![a + b] }
+    }
+}
+
+#[test]
+fn test_compute_binary_increment() {
+    // moves to the least significant digit before carrying leftwards
+    let increment = program! {
+        #[default_state(0)]
+        #[halt(2)]
+        rules: {
+            (0, '0') -> Right(0, '0'),
+            (0, '1') -> Right(0, '1'),
+            (0, '\0') -> Left(1, '\0'),
+            (1, '1') -> Left(1, '0'),
+            (1, '0') -> Stay(2, '1'),
+            (1, '\0') -> Stay(2, '1'),
+        };
+    };
+    let codec = Binary::big('0', '1');
+    for n in [0, 1, 6, 7, 255] {
+        assert_eq! { increment.compute(&codec, &n, 1_000).unwrap(), n + 1 }
+    }
+    assert! { matches!(increment.compute(&codec, &255, 4), Err(Error::StepLimitExceeded(4))) }
+    // a program without an initial state cannot be run
+    let program = rstm_core::Program::from_rules(increment.rules().clone());
+    assert! { matches!(program.compute(&codec, &1, 1_000), Err(Error::NoInitialState)) }
+}
+
+#[test]
+fn test_compute_binary_with_blank_zero() {
+    // the blank doubles as the digit `0`, so the trailing zeros must be kept
+    let identity = program! {
+        #[default_state(0)]
+        rules: {
+            (0, 1) -> Stay(1, 1),
+        };
+    };
+    let codec = Binary::big(0usize, 1);
+    for n in [0, 1, 4, 6, 8] {
+        assert_eq! { identity.compute(&codec, &n, 10).unwrap(), n }
+    }
+    // doubles the number by prepending its least significant digit
+    let double = program! {
+        #[default_state(0)]
+        rules: {
+            (0, 0) -> Left(1, 0),
+            (0, 1) -> Left(1, 1),
+        };
+    };
+    let codec = Binary::little(0usize, 1);
+    for n in [0, 1, 4, 5] {
+        assert_eq! { double.compute(&codec, &n, 10).unwrap(), 2 * n }
+    }
+}
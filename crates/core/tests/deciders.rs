@@ -0,0 +1,87 @@
+/*
+    Appellation: deciders <tests>
+    Created At: 2026.10.19:11:52:10
+    Contrib: @FL03
+*/
+use rstm_core::actors::Configuration;
+use rstm_core::search::{Certificate, Cycler, Decider, Decision, Enumerator, TranslatedCycler};
+use rstm_core::{Direction, Program, Rule};
+
+fn program(rules: impl IntoIterator<Item = Rule<usize, usize>>) -> Program<usize, usize> {
+    Program::from_rules(rules.into_iter().collect()).with_default_state(0)
+}
+
+#[test]
+fn test_cycler() {
+    // bounce between two cells forever
+    let program = program([
+        Rule::from_parts(0, 0, Direction::Right, 1, 0),
+        Rule::from_parts(1, 0, Direction::Left, 0, 0),
+    ]);
+    let config = Configuration::new(0);
+    let decision = Cycler::default().decide(&program, &config);
+    assert_eq! {
+        decision,
+        Decision::NeverHalts(Certificate::Cycler { start: 0, period: 2 })
+    }
+    assert! { decision.certificate().unwrap().verify(&program, &config) }
+    assert! { TranslatedCycler::new(100).decide(&program, &config).is_unknown() }
+    // tampering with the certificate should invalidate it
+    assert! { !Certificate::Cycler { start: 0, period: 3 }.verify(&program, &config) }
+}
+
+#[test]
+fn test_translated_cycler() {
+    // sweep to the right forever, leaving a trail of ones
+    let program = program([Rule::from_parts(0, 0, Direction::Right, 0, 1)]);
+    let config = Configuration::new(0);
+    assert! { Cycler::new(100).decide(&program, &config).is_unknown() }
+    let decision = TranslatedCycler::default().decide(&program, &config);
+    let certificate = decision
+        .certificate()
+        .copied()
+        .expect("expected a certificate");
+    assert! { certificate.is_translated_cycler() }
+    assert! { certificate.verify(&program, &config) }
+    assert! {
+        !Certificate::TranslatedCycler { start: 1, period: 1, shift: -1 }.verify(&program, &config)
+    }
+}
+
+#[test]
+fn test_deciders_halting() {
+    let program = program([
+        Rule::from_parts(0, 0, Direction::Right, 1, 1),
+        Rule::from_parts(0, 1, Direction::Left, 1, 1),
+        Rule::from_parts(1, 0, Direction::Left, 0, 1),
+        Rule::from_parts(1, 1, Direction::Right, usize::MAX, 1),
+    ]);
+    let config = Configuration::new(0);
+    assert_eq! { Cycler::default().decide(&program, &config), Decision::Halts(6) }
+    assert_eq! { TranslatedCycler::default().decide(&program, &config), Decision::Halts(6) }
+}
+
+#[test]
+fn test_deciders_busy_beaver_2x2() {
+    let deciders: [&dyn Decider<usize, usize>; 2] =
+        [&Cycler::new(200), &TranslatedCycler::new(200)];
+    let (mut cyclers, mut translated) = (0, 0);
+    for candidate in Enumerator::new(2, 2).with_step_limit(100) {
+        if candidate.is_halted() {
+            continue;
+        }
+        let program = candidate.program();
+        let config = Configuration::new(0);
+        // every certificate produced by the deciders should hold up to verification
+        for decider in deciders {
+            if let Decision::NeverHalts(certificate) = decider.decide(program, &config) {
+                assert! { certificate.verify(program, &config) }
+                match certificate {
+                    Certificate::Cycler { .. } => cyclers += 1,
+                    Certificate::TranslatedCycler { .. } => translated += 1,
+                }
+            }
+        }
+    }
+    assert! { cyclers > 0 && translated > 0 }
+}
@@ -3,7 +3,23 @@
     Created At: 2025.09.03:21:59:56
     Contrib: @FL03
 */
-use rstm_core::{MovingHead, program};
+use rstm_core::actors::LinearBounded;
+use rstm_core::search::{Certificate, Decision};
+use rstm_core::tape::TapeError;
+use rstm_core::{Direction, Error, HaltKind, Head, MovingHead, Program, program};
+
+/// the two-state busy beaver champion, which must grow the tape in either direction
+fn bb2() -> Program<isize, usize> {
+    program! {
+        #[default_state(0)]
+        rules: {
+            (0, 0) -> Right(1, 1),
+            (0, 1) -> Left(1, 1),
+            (1, 0) -> Left(0, 1),
+            (1, 1) -> Right(<isize>::MAX, 1),
+        };
+    }
+}
 
 #[test]
 fn test_head_engine() {
@@ -31,3 +47,272 @@ fn test_head_engine() {
     assert! { tm.is_halted() }
     assert_eq! { tm.cycles(), 9 }
 }
+
+#[test]
+fn test_head_engine_accepts_and_rejects() {
+    // accepts inputs containing an even number of ones, without resorting to sentinel states
+    let parity = || {
+        program! {
+            #[default_state(0)]
+            #[accept(2)]
+            #[reject(3)]
+            rules: {
+                (0, 1) -> Right(1, 1),
+                (1, 1) -> Right(0, 1),
+                (0, 0) -> Right(2, 0),
+                (1, 0) -> Right(3, 0),
+            };
+        }
+    };
+    let mut tm = MovingHead::tmh(parity());
+    tm.extend_tape([1usize, 1, 0]);
+    tm.run().expect("failed to execute the program...");
+    assert! { tm.is_halted() }
+    assert_eq! { tm.halt_kind(), Some(HaltKind::Accept) }
+    assert_eq! { tm.cycles(), 3 }
+
+    let mut tm = MovingHead::tmh(parity());
+    tm.extend_tape([1usize, 0]);
+    tm.run().expect("failed to execute the program...");
+    assert_eq! { tm.halt_kind(), Some(HaltKind::Reject) }
+    // declaring additional halting states leaves the others untouched
+    let mut tm = MovingHead::tmh(parity().with_halt_states([-1]));
+    assert_eq! { tm.halt_kind(), None }
+    tm.extend_tape([1usize, 1, 0]);
+    tm.run().expect("failed to execute the program...");
+    assert_eq! { tm.halt_kind(), Some(HaltKind::Accept) }
+    assert_eq! { tm.program().unwrap().halt_kind(&-1), Some(HaltKind::Halt) }
+}
+
+#[test]
+fn test_head_engine_inspection() {
+    let program = program! {
+        #[default_state(0)]
+        rules: {
+            (0, 0) -> Stay(1, 1),
+            (1, 1) -> Right(2, 0),
+        };
+    };
+    let mut tm = MovingHead::tmh(program);
+    tm.extend_tape([0usize, 1, 1, 1]);
+    assert_eq! { tm.print_with_radius(1), "[[0]]1" }
+    assert_eq! { tm.current_tail().map(|tail| tail.direction), Some(Direction::Stay) }
+    // clones of the engine are independent of one another
+    let snapshot = tm.clone();
+    tm.step::<Head<usize, usize>>().unwrap();
+    // remaining in place must not disturb the position of the head
+    assert_eq! { tm.current_position(), 0 }
+    assert_eq! { tm.print(), "[[1]]111" }
+    tm.step::<Head<usize, usize>>().unwrap();
+    assert_eq! { tm.current_position(), 1 }
+    assert_eq! { tm.print_with_radius(1), "0[[1]]1" }
+    assert! { tm.current_tail().is_none() }
+    assert_eq! { snapshot.cycles(), 0 }
+    assert_eq! { snapshot.print(), "[[0]]111" }
+}
+
+#[test]
+fn test_head_engine_follows_the_tape() {
+    use rstm_core::tape::TapeBase;
+    use std::collections::VecDeque;
+
+    let program = program! {
+        #[default_state(0)]
+        rules: {
+            (0, 1) -> Right(1, 0),
+            (1, 2) -> Stay(2, 2),
+        };
+    };
+    let mut tm =
+        MovingHead::from_program_with_tape(program, TapeBase::<VecDeque<usize>>::default());
+    assert! { matches!(tm.step::<Head<usize, usize>>(), Err(Error::TapeIsEmpty)) }
+    tm.extend_tape([1, 2]);
+    // growing the tape towards the left shifts the index of the head
+    tm.tape_mut().write_at(-2, 3);
+    assert_eq! { tm.current_position(), 2 }
+    assert_eq! { tm.read().ok(), Some(&1) }
+    assert! { tm.current_tail().is_some() }
+    assert_eq! { tm.print_with_radius(1), "0[[1]]2" }
+    tm.step::<Head<usize, usize>>().unwrap();
+    assert_eq! { tm.read().ok(), Some(&2) }
+    assert_eq! { tm.print(), "300[[2]]" }
+}
+
+#[test]
+fn test_head_engine_with_tape() {
+    use rstm_core::tape::TapeBase;
+    use std::collections::VecDeque;
+    let tape = TapeBase::<VecDeque<usize>>::from_iter([0]);
+    let mut tm = MovingHead::from_program_with_tape(bb2(), tape);
+    tm.run().expect("failed to execute the program...");
+    assert! { tm.is_halted() }
+    assert_eq! { tm.cycles(), 6 }
+    assert_eq! { tm.tape().iter_non_blank().count(), 4 }
+    assert_eq! { tm.tape().position(), 0 }
+    assert_eq! { tm.current_position(), tm.tape().head() }
+    // the driver follows the head after the tape is grown towards the left
+    assert_eq! { tm.driver().symbol, tm.current_position() }
+}
+
+#[test]
+fn test_head_engine_sparse() {
+    use rstm_core::tape::SparseTape;
+
+    let mut tm = MovingHead::from_program_with_tape(bb2(), SparseTape::from_iter([0]));
+    tm.run().unwrap();
+    // the cells read but never written by the engine are not retained
+    assert_eq! { tm.tape().count(), 4 }
+    assert_eq! { tm.tape().regions().collect::<Vec<_>>(), [-2..=1] }
+    // overwriting a cell with the blank prunes it from the store
+    tm.tape_mut().write_at(-1, 0);
+    assert_eq! { tm.tape().count(), 3 }
+    assert_eq! { tm.tape().regions().collect::<Vec<_>>(), [-2..=-2, 0..=1] }
+    // cells far from the rest are held without materializing those in between
+    tm.tape_mut().write_at(1_000_000, 1);
+    assert_eq! { tm.tape().count(), 4 }
+    assert! { tm.tape().store().len() > 1_000_000 }
+}
+
+#[test]
+fn test_head_engine_persistent() {
+    use rstm_core::tape::PersistentTape;
+
+    let mut tm = MovingHead::from_program_with_tape(bb2(), PersistentTape::from_iter([0usize; 3]));
+    tm.step::<Head<isize, usize>>().unwrap();
+    // forks of the engine share the cells of the tape until either is written to
+    let fork = tm.clone();
+    assert_eq! { fork.tape().store().shared_chunks(tm.tape().store()), 1 }
+    tm.step::<Head<isize, usize>>().unwrap();
+    assert_eq! { fork.tape().store().shared_chunks(tm.tape().store()), 0 }
+    // the fork is left untouched by the original
+    assert_eq! { fork.cycles(), 1 }
+    assert_eq! { fork.print(), "1[[0]]0" }
+}
+
+#[test]
+fn test_head_engine_instrumented() {
+    use rstm_core::tape::InstrumentedTape;
+
+    let tape = InstrumentedTape::<usize>::from_iter([0]);
+    let mut tm = MovingHead::from_program_with_tape(bb2(), tape);
+    tm.run().unwrap();
+    // the engine records every access made to the tape
+    let tape = tm.tape();
+    assert_eq! { tape.heatmap().collect::<Vec<_>>(), [(-2, 1), (-1, 2), (0, 2), (1, 1)] }
+    let cell = tape.cell_at(0).unwrap();
+    assert_eq! { cell.last_read(), Some(2) }
+    assert_eq! { cell.last_write(), Some(2) }
+    assert_eq! { cell.history(), [1, 1] }
+    assert_eq! { tape.dead_regions(4).collect::<Vec<_>>(), [0..=1] }
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_tmh_engine_instrumented() {
+    use rstm_core::actors::EngineBase;
+    use rstm_core::actors::drivers::TMH;
+    use rstm_core::tape::InstrumentedStore;
+
+    let driver = TMH::new(0, [0usize]);
+    let mut tm =
+        EngineBase::<_, _, _, InstrumentedStore<usize>>::from_driver(driver).with_program(bb2());
+    tm.run().unwrap();
+    // the deprecated driver records its accesses just like the moving head
+    let tape = tm.tape();
+    assert_eq! { tm.cycles(), 6 }
+    assert_eq! { tape.heatmap().collect::<Vec<_>>(), [(-2, 1), (-1, 2), (0, 2), (1, 1)] }
+    assert_eq! { tape.cell_at(0).unwrap().history(), [1, 1] }
+    assert_eq! { tm.driver().current_position(), tm.current_position() }
+}
+
+#[test]
+fn test_configuration_agrees_with_engine() {
+    use rstm_core::actors::Configuration;
+    use rstm_core::tape::DenseTape;
+    // the three-state busy beaver champion, whose tape grows in either direction
+    let program = "1RB1RZ_1LB0RC_1LC1LA"
+        .parse::<Program<usize, usize>>()
+        .unwrap();
+    let mut config = Configuration::new(0);
+    let mut tm = MovingHead::from_program_with_tape(program.clone(), DenseTape::from_iter([0]));
+    while !config.is_halted() {
+        config.step(&program).unwrap();
+        tm.step::<Head<usize, usize>>().unwrap();
+        assert_eq! { tm.current_state(), config.state().view() }
+        assert_eq! { tm.tape().position(), config.position() }
+        assert_eq! { tm.read().ok(), Some(config.read()) }
+    }
+    assert! { tm.is_halted() }
+    assert_eq! { tm.cycles(), config.steps() }
+    assert_eq! { tm.tape().iter_non_blank().count(), config.sigma() }
+}
+
+#[test]
+fn test_linear_bounded() {
+    // replace every `a` with a `b` before returning to the left marker
+    let program = program! {
+        #[default_state(0)]
+        rules: {
+            (0, 'a') -> Right(0, 'b'),
+            (0, '>') -> Left(1, '>'),
+            (1, 'b') -> Left(1, 'b'),
+            (1, '<') -> Right(isize::MAX, '<'),
+        };
+    };
+    let mut lba = LinearBounded::from_input(program.clone(), '<', "aa".chars(), '>').unwrap();
+    assert_eq! { lba.decide().unwrap(), Decision::Halts(6) }
+    lba.run().unwrap();
+    assert_eq! { lba.steps(), 6 }
+    assert_eq! { lba.tape().to_string(), "<, [b], b, >" }
+    // the head may not move beyond either marker...
+    let program = program! {
+        #[default_state(0)]
+        rules: {
+            (0, 'a') -> Right(0, 'a'),
+            (0, '>') -> Right(0, '>'),
+        };
+    };
+    let mut lba = LinearBounded::from_input(program, '<', "a".chars(), '>').unwrap();
+    assert! { matches!(lba.run(), Err(Error::TapeError(TapeError::CrossedEndMarker(Direction::Right)))) }
+    assert! { lba.decide().is_err() }
+    // ...nor overwrite them
+    let program = program! {
+        #[default_state(0)]
+        rules: {
+            (0, 'a') -> Right(0, 'a'),
+            (0, '>') -> Left(0, 'x'),
+        };
+    };
+    let mut lba = LinearBounded::from_input(program.clone(), '<', "a".chars(), '>').unwrap();
+    assert! { matches!(lba.run(), Err(Error::TapeError(TapeError::OverwroteEndMarker))) }
+    assert_eq! { lba.steps(), 1 }
+    assert_eq! { lba.tape().to_string(), "<, a, [>]" }
+    // the machine must begin in the initial state declared by the program
+    let program = Program::from_rules(program.rules().clone());
+    assert! {
+        matches!(
+            LinearBounded::from_input(program, '<', "a".chars(), '>'),
+            Err(Error::NoInitialState)
+        )
+    }
+}
+
+#[test]
+fn test_linear_bounded_decides_loops() {
+    // bounce between the markers forever
+    let program = program! {
+        #[default_state(0)]
+        rules: {
+            (0, 'a') -> Right(0, 'a'),
+            (0, '>') -> Left(1, '>'),
+            (1, 'a') -> Left(1, 'a'),
+            (1, '<') -> Right(0, '<'),
+        };
+    };
+    let lba = LinearBounded::from_input(program, '<', "a".chars(), '>').unwrap();
+    assert_eq! {
+        lba.decide().unwrap(),
+        Decision::NeverHalts(Certificate::Cycler { start: 0, period: 4 })
+    }
+    assert! { !lba.halts().unwrap() }
+}
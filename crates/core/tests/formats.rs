@@ -0,0 +1,284 @@
+/*
+    Appellation: formats <tests>
+    Created At: 2026.10.19:12:41:55
+    Contrib: @FL03
+*/
+use rstm_core::actors::Configuration;
+use rstm_core::formats::CompactProgram;
+use rstm_core::programs::CANONICAL_HALT;
+use rstm_core::{Direction, Error, HaltKind, Program, Rule};
+
+#[test]
+fn test_compact_roundtrip() {
+    for machine in [
+        "1RB1LB_1LA1RZ",
+        "1RB1RZ_1LB0RC_1LC1LA",
+        "1RB1LB_1LA0LC_1RZ1LD_1RD0RA",
+        "1RB2LA1RA_2LA2RB0RA",
+        "1RB---_0LA1RZ",
+    ] {
+        let program = machine.parse::<Program<usize, usize>>().unwrap();
+        assert_eq! { program.to_compact().unwrap().to_string(), machine }
+        let compact = machine.parse::<CompactProgram>().unwrap();
+        assert_eq! { compact.program(), &program }
+        assert_eq! { compact.to_string(), machine }
+    }
+}
+
+#[test]
+fn test_compact_parse() {
+    let program = "1RB1LB_1LA1RZ".parse::<Program<usize, usize>>().unwrap();
+    assert_eq! { program.initial_state().map(|s| *s.get()), Some(0) }
+    assert_eq! {
+        program.rules(),
+        &[
+            Rule::from_parts(0, 0, Direction::Right, 1, 1),
+            Rule::from_parts(0, 1, Direction::Left, 1, 1),
+            Rule::from_parts(1, 0, Direction::Left, 0, 1),
+            Rule::from_parts(1, 1, Direction::Right, CANONICAL_HALT, 1),
+        ]
+    }
+    // the four-state busy beaver champion
+    let program = "1RB1LB_1LA0LC_1RZ1LD_1RD0RA"
+        .parse::<Program<usize, usize>>()
+        .unwrap();
+    let mut config = Configuration::new(0);
+    while !config.is_halted() {
+        config.step(&program).unwrap();
+    }
+    assert_eq! { (config.steps(), config.sigma()), (107, 13) }
+}
+
+#[test]
+fn test_compact_errors() {
+    let position = |machine: &str| match machine.parse::<Program<usize, usize>>() {
+        Err(Error::ParseRuleError { pos, .. }) => pos,
+        other => panic!("expected a parse error for {machine}, found {other:?}"),
+    };
+    assert_eq! { position(""), 0 }
+    assert_eq! { position("1RB1LB_1LA1R"), 7 }
+    assert_eq! { position("1XB1LB_1LA1RZ"), 1 }
+    assert_eq! { position("1RB1LB_1LA1RC"), 12 }
+    assert_eq! { position("1RB1LB_1LA2RZ"), 10 }
+    assert_eq! { position("1RB1LB_1LA1RZ1RZ"), 7 }
+    // programs exceeding the limits of the format cannot be written
+    let program = |rule| Program::from_rules(vec![rule]).with_default_state(0);
+    let many_states = program(Rule::from_parts(0, 0, Direction::Right, 25, 1));
+    let many_symbols = program(Rule::from_parts(0, 0, Direction::Right, 0, 10));
+    for program in [many_states, many_symbols] {
+        assert! { matches!(program.to_compact(), Err(Error::Unrepresentable { .. })) }
+        assert! { CompactProgram::try_from(program).is_err() }
+    }
+}
+
+const TM_INCREMENT: &str = "\
+; binary increment: seek the rightmost digit before adding one
+0 * * r 0
+0 _ _ l 1
+
+1 1 0 l 1   ; carry the one
+1 0 1 * halt
+1 _ 1 * halt
+";
+
+#[test]
+fn test_tm_parse() {
+    use rstm_core::formats::parse_tm;
+
+    let program = parse_tm(TM_INCREMENT, &['0', '1']).unwrap();
+    // the wildcard expands over the blank and both digits; the explicit rule takes precedence
+    assert_eq! { program.len(), 6 }
+    assert_eq! { program.initial_state().map(|s| s.get().as_str()), Some("0") }
+
+    assert_eq! { program.halt_kind(&String::from("halt")), Some(HaltKind::Halt) }
+    // the machine runs until it reaches the declared halting state
+    let mut config = Configuration::from_input(String::from("0"), "1011".chars());
+    while program.halt_kind(config.state().get()).is_none() {
+        config.step(&program).unwrap();
+    }
+    assert_eq! { config.state().get(), "halt" }
+    let output = config
+        .tape()
+        .iter()
+        .filter(|&&c| c != char::default())
+        .collect::<String>();
+    assert_eq! { output, "1100" }
+}
+
+#[test]
+fn test_tm_accepts() {
+    use rstm_core::formats::parse_tm;
+    // accepts the words over `a` of even length
+    let program = parse_tm(
+        "0 a a r 1\n0 _ _ * halt-accept\n1 a a r 0\n1 _ _ * halt-reject",
+        &['a'],
+    )
+    .unwrap();
+    let halts = |input: &str| {
+        let mut config = Configuration::from_input(String::from("0"), input.chars());
+        loop {
+            if let Some(kind) = program.halt_kind(config.state().get()) {
+                return kind;
+            }
+            config.step(&program).unwrap();
+        }
+    };
+    assert_eq! { halts("aa"), HaltKind::Accept }
+    assert_eq! { halts("aaa"), HaltKind::Reject }
+    assert_eq! { halts(""), HaltKind::Accept }
+}
+
+#[test]
+fn test_tm_wildcards() {
+    use rstm_core::formats::parse_tm;
+
+    let program = parse_tm("* a * l 1\n0 a b r *\n1 * c * halt", &['a']).unwrap();
+    let tail = |state: &str, symbol| {
+        let state = String::from(state);
+        program
+            .find_tail(rstm_core::State(&state), &symbol)
+            .map(|tail| {
+                (
+                    tail.direction,
+                    tail.next_state.get().clone(),
+                    tail.write_symbol,
+                )
+            })
+    };
+    assert_eq! { tail("0", 'a'), Some((Direction::Right, String::from("0"), 'b')) }
+    assert_eq! { tail("1", 'a'), Some((Direction::Stay, String::from("halt"), 'c')) }
+    assert_eq! { tail("0", 'b'), None }
+    assert_eq! { tail("1", 'b'), Some((Direction::Stay, String::from("halt"), 'c')) }
+}
+
+#[test]
+fn test_tm_roundtrip() {
+    use rstm_core::formats::{parse_tm, to_tm};
+
+    let program = parse_tm(TM_INCREMENT, &['0', '1']).unwrap();
+    let text = to_tm(&program).unwrap();
+    assert! { text.contains("0 _ _ l 1") }
+    assert_eq! { parse_tm(&text, &[]).unwrap(), program }
+    // the initial state is always written as `0`
+    let swapped = Program::from_rules(vec![
+        Rule::from_parts("a".to_string(), '1', Direction::Right, "0".to_string(), '1'),
+        Rule::from_parts(
+            "0".to_string(),
+            '1',
+            Direction::Left,
+            "halt".to_string(),
+            '1',
+        ),
+    ])
+    .with_default_state("a".to_string());
+    assert_eq! { to_tm(&swapped).unwrap(), "0 1 1 r a\na 1 1 l halt\n" }
+    // states and symbols reserved by the format cannot be written
+    for (state, symbol) in [("a b", 'x'), ("a", '*'), ("a", ' ')] {
+        let rule = Rule::from_parts(
+            state.to_string(),
+            symbol,
+            Direction::Left,
+            "a".to_string(),
+            'x',
+        );
+        let program = Program::from_rules(vec![rule]);
+        assert! { matches!(to_tm(&program), Err(Error::Unrepresentable { .. })) }
+    }
+}
+
+#[test]
+fn test_tm_errors() {
+    use rstm_core::formats::parse_tm;
+
+    let position = |input: &str| match parse_tm(input, &[]) {
+        Err(Error::ParseRuleError { pos, .. }) => pos,
+        other => panic!("expected a parse error for {input:?}, found {other:?}"),
+    };
+    assert_eq! { position("0 _ 1 r 1\n1 _ 1 r"), 10 }
+    assert_eq! { position("0 _ 1 x 1"), 6 }
+    assert_eq! { position("0 _ 1 r 1 2"), 10 }
+    assert_eq! { position("0 ab 1 r 1"), 2 }
+    assert_eq! { position("0 _ 1 r 1\n; comment\n0 _ 0 l 0"), 20 }
+}
+
+#[test]
+fn test_notation_parse() {
+    use rstm_core::formats::parse_program;
+
+    let input = r#"
+        // a simple, two-state machine
+        #[default_state(0)]
+        rules: {
+            (0, 0) -> Right(1, 1),
+            (0, 1) -> Left(-1, 0), /* halts */
+            (1, 0) -> Stay(0, 1),
+        };
+    "#;
+    let program = parse_program::<isize, usize>(input).unwrap();
+    assert_eq! { program.initial_state().map(|s| *s.get()), Some(0) }
+    assert_eq! {
+        program.rules(),
+        &[
+            Rule::from_parts(0, 0, Direction::Right, 1, 1),
+            Rule::from_parts(0, 1, Direction::Left, -1, 0),
+            Rule::from_parts(1, 0, Direction::Stay, 0, 1),
+        ]
+    }
+    // a bare list of rules using quoted symbols
+    let program = parse_program::<String, char>(r#"("a", 'x') -> Right("b", '\0'),"#).unwrap();
+    assert! { program.initial_state().is_none() }
+    assert_eq! {
+        program.rules(),
+        &[Rule::from_parts("a".to_string(), 'x', Direction::Right, "b".to_string(), '\0')]
+    }
+    // the debug representation of unusual symbols is accepted
+    let input = format!("(\"a\", {:?}) -> Left(\"b\", {:?}),", '\u{7f}', '\u{301}');
+    let program = parse_program::<String, char>(&input).unwrap();
+    assert_eq! {
+        program.rules(),
+        &[Rule::from_parts("a".to_string(), '\u{7f}', Direction::Left, "b".to_string(), '\u{301}')]
+    }
+}
+
+#[test]
+#[cfg(feature = "macros")]
+fn test_notation_matches_macro() {
+    use rstm_core::formats::{parse_program, parse_rule};
+
+    let expected = rstm_core::program! {
+        #[default_state(0)]
+        rules: {
+            (0, 'a') -> Right(1, 'b'),
+            (1, 'b') -> Left(0, 'a'),
+        };
+    };
+    let program = parse_program::<isize, char>(
+        "#[default_state(0)] rules: { (0, 'a') -> Right(1, 'b'), (1, 'b') -> Left(0, 'a') };",
+    )
+    .unwrap();
+    assert_eq! { program, expected }
+    assert_eq! {
+        parse_rule::<isize, char>("(0, 'a') -> Right(1, 'c')").unwrap(),
+        rstm_core::rule![(0, 'a') -> Right(1, 'c')]
+    }
+}
+
+#[test]
+fn test_notation_errors() {
+    use rstm_core::formats::parse_program;
+
+    let position = |input: &str| match parse_program::<isize, usize>(input) {
+        Err(Error::SyntaxError { line, column, .. }) => (line, column),
+        other => panic!("expected a syntax error for {input:?}, found {other:?}"),
+    };
+    assert_eq! { position("(0, 0) -> Up(1, 1)"), (1, 11) }
+    assert_eq! { position("(0, 0) -> Right(1, 1),\n(0, x) -> Left(0, 0)"), (2, 5) }
+    assert_eq! { position("(0, 0) Right(1, 1)"), (1, 8) }
+    assert_eq! { position("rules: {\n  (0, 0) -> Right(1, 1)\n"), (3, 1) }
+    assert_eq! { position("#[initial(0)]"), (1, 3) }
+    assert_eq! { position("(0, 0) -> Right(1, 1),\n(0, 0) -> Left(1, 1)"), (2, 1) }
+    assert_eq! { position("/* (0, 0) -> Right(1, 1)"), (1, 1) }
+    assert_eq! { position("(0, 0) -> Right(1, 1) (1, 0) -> Left(0, 0)"), (1, 23) }
+    assert_eq! { position("(0, '\\u{110000}') -> Left(0, 0)"), (1, 5) }
+    assert_eq! { position("(0, '\\u7f') -> Left(0, 0)"), (1, 5) }
+}
@@ -0,0 +1,72 @@
+/*
+    Appellation: interner <tests>
+    Created At: 2026.10.19:22:04:37
+    Contrib: @FL03
+*/
+use rstm_core::programs::{Interner, StateId, SymId};
+use rstm_core::{Direction, Halting, MovingHead, Program, Rule};
+
+/// the two-state, two-symbol busy beaver champion using readable states and symbols
+fn bb2() -> Program<String, char> {
+    let q = |s: &str| s.to_string();
+    Program::from_rules(vec![
+        Rule::from_parts(q("start"), '\0', Direction::Right, q("next"), '1'),
+        Rule::from_parts(q("start"), '1', Direction::Left, q("next"), '1'),
+        Rule::from_parts(q("next"), '\0', Direction::Left, q("start"), '1'),
+        Rule::from_parts(q("next"), '1', Direction::Right, q("halt"), '1'),
+    ])
+    .with_default_state(q("start"))
+}
+
+#[test]
+fn test_intern_program() {
+    let program = bb2();
+    let (interned, interner) = program.intern().unwrap();
+    assert_eq! { interned.initial_state().map(|s| *s.get()), Some(StateId::new(0)) }
+    assert_eq! { interner.state_id(&"next".to_string()), Some(StateId::new(1)) }
+    // states without any rules are considered halting
+    let halt = interner.state_id(&"halt".to_string()).unwrap();
+    assert! { halt.is_halted() }
+    assert_eq! { interner.state(halt).map(String::as_str), Some("halt") }
+    // the blank is always assigned the first id
+    assert_eq! { interner.symbol_id(&'\0'), Some(SymId::BLANK) }
+    assert_eq! { interner.symbol_id(&'1'), Some(SymId::new(1)) }
+    assert_eq! { interner.resolve_program(&interned), Some(program) }
+}
+
+#[test]
+fn test_interned_execution() {
+    let (program, interner) = bb2().intern().unwrap();
+    let mut tm = MovingHead::tmh(program);
+    tm.extend_tape([SymId::BLANK]);
+    tm.run().expect("failed to execute the program...");
+    assert_eq! { tm.cycles(), 6 }
+    assert_eq! { interner.state(**tm.current_state().get()).map(String::as_str), Some("halt") }
+    let tape = interner.resolve_symbols(tm.tape().iter().copied()).unwrap();
+    assert_eq! { tape.into_iter().collect::<String>(), "1111" }
+}
+
+#[test]
+fn test_state_id_bounds() {
+    // the most significant bit is reserved for the halt flag
+    let id = StateId::checked(StateId::MAX_INDEX, true).unwrap();
+    assert! { id.is_halt() }
+    assert_eq! { id.index(), StateId::MAX_INDEX as usize }
+    assert_eq! { StateId::checked(StateId::MAX_INDEX + 1, false), None }
+    assert_eq! { StateId::checked(u32::MAX, true), None }
+}
+
+#[test]
+#[should_panic(expected = "halt flag")]
+fn test_state_id_overflow() {
+    StateId::new(StateId::MAX_INDEX + 1);
+}
+
+#[test]
+fn test_intern_input() {
+    let mut interner = Interner::<String, char>::new();
+    let input = interner.intern_input(&['a', 'b', 'a', '\0']);
+    assert_eq! { input, [SymId::new(1), SymId::new(2), SymId::new(1), SymId::BLANK] }
+    assert_eq! { interner.symbols().len(), 3 }
+    assert_eq! { interner.resolve_symbols([SymId::new(7)]), None }
+}
@@ -0,0 +1,84 @@
+/*
+    Appellation: jflap <tests>
+    Created At: 2026.10.19:14:22:37
+    Contrib: @FL03
+*/
+use rstm_core::actors::Configuration;
+use rstm_core::formats::{JflapAction, JflapMachine};
+use rstm_core::{Direction, Error, HaltKind};
+
+const INCREMENT: &str = include_str!("data/increment.jff");
+const COPY: &str = include_str!("data/copy.jff");
+
+#[test]
+fn test_jflap_roundtrip() {
+    for input in [INCREMENT, COPY] {
+        let machine = JflapMachine::from_xml(input).unwrap();
+        assert_eq! { JflapMachine::from_xml(&machine.to_xml()).unwrap(), machine }
+    }
+}
+
+#[test]
+fn test_jflap_single_tape() {
+    let machine = JflapMachine::from_xml(INCREMENT).unwrap();
+    assert_eq! { machine.tapes, 1 }
+    assert_eq! { machine.states.len(), 3 }
+    assert_eq! { machine.transitions.len(), 6 }
+
+    let program = machine.to_program().unwrap();
+    assert_eq! { program.halt_kind(&"done".to_string()), Some(HaltKind::Accept) }
+    assert_eq! { program.initial_state().map(|s| s.get().as_str()), Some("seek") }
+
+    let mut config = Configuration::from_input(String::from("seek"), "1011".chars());
+    while program.halt_kind(config.state().get()).is_none() {
+        config.step(&program).unwrap();
+    }
+    let output = config
+        .tape()
+        .iter()
+        .filter(|&&c| c != char::default())
+        .collect::<String>();
+    assert_eq! { output, "1100" }
+    // exporting the program should produce an equivalent machine
+    let exported = JflapMachine::from_program(&program);
+    assert! { exported.states.iter().any(|s| s.accept && s.name == "done") }
+    assert_eq! { exported.to_program().unwrap(), program }
+}
+
+#[test]
+fn test_jflap_multi_tape() {
+    let machine = JflapMachine::from_xml(COPY).unwrap();
+    assert_eq! { machine.tapes, 2 }
+    assert_eq! {
+        machine.transitions[0].tapes,
+        [
+            JflapAction { read: Some('a'), write: Some('a'), direction: Direction::Right },
+            JflapAction { read: None, write: Some('a'), direction: Direction::Right },
+        ]
+    }
+    // multi-tape machines cannot be represented by a program
+    assert! { machine.to_program().is_err() }
+}
+
+#[test]
+fn test_jflap_errors() {
+    let fsa = r#"<structure><type>fa</type><automaton/></structure>"#;
+    assert! {
+        matches!(
+            JflapMachine::from_xml(fsa),
+            Err(Error::SyntaxError { line: 1, column: 12, .. })
+        )
+    }
+    let invalid = INCREMENT.replace("<move>S</move>", "<move>X</move>");
+    assert! { matches!(JflapMachine::from_xml(&invalid), Err(Error::SyntaxError { .. })) }
+    assert! {
+        matches!(
+            JflapMachine::from_xml("<structure>\n<type>turing</type>"),
+            Err(Error::SyntaxError { line: 2, column: 20, .. })
+        )
+    }
+    // transitions without any actions cannot be converted into a program
+    let mut machine = JflapMachine::from_xml(INCREMENT).unwrap();
+    machine.transitions[0].tapes.clear();
+    assert! { machine.to_program().is_err() }
+}
@@ -0,0 +1,62 @@
+/*
+    Appellation: language <tests>
+    Created At: 2026.10.19:22:58:12
+    Contrib: @FL03
+*/
+use rstm_core::programs::Verdict;
+use rstm_core::{Error, Program, program};
+
+/// accepts the words over `{1, 2}` containing an even number of ones
+fn even_ones() -> Program<isize, usize> {
+    program! {
+        #[default_state(0)]
+        #[accept(2)]
+        #[reject(3)]
+        rules: {
+            (0, 1) -> Right(1, 1),
+            (0, 2) -> Right(0, 2),
+            (1, 1) -> Right(0, 1),
+            (1, 2) -> Right(1, 2),
+            (0, 0) -> Stay(2, 0),
+            (1, 0) -> Stay(3, 0),
+        };
+    }
+}
+
+#[test]
+fn test_accepts() {
+    let program = even_ones();
+    assert_eq! { program.accepts([1, 2, 1], 100).unwrap(), Verdict::Accept { steps: 4 } }
+    assert_eq! { program.accepts([], 100).unwrap(), Verdict::Accept { steps: 1 } }
+    assert_eq! { program.accepts([2, 1], 100).unwrap(), Verdict::Reject { steps: 3 } }
+    // the program defines no rule for the symbol `3`
+    assert_eq! { program.accepts([1, 3], 100).unwrap(), Verdict::NoTransition { steps: 1 } }
+    assert_eq! { program.accepts([1, 2, 1], 2).unwrap(), Verdict::Timeout { steps: 2 } }
+    // a program without an initial state cannot be run
+    let program = Program::<isize, usize>::from_rules(even_ones().rules().clone());
+    assert! { matches!(program.accepts([1], 100), Err(Error::NoInitialState)) }
+}
+
+#[test]
+fn test_accepts_timeout() {
+    // never leaves the initial state
+    let program = program! {
+        #[default_state(0)]
+        #[accept(1)]
+        rules: {
+            (0, 0) -> Right(0, 0),
+        };
+    };
+    let verdict = program.accepts([0usize], 1_000).unwrap();
+    assert! { verdict.is_timeout() }
+    assert_eq! { verdict.steps(), 1_000 }
+}
+
+#[test]
+fn test_accepted_words() {
+    let words = even_ones().accepted_words(&[1, 2], 2, 100).unwrap();
+    assert_eq! { words, vec![vec![], vec![2], vec![1, 1], vec![2, 2]] }
+    // only the empty word may be formed without an alphabet
+    let words = even_ones().accepted_words(&[], 4, 100).unwrap();
+    assert_eq! { words, vec![Vec::<usize>::new()] }
+}
@@ -0,0 +1,121 @@
+/*
+    Appellation: machines <tests>
+    Created At: 2026.10.19:00:12:55
+    Contrib: @FL03
+*/
+use rstm_core::actors::Configuration;
+use rstm_core::compute::{Binary, Tuple, Unary};
+use rstm_core::machines::{self, BUSY_BEAVERS};
+use rstm_core::programs::{Program, Verdict};
+use rstm_core::{Halting, RawState};
+
+/// runs the program from the given configuration until it halts
+fn run<Q, A>(program: &Program<Q, A>, config: &mut Configuration<Q, A>)
+where
+    Q: RawState + Clone + Halting + PartialEq,
+    A: Clone + Default + PartialEq,
+{
+    while !config.is_halted() {
+        config
+            .step(program)
+            .expect("the machine halted prematurely");
+    }
+}
+
+/// returns the tape of the configuration, trimmed of any leading or trailing blanks
+fn trimmed<Q, A>(config: &Configuration<Q, A>) -> Vec<A>
+where
+    Q: RawState,
+    A: Clone + Default + PartialEq,
+{
+    let blank = A::default();
+    let tape = config.tape().iter().cloned().collect::<Vec<_>>();
+    let start = tape.iter().position(|s| s != &blank).unwrap_or(tape.len());
+    let end = tape
+        .iter()
+        .rposition(|s| s != &blank)
+        .map_or(start, |i| i + 1);
+    tape[start..end].to_vec()
+}
+
+#[test]
+fn test_busy_beavers() {
+    for champion in BUSY_BEAVERS.iter() {
+        let mut config = Configuration::new(0);
+        run(&champion.program(), &mut config);
+        assert_eq! { config.steps(), champion.steps() }
+        assert_eq! { config.sigma(), champion.sigma() }
+    }
+    assert! { machines::busy_beaver(1).is_none() && machines::busy_beaver(6).is_none() }
+}
+
+#[test]
+fn test_arithmetic() {
+    let adder = machines::unary_adder();
+    let codec = Tuple::new(Unary::new(1), 2);
+    for (a, b) in [(0, 0), (1, 0), (0, 3), (4, 7)] {
+        assert_eq! { adder.compute(&codec, &vec![a, b], 100).unwrap(), vec![a + b] }
+    }
+    let incrementer = machines::binary_incrementer();
+    let codec = Binary::big('0', '1');
+    for n in [0, 1, 2, 7, 8, 1023] {
+        assert_eq! { incrementer.compute(&codec, &n, 1_000).unwrap(), n + 1 }
+    }
+}
+
+#[test]
+fn test_string_copier() {
+    let copier = machines::string_copier();
+    for word in ["", "a", "ab", "abba", "babab"] {
+        let mut config = Configuration::from_input(0, word.chars());
+        run(&copier, &mut config);
+        let copy = trimmed(&config).into_iter().collect::<String>();
+        assert_eq! { copy, format!("{word}#{word}") }
+    }
+}
+
+#[test]
+fn test_recognizers() {
+    let palindromes = machines::palindromes();
+    let words = palindromes.accepted_words(&['a', 'b'], 4, 1_000).unwrap();
+    let expected = ["", "a", "b", "aa", "bb", "aaa", "aba", "bab", "bbb"]
+        .into_iter()
+        .chain(["aaaa", "abba", "baab", "bbbb"])
+        .map(|w| w.chars().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    assert_eq! { words, expected }
+    assert! { palindromes.accepts("abab".chars(), 1_000).unwrap().is_reject() }
+
+    let zeros_then_ones = machines::zeros_then_ones();
+    let words = zeros_then_ones
+        .accepted_words(&['0', '1'], 6, 1_000)
+        .unwrap();
+    let expected = ["", "01", "0011", "000111"]
+        .map(|w| w.chars().collect::<Vec<_>>())
+        .to_vec();
+    assert_eq! { words, expected }
+    assert! { matches!(zeros_then_ones.accepts("0101".chars(), 1_000).unwrap(), Verdict::Reject { .. }) }
+}
+
+#[test]
+fn test_universal() {
+    let universal = machines::universal();
+    for champion in BUSY_BEAVERS.iter().filter(|c| c.states() < 5) {
+        let program = champion.program();
+        // run the machine directly
+        let mut expected = Configuration::new(0);
+        run(&program, &mut expected);
+        // simulate the machine using the universal machine
+        let tape = machines::encode_universal(&program, &[]).unwrap();
+        let mut config = Configuration::from_input(0, tape);
+        run(&universal, &mut config);
+        let simulated = machines::decode_universal(config.tape()).unwrap();
+        assert_eq! { simulated.iter().filter(|&&s| s == 1).count(), champion.sigma() }
+        let start = simulated.iter().position(|&s| s == 1).unwrap();
+        let end = simulated.iter().rposition(|&s| s == 1).unwrap();
+        assert_eq! { simulated[start..=end], trimmed(&expected) }
+    }
+    // machines using other symbols or staying in place cannot be encoded
+    let program = machines::unary_adder();
+    assert! { machines::encode_universal(&program, &[1, 2, 1]).is_none() }
+}
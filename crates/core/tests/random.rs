@@ -0,0 +1,161 @@
+/*
+    Appellation: random <tests>
+    Created At: 2026.10.19:02:41:06
+    Contrib: @FL03
+*/
+use rand::SeedableRng;
+use rand::distr::Distribution;
+use rand_chacha::ChaCha8Rng;
+use rstm_core::Direction;
+use rstm_core::programs::{CANONICAL_HALT, Program};
+use rstm_core::random::ProgramGenerator;
+
+/// returns true if a halting transition, defined or otherwise, is reachable from the initial
+/// state within the transition graph of the program
+fn reaches_halt(program: &Program<usize, char>, states: usize, alphabet: &[char]) -> bool {
+    let mut visited = vec![false; states];
+    let mut stack = vec![0];
+    visited[0] = true;
+    while let Some(q) = stack.pop() {
+        for a in alphabet {
+            let next = program
+                .iter()
+                .find(|rule| *rule.state().get() == q && rule.symbol() == a)
+                .map(|rule| *rule.next_state().get());
+            match next {
+                None | Some(CANONICAL_HALT) => return true,
+                Some(p) if !visited[p] => {
+                    visited[p] = true;
+                    stack.push(p);
+                }
+                Some(_) => {}
+            }
+        }
+    }
+    false
+}
+
+#[test]
+fn test_seeded_programs() {
+    let generator = ProgramGenerator::new(3, ['0', '1']);
+    assert_eq! { generator.generate_seeded(42), generator.generate_seeded(42) }
+    assert_eq! { generator.population(8, 7), generator.population(8, 7) }
+    // sampling the generator as a distribution is equivalent to generating programs
+    let programs = (&generator)
+        .sample_iter(ChaCha8Rng::seed_from_u64(7))
+        .take(8)
+        .collect::<Vec<_>>();
+    assert_eq! { programs, generator.population(8, 7) }
+}
+
+#[test]
+fn test_total_programs() {
+    let generator = ProgramGenerator::new(4, ['0', '1', '2']);
+    for program in generator.population(64, 0) {
+        assert_eq! { program.len(), 12 }
+        assert_eq! { program.initial_state().map(|q| *q.get()), Some(0) }
+        for q in 0..4 {
+            for a in ['0', '1', '2'] {
+                let count = program
+                    .iter()
+                    .filter(|rule| *rule.state().get() == q && *rule.symbol() == a)
+                    .count();
+                assert_eq! { count, 1 }
+            }
+        }
+        assert! {
+            program.iter().all(|rule| {
+                let next = *rule.next_state().get();
+                next < 4 || next == CANONICAL_HALT
+            })
+        }
+    }
+}
+
+#[test]
+fn test_partial_programs() {
+    let generator = ProgramGenerator::new(2, ['0', '1'])
+        .with_total(false)
+        .with_halt_probability(1.0);
+    assert! { generator.population(8, 0).iter().all(|program| program.is_empty()) }
+    // halting transitions are left undefined rather than moving onto the halting state
+    let generator = generator.with_halt_probability(0.5);
+    for program in generator.population(64, 0) {
+        assert! { program.len() <= 4 }
+        assert! { program.iter().all(|rule| *rule.next_state().get() != CANONICAL_HALT) }
+    }
+}
+
+#[test]
+fn test_reachable_halt() {
+    let alphabet = ['0', '1'];
+    for total in [true, false] {
+        let generator = ProgramGenerator::new(5, alphabet)
+            .with_halt_probability(0.0)
+            .with_total(total)
+            .with_reachable_halt(true);
+        for program in generator.population(128, 3) {
+            assert! { reaches_halt(&program, 5, &alphabet) }
+        }
+    }
+    // without the guarantee, programs never halting are generated
+    let generator = ProgramGenerator::new(5, alphabet).with_halt_probability(0.0);
+    assert! {
+        generator
+            .population(16, 3)
+            .iter()
+            .all(|program| !reaches_halt(program, 5, &alphabet))
+    }
+}
+
+#[test]
+fn test_direction_weights() {
+    let generator = ProgramGenerator::new(3, ['0', '1']);
+    assert! {
+        generator
+            .population(32, 1)
+            .iter()
+            .flat_map(|program| program.iter())
+            .all(|rule| rule.direction() != Direction::Stay)
+    }
+    let generator = generator.with_direction_weights(0.0, 0.0, 1.0);
+    assert! {
+        generator
+            .population(32, 1)
+            .iter()
+            .flat_map(|program| program.iter())
+            .all(|rule| rule.direction() == Direction::Stay)
+    }
+}
+
+#[test]
+#[should_panic(expected = "halting probability")]
+fn test_invalid_halt_probability() {
+    let _ = ProgramGenerator::new(2, [0u8, 1]).with_halt_probability(1.5);
+}
+
+#[test]
+fn test_random_programs_run() {
+    let generator = ProgramGenerator::new(3, [0u8, 1])
+        .with_total(false)
+        .with_reachable_halt(true);
+    for program in generator.population(256, 11) {
+        let verdict = program
+            .accepts([1, 0, 1], 100)
+            .expect("the program has a default state");
+        assert! { verdict.steps() <= 100 }
+        assert! { !verdict.is_accept() }
+    }
+}
+
+#[test]
+fn test_seeded_programs_are_pinned() {
+    // the seeded programs must not depend on the platform or the version of `rand`
+    let generator = ProgramGenerator::new(2, [0usize, 1]);
+    let program = generator
+        .generate_seeded(42)
+        .to_compact()
+        .unwrap()
+        .to_string();
+    assert_eq! { program, "0RB1RZ_1LZ1RZ" }
+}
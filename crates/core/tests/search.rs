@@ -0,0 +1,59 @@
+/*
+    Appellation: search <tests>
+    Created At: 2026.10.19:10:52:36
+    Contrib: @FL03
+*/
+use rstm_core::actors::Configuration;
+use rstm_core::search::{Enumerator, Outcome};
+
+/// returns the maximum number of steps and the maximum number of non-blank symbols across
+/// the halting candidates of the given enumerator
+fn champions(enumerator: Enumerator) -> (usize, usize) {
+    enumerator
+        .filter_map(|candidate| match candidate.outcome() {
+            Outcome::Halted { steps, sigma } => Some((steps, sigma)),
+            _ => None,
+        })
+        .fold((0, 0), |(s, p), (steps, sigma)| {
+            (s.max(steps), p.max(sigma))
+        })
+}
+
+#[test]
+fn test_busy_beaver_2x2() {
+    assert_eq! { champions(Enumerator::new(2, 2).with_step_limit(100)), (6, 4) }
+}
+
+#[test]
+fn test_busy_beaver_3x2() {
+    assert_eq! { champions(Enumerator::new(3, 2).with_step_limit(100)), (21, 6) }
+}
+
+#[test]
+fn test_enumerator_replay() {
+    // every halting candidate should halt when replayed from scratch
+    for candidate in Enumerator::new(2, 2).with_step_limit(100) {
+        let Outcome::Halted { steps, sigma } = candidate.outcome() else {
+            assert! { candidate.outcome().steps() >= 100 }
+            continue;
+        };
+        let mut config = Configuration::<usize, usize>::new(0);
+        while !config.is_halted() {
+            config
+                .step(candidate.program())
+                .expect("the program should be defined up until it halts");
+        }
+        assert_eq! { config.steps(), steps }
+        assert_eq! { config.sigma(), sigma }
+    }
+}
+
+#[test]
+fn test_enumerator_space_limit() {
+    let undecided = Enumerator::new(2, 2)
+        .with_step_limit(100)
+        .with_space_limit(8)
+        .filter(|candidate| candidate.outcome().is_undecided())
+        .count();
+    assert! { undecided > 0 }
+}
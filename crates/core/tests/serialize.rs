@@ -0,0 +1,103 @@
+/*
+    Appellation: serialize <tests>
+    Created At: 2026.10.19:15:08:27
+    Contrib: @FL03
+*/
+use rstm_core::programs::ProgramFormat;
+use rstm_core::{Direction, Error, Program, Rule};
+
+fn sample() -> Program<isize, usize> {
+    Program::from_rules(vec![
+        Rule::from_parts(0, 0, Direction::Right, 1, 1),
+        Rule::from_parts(0, 1, Direction::Left, -1, 1),
+        Rule::from_parts(1, 0, Direction::Stay, 0, 1),
+        Rule::from_parts(1, 1, Direction::Right, 0, 0),
+    ])
+    .with_default_state(0)
+}
+
+#[test]
+fn test_format_detection() {
+    assert_eq! { ProgramFormat::from_path("program.json"), Some(ProgramFormat::Json) }
+    assert_eq! { ProgramFormat::from_path("program.yml"), Some(ProgramFormat::Yaml) }
+    assert_eq! { ProgramFormat::from_path("program.YAML"), Some(ProgramFormat::Yaml) }
+    assert_eq! { ProgramFormat::from_path("dir/program.toml"), Some(ProgramFormat::Toml) }
+    assert_eq! { ProgramFormat::from_path("program.ron"), Some(ProgramFormat::Ron) }
+    assert_eq! { ProgramFormat::from_path("program.txt"), None }
+    assert_eq! { ProgramFormat::from_path("program"), None }
+}
+
+#[test]
+fn test_serialize_roundtrip() {
+    let program = sample();
+    for format in [
+        ProgramFormat::Json,
+        ProgramFormat::Yaml,
+        ProgramFormat::Toml,
+        ProgramFormat::Ron,
+    ] {
+        assert! { format.is_enabled() }
+        let serialized = program.serialize_as(format).unwrap();
+        let parsed = Program::<isize, usize>::deserialize_as(&serialized, format).unwrap();
+        assert_eq! { parsed, program, "failed to round-trip the program as {format}" }
+    }
+}
+
+#[test]
+fn test_save_and_load() {
+    let program = sample();
+    let dir = std::env::temp_dir().join(format!("rstm-serialize-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    for ext in ["json", "yaml", "yml", "toml", "ron"] {
+        let path = dir.join(format!("program.{ext}"));
+        program.save(&path).unwrap();
+        assert_eq! { Program::<isize, usize>::load(&path).unwrap(), program }
+    }
+    // an explicit format takes precedence over the extension
+    let path = dir.join("program.txt");
+    program.save_as(&path, ProgramFormat::Ron).unwrap();
+    assert_eq! {
+        Program::<isize, usize>::load_as(&path, ProgramFormat::Ron).unwrap(),
+        program
+    }
+    assert! { matches!(Program::<isize, usize>::load(&path), Err(Error::UnknownFormat)) }
+    // exporting as JSON no longer depends upon the extension of the path
+    let path = dir.join("program.rstm");
+    program.export_json(&path).unwrap();
+    assert_eq! {
+        Program::<isize, usize>::load_as(&path, ProgramFormat::Json).unwrap(),
+        program
+    }
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_handwritten_yaml() {
+    let input = r#"
+# a simple two-state machine
+initial_state: 0
+rules:
+  - { state: 0, symbol: 0, direction: right, next_state: 1, write_symbol: 1 }
+  - { state: 0, symbol: 1, direction: l, next_state: -1, write_symbol: 1 }
+  # stay put for a single step
+  - { state: 1, symbol: 0, direction: stay, next_state: 0, write_symbol: 1 }
+  - { state: 1, symbol: 1, direction: R, next_state: 0, write_symbol: 0 }
+"#;
+    let program = Program::<isize, usize>::deserialize_as(input, ProgramFormat::Yaml).unwrap();
+    assert_eq! { program, sample() }
+}
+
+#[test]
+fn test_interner_roundtrip() {
+    use rstm_core::programs::Interner;
+
+    let (interned, interner) = sample().intern().unwrap();
+    let json = serde_json::to_string(&interner).unwrap();
+    assert_eq! { json, r#"{"states":[0,1,-1],"halting":[2],"symbols":[0,1]}"# }
+    let restored = serde_json::from_str::<Interner<isize, usize>>(&json).unwrap();
+    assert_eq! { restored.resolve_program(&interned), Some(sample()) }
+    assert_eq! { restored.state_id(&-1), interner.state_id(&-1) }
+    // tables containing duplicates are rejected
+    let json = r#"{"states":[0,0],"halting":[],"symbols":[0]}"#;
+    assert! { serde_json::from_str::<Interner<isize, usize>>(json).is_err() }
+}
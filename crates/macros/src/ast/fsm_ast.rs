@@ -1,77 +1,116 @@
 /*
-    appellation: ops <module>
+    appellation: fsm_ast <module>
     authors: @FL03
 */
 use super::RuleAst;
+use crate::keywords;
 use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
-use syn::token::Impl;
-use syn::{AngleBracketedGenericArguments, Ident, Token, WhereClause, braced};
+use syn::{Attribute, Expr, Ident, Token, Type, Visibility, braced, bracketed};
 
-fn _parse_ops(input: ParseStream) -> syn::Result<Punctuated<RuleAst, Token![,]>> {
-    // parse the operations defined within braces
-    let content;
-    let _ = braced! { content in input };
-    Punctuated::parse_terminated(&content)
-}
-
-#[allow(dead_code)]
 /// The abstract syntax tree for the `fsm!` procedural macro
+///
+/// ```ignore
+/// #[attrs]
+/// pub struct Name {
+///     symbols: Type = [symbol, ...],
+///     states: [State, ...],
+///     halt: [State, ...],   // optional
+///     initial: State,       // optional; defaults to the first state
+///     rules: { (state, symbol) -> Direction(next_state, write_symbol), ... }
+/// }
+/// ```
 pub struct FiniteStateMachineAst {
-    pub impl_token: Impl,
-    pub generics: Option<AngleBracketedGenericArguments>,
-    pub target: Ident,
-    pub field: Option<Ident>,
-    pub where_clause: Option<WhereClause>,
-    pub ops: Punctuated<RuleAst, Token![,]>,
+    pub attrs: Vec<Attribute>,
+    pub vis: Visibility,
+    pub name: Ident,
+    pub symbol_ty: Type,
+    pub symbols: Punctuated<Expr, Token![,]>,
+    pub states: Punctuated<Ident, Token![,]>,
+    pub halt: Punctuated<Ident, Token![,]>,
+    pub initial: Option<Ident>,
+    pub rules: Punctuated<RuleAst, Token![,]>,
 }
 
 /*
  ************* Implementations *************
 */
 
+/// parses a bracketed, comma-separated list of items: `[item, ...]`
+fn parse_list<T: Parse>(input: ParseStream) -> syn::Result<Punctuated<T, Token![,]>> {
+    let content;
+    let _ = bracketed! { content in input };
+    Punctuated::parse_terminated(&content)
+}
+
+/// parses the (optional) trailing comma of a section
+fn parse_separator(input: ParseStream) -> syn::Result<()> {
+    if input.peek(Token![,]) {
+        input.parse::<Token![,]>()?;
+    }
+    Ok(())
+}
+
 impl Parse for FiniteStateMachineAst {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        // parse the `impl` keyword
-        let impl_token = input.parse::<Impl>()?;
-        // detect any generic parameters
-        let generics = if input.peek(Token![<]) {
-            input.parse().ok()
-        } else {
-            None
-        };
-        let target = input.parse::<Ident>()?;
-        // resolve the optional named field
-        let field = if input.peek(Token![.]) {
-            input.parse::<Token![.]>()?;
-            Some(input.parse()?)
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse::<Visibility>()?;
+        input.parse::<Token![struct]>()?;
+        let name = input.parse::<Ident>()?;
+        let content;
+        let _ = braced! { content in input };
+        // symbols: Type = [symbol, ...]
+        content.parse::<keywords::symbols>()?;
+        content.parse::<Token![:]>()?;
+        let symbol_ty = content.parse::<Type>()?;
+        content.parse::<Token![=]>()?;
+        let symbols = parse_list(&content)?;
+        parse_separator(&content)?;
+        // states: [State, ...]
+        content.parse::<keywords::states>()?;
+        content.parse::<Token![:]>()?;
+        let states = parse_list(&content)?;
+        parse_separator(&content)?;
+        // halt: [State, ...]
+        let halt = if content.peek(keywords::halt) {
+            content.parse::<keywords::halt>()?;
+            content.parse::<Token![:]>()?;
+            let halt = parse_list(&content)?;
+            parse_separator(&content)?;
+            halt
         } else {
-            None
+            Punctuated::new()
         };
-        // parse the optional where clause
-        let where_clause = if input.peek(Token![where]) {
-            Some(input.parse()?)
+        // initial: State
+        let initial = if content.peek(keywords::initial) {
+            content.parse::<keywords::initial>()?;
+            content.parse::<Token![:]>()?;
+            let initial = content.parse::<Ident>()?;
+            parse_separator(&content)?;
+            Some(initial)
         } else {
             None
         };
-        // parse the operations block
-        let content;
-        let _ = braced! { content in input };
-        let mut ops = Punctuated::new();
-        while !content.is_empty() {
-            ops.push(content.parse::<RuleAst>()?);
-            if content.peek(Token![,]) {
-                content.parse::<Token![,]>()?;
-            }
+        // rules: { rule, ... }
+        content.parse::<keywords::rules>()?;
+        content.parse::<Token![:]>()?;
+        let block;
+        let _ = braced! { block in content };
+        let rules = Punctuated::parse_terminated(&block)?;
+        parse_separator(&content)?;
+        if !content.is_empty() {
+            return Err(content.error("unexpected tokens following the rules"));
         }
-
         Ok(Self {
-            impl_token,
-            generics,
-            target,
-            field,
-            where_clause,
-            ops,
+            attrs,
+            vis,
+            name,
+            symbol_ty,
+            symbols,
+            states,
+            halt,
+            initial,
+            rules,
         })
     }
 }
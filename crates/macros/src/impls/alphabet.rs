@@ -0,0 +1,122 @@
+/*
+    appellation: alphabet <module>
+    authors: @FL03
+*/
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields};
+
+/// implements the `Alphabet` and `FiniteSymbol` traits for the given fieldless enum, along
+/// with `Default` and `Display` whenever they are requested using `#[alphabet(..)]`
+pub fn impl_alphabet(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "`Alphabet` can only be derived for fieldless enums",
+        ));
+    };
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "`Alphabet` cannot be derived for generic enums",
+        ));
+    }
+    if data.variants.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "an alphabet must contain at least one symbol",
+        ));
+    }
+    let mut blank = None;
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new_spanned(
+                &variant.fields,
+                "`Alphabet` can only be derived for fieldless enums",
+            ));
+        }
+        for attr in variant.attrs.iter().filter(|a| a.path().is_ident("blank")) {
+            attr.meta.require_path_only()?;
+            if blank.replace(&variant.ident).is_some() {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "only a single variant may be marked as `#[blank]`",
+                ));
+            }
+        }
+    }
+    // the implementations of `Default` and `Display` are opt-in to avoid conflicting with
+    // those provided by the user
+    let (mut default, mut display) = (false, false);
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("alphabet")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("default") {
+                default = true;
+            } else if meta.path.is_ident("display") {
+                display = true;
+            } else {
+                return Err(meta.error("expected `default` or `display`"));
+            }
+            Ok(())
+        })?;
+    }
+    let name = &input.ident;
+    // default to the first variant whenever no blank has been specified
+    let blank = blank.unwrap_or(&data.variants[0].ident);
+    let variants = data.variants.iter().map(|v| &v.ident).collect::<Vec<_>>();
+    let names = variants.iter().map(|v| v.to_string());
+    let indices = 0..variants.len();
+    let count = variants.len();
+    let default = default.then(|| {
+        quote! {
+            impl ::core::default::Default for #name {
+                fn default() -> Self {
+                    Self::#blank
+                }
+            }
+        }
+    });
+    let display = display.then(|| {
+        quote! {
+            impl ::core::fmt::Display for #name {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    match self {
+                        #(Self::#variants => f.write_str(#names)),*
+                    }
+                }
+            }
+        }
+    });
+    Ok(quote! {
+        impl rstm::traits::FiniteSymbol for #name {
+            const COUNT: usize = #count;
+            const SYMBOLS: &'static [Self] = &[#(Self::#variants),*];
+
+            fn blank() -> Self {
+                Self::#blank
+            }
+
+            fn index(&self) -> usize {
+                match self {
+                    #(Self::#variants => #indices),*
+                }
+            }
+        }
+
+        impl rstm::traits::Alphabet for #name {
+            type Elem = Self;
+
+            fn as_slice(&self) -> &[Self] {
+                <Self as rstm::traits::FiniteSymbol>::SYMBOLS
+            }
+
+            fn len(&self) -> usize {
+                #count
+            }
+        }
+
+        #default
+
+        #display
+    })
+}
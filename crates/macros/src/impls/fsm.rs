@@ -1,52 +1,314 @@
 /*
-    appellation: impl_binary <module>
+    appellation: fsm <module>
     authors: @FL03
 */
 use crate::ast::{FiniteStateMachineAst, HeadAst, RuleAst, TailAst};
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{ToTokens, format_ident, quote};
+use std::collections::BTreeMap;
+use syn::spanned::Spanned;
+use syn::{Expr, Ident};
 
 /// Procedural macro entry point
-pub fn impl_wrapper_binary_ops(input: FiniteStateMachineAst) -> TokenStream {
-    let rules = generate_rules(&input);
+pub fn impl_fsm(input: &FiniteStateMachineAst) -> TokenStream {
+    match validate(input) {
+        Ok(()) => generate(input),
+        Err(err) => err.to_compile_error(),
+    }
+}
 
-    quote! {
-        #(#rules)*
+/// returns a key used to compare symbols by their tokens
+fn symbol_key(symbol: &Expr) -> String {
+    symbol.to_token_stream().to_string()
+}
+
+/// returns the identifier of a state used within a rule
+fn state_ident(state: &Expr) -> syn::Result<&Ident> {
+    match state {
+        Expr::Path(path) if path.qself.is_none() => path
+            .path
+            .get_ident()
+            .ok_or_else(|| syn::Error::new(state.span(), "expected the name of a state")),
+        _ => Err(syn::Error::new(
+            state.span(),
+            "expected the name of a state",
+        )),
     }
 }
 
-fn generate_rules(FiniteStateMachineAst { ops, .. }: &FiniteStateMachineAst) -> Vec<TokenStream> {
-    let mut impls = Vec::new();
-    for rule in ops {
-        let _impl = handle_rule(rule);
-        impls.push(_impl);
+/// pushes the error onto the accumulated errors
+fn push_error(errors: &mut Option<syn::Error>, err: syn::Error) {
+    match errors {
+        Some(errors) => errors.combine(err),
+        None => *errors = Some(err),
     }
-    impls
 }
 
-fn handle_rule(
-    RuleAst {
-        head: HeadAst { state, symbol, .. },
-        tail:
-            TailAst {
-                direction,
-                next_state,
-                next_symbol,
-                ..
-            },
+/// ensures the machine is well-formed, i.e. every state and symbol is declared, and each
+/// non-halting state defines exactly one transition for every symbol
+fn validate(
+    FiniteStateMachineAst {
+        symbols,
+        states,
+        halt,
+        initial,
+        rules,
+        name,
         ..
-    }: &RuleAst,
+    }: &FiniteStateMachineAst,
+) -> syn::Result<()> {
+    let mut errors = None;
+    if states.is_empty() {
+        push_error(
+            &mut errors,
+            syn::Error::new(name.span(), "the machine must declare at least one state"),
+        );
+    }
+    // ensure the states and symbols are unique
+    let mut declared = BTreeMap::new();
+    for state in states.iter().chain(halt) {
+        if declared
+            .insert(state.to_string(), halt.iter().any(|h| h == state))
+            .is_some()
+        {
+            push_error(
+                &mut errors,
+                syn::Error::new(
+                    state.span(),
+                    format!("the state `{state}` is already declared"),
+                ),
+            );
+        }
+    }
+    let mut alphabet = Vec::new();
+    for symbol in symbols {
+        let key = symbol_key(symbol);
+        if alphabet.contains(&key) {
+            push_error(
+                &mut errors,
+                syn::Error::new(
+                    symbol.span(),
+                    format!("the symbol `{key}` is already declared"),
+                ),
+            );
+        } else {
+            alphabet.push(key);
+        }
+    }
+    if let Some(initial) = initial
+        && !declared.contains_key(&initial.to_string())
+    {
+        push_error(
+            &mut errors,
+            syn::Error::new(initial.span(), format!("undeclared state `{initial}`")),
+        );
+    }
+    // validate each rule, recording the head it defines
+    let mut defined = BTreeMap::<(String, String), &RuleAst>::new();
+    for rule in rules {
+        let RuleAst {
+            head: HeadAst { state, symbol, .. },
+            tail:
+                TailAst {
+                    direction,
+                    next_state,
+                    next_symbol,
+                    ..
+                },
+            ..
+        } = rule;
+        let mut check_state = |expr: &Expr, head: bool| match state_ident(expr) {
+            Ok(ident) => match declared.get(&ident.to_string()) {
+                Some(true) if head => push_error(
+                    &mut errors,
+                    syn::Error::new(
+                        ident.span(),
+                        format!("the halting state `{ident}` cannot define any transitions"),
+                    ),
+                ),
+                Some(_) => {}
+                None => push_error(
+                    &mut errors,
+                    syn::Error::new(ident.span(), format!("undeclared state `{ident}`")),
+                ),
+            },
+            Err(err) => push_error(&mut errors, err),
+        };
+        check_state(state, true);
+        check_state(next_state, false);
+        for sym in [symbol, next_symbol] {
+            if !alphabet.contains(&symbol_key(sym)) {
+                push_error(
+                    &mut errors,
+                    syn::Error::new(
+                        sym.span(),
+                        format!("undeclared symbol `{}`", symbol_key(sym)),
+                    ),
+                );
+            }
+        }
+        if !matches!(direction.to_string().as_str(), "Left" | "Right" | "Stay") {
+            push_error(
+                &mut errors,
+                syn::Error::new(
+                    direction.span(),
+                    "expected a direction (`Left`, `Right`, or `Stay`)",
+                ),
+            );
+        }
+        let key = (state.to_token_stream().to_string(), symbol_key(symbol));
+        if let Some(prev) = defined.get(&key) {
+            let mut err = syn::Error::new(
+                rule.head.group.span.join(),
+                format!("duplicate transition for ({}, {})", key.0, key.1),
+            );
+            err.combine(syn::Error::new(
+                prev.head.group.span.join(),
+                "the transition is first defined here",
+            ));
+            push_error(&mut errors, err);
+        } else {
+            defined.insert(key, rule);
+        }
+    }
+    // ensure every non-halting state is defined for each symbol
+    for state in states {
+        for symbol in symbols {
+            let key = (state.to_string(), symbol_key(symbol));
+            if !defined.contains_key(&key) {
+                push_error(
+                    &mut errors,
+                    syn::Error::new(
+                        state.span(),
+                        format!("missing transition for ({}, {})", key.0, key.1),
+                    ),
+                );
+            }
+        }
+    }
+    match errors {
+        Some(err) => Err(err),
+        None => Ok(()),
+    }
+}
+
+/// generates the state enum and the machine itself
+fn generate(
+    FiniteStateMachineAst {
+        attrs,
+        vis,
+        name,
+        symbol_ty,
+        symbols,
+        states,
+        halt,
+        initial,
+        rules,
+    }: &FiniteStateMachineAst,
 ) -> TokenStream {
+    let state_ty = format_ident!("{name}State");
+    let initial = initial.as_ref().unwrap_or(&states[0]);
+    let variants = states.iter().chain(halt).collect::<Vec<_>>();
+    let n_states = variants.len();
+    let n_symbols = symbols.len();
+    // the initial state may also be a halting state, requiring both markers
+    let defs = variants.iter().map(|v| {
+        let default = (*v == initial).then(|| quote! { #[default] });
+        let halting = halt.iter().any(|h| h == *v).then(|| quote! { #[halt] });
+        quote! { #default #halting #v }
+    });
+    let names = variants.iter().map(|v| v.to_string());
+    let rules = rules.iter().map(|rule| {
+        let RuleAst {
+            head: HeadAst { state, symbol, .. },
+            tail:
+                TailAst {
+                    direction,
+                    next_state,
+                    next_symbol,
+                    ..
+                },
+            ..
+        } = rule;
+        quote! {
+            rstm::Rule::from_parts(
+                #state_ty::#state,
+                #symbol,
+                rstm::Direction::#direction,
+                #state_ty::#next_state,
+                #next_symbol,
+            )
+        }
+    });
+    let state_doc = format!("the states of the [`{name}`] machine");
     quote! {
-        rstm::Rule {
-            head: rstm::Head {
-                state: rstm::State(#state),
-                symbol: #symbol,
-            },
-            tail: rstm::Tail {
-                new_state: rstm::State(#next_state),
-                new_symbol: #next_symbol,
-                direction: rstm::Direction::#direction,
+        #[doc = #state_doc]
+        #[derive(
+            Clone,
+            Copy,
+            Debug,
+            Default,
+            Eq,
+            Hash,
+            Ord,
+            PartialEq,
+            PartialOrd,
+            rstm::Halting,
+            rstm::RawState,
+        )]
+        #vis enum #state_ty {
+            #(#defs),*
+        }
+
+        impl #state_ty {
+            /// every state of the machine, in order of declaration
+            pub const VARIANTS: [Self; #n_states] = [#(Self::#variants),*];
+        }
+
+        impl ::core::fmt::Display for #state_ty {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self {
+                    #(Self::#variants => f.write_str(#names)),*
+                }
+            }
+        }
+
+        #(#attrs)*
+        #[derive(Clone, Debug, PartialEq)]
+        #vis struct #name {
+            program: rstm::Program<#state_ty, #symbol_ty>,
+        }
+
+        impl #name {
+            /// the symbols of the machine's alphabet
+            pub const SYMBOLS: [#symbol_ty; #n_symbols] = [#symbols];
+            /// the initial state of the machine
+            pub const INITIAL_STATE: #state_ty = #state_ty::#initial;
+            /// returns a new instance of the machine
+            pub fn new() -> Self {
+                let program = rstm::Program::from_iter([#(#rules),*])
+                    .with_default_state(Self::INITIAL_STATE);
+                Self { program }
+            }
+            /// returns a reference to the program of the machine
+            pub const fn program(&self) -> &rstm::Program<#state_ty, #symbol_ty> {
+                &self.program
+            }
+            /// consumes the machine, returning its program
+            pub fn into_program(self) -> rstm::Program<#state_ty, #symbol_ty> {
+                self.program
+            }
+        }
+
+        impl ::core::default::Default for #name {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl ::core::convert::From<#name> for rstm::Program<#state_ty, #symbol_ty> {
+            fn from(machine: #name) -> Self {
+                machine.into_program()
             }
         }
     }
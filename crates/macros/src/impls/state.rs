@@ -0,0 +1,94 @@
+/*
+    appellation: state <module>
+    authors: @FL03
+*/
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, Variant};
+
+/// implements the `RawState` trait for the given type
+pub fn impl_raw_state(input: &DeriveInput) -> TokenStream {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    quote! {
+        impl #impl_generics rstm::RawState for #name #ty_generics #where_clause {
+            fn __private__(&self) -> rstm::state::__private::Seal {
+                rstm::state::__private::Seal
+            }
+        }
+    }
+}
+
+/// implements the `Halting` trait for the given enum, where every variant marked with
+/// `#[halt]`, `#[accept]` or `#[reject]` is considered to be halted.
+pub fn impl_halting(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "`Halting` can only be derived for enums",
+        ));
+    };
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let mut halted = Vec::new();
+    let mut accepting = Vec::new();
+    let mut rejecting = Vec::new();
+    for variant in &data.variants {
+        let mut marked = false;
+        for attr in &variant.attrs {
+            let target = if attr.path().is_ident("halt") {
+                &mut halted
+            } else if attr.path().is_ident("accept") {
+                &mut accepting
+            } else if attr.path().is_ident("reject") {
+                &mut rejecting
+            } else {
+                continue;
+            };
+            attr.meta.require_path_only()?;
+            if marked {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "a variant may only be marked as one of `halt`, `accept` or `reject`",
+                ));
+            }
+            marked = true;
+            target.push(pattern(variant));
+        }
+    }
+    let matches = |patterns: &[TokenStream]| {
+        if patterns.is_empty() {
+            quote! { false }
+        } else {
+            quote! { matches!(self, #(#patterns)|*) }
+        }
+    };
+    let is_halted = matches(&[&halted[..], &accepting[..], &rejecting[..]].concat());
+    let is_accepting = matches(&accepting);
+    let is_rejecting = matches(&rejecting);
+    Ok(quote! {
+        impl #impl_generics rstm::Halting for #name #ty_generics #where_clause {
+            fn is_halted(&self) -> bool {
+                #is_halted
+            }
+
+            fn is_accepting(&self) -> bool {
+                #is_accepting
+            }
+
+            fn is_rejecting(&self) -> bool {
+                #is_rejecting
+            }
+        }
+    })
+}
+
+/// returns a pattern matching the given variant regardless of its fields
+fn pattern(variant: &Variant) -> TokenStream {
+    let ident = &variant.ident;
+    match variant.fields {
+        Fields::Named(_) => quote! { Self::#ident { .. } },
+        Fields::Unnamed(_) => quote! { Self::#ident(..) },
+        Fields::Unit => quote! { Self::#ident },
+    }
+}
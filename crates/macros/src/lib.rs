@@ -14,10 +14,17 @@ mod ast {
 }
 
 mod impls {
-    pub use self::{fsm::impl_wrapper_binary_ops, rule::impl_rule};
+    pub use self::{
+        alphabet::impl_alphabet,
+        fsm::impl_fsm,
+        rule::impl_rule,
+        state::{impl_halting, impl_raw_state},
+    };
 
+    pub mod alphabet;
     pub mod fsm;
     pub mod rule;
+    pub mod state;
 }
 
 pub(crate) mod keywords {
@@ -29,11 +36,17 @@ pub(crate) mod keywords {
     syn::custom_keyword! { tail }
 
     syn::custom_keyword! { rule }
+
+    syn::custom_keyword! { halt }
+    syn::custom_keyword! { initial }
+    syn::custom_keyword! { rules }
+    syn::custom_keyword! { states }
+    syn::custom_keyword! { symbols }
 }
 
 use crate::ast::{FiniteStateMachineAst, RuleAst};
 use proc_macro::TokenStream;
-use syn::parse_macro_input;
+use syn::{DeriveInput, parse_macro_input};
 
 /// The [`ruler!`] generates a finite state machine implementation
 ///
@@ -46,14 +59,104 @@ pub fn ruler(input: TokenStream) -> TokenStream {
     let output = impls::impl_rule(&ast);
     output.into()
 }
-/// The [`fsm!`] generates a finite state machine implementation
+/// The [`fsm!`] macro defines a named machine holding a [`Program`] alongside an enum of its
+/// states, named after the machine (e.g. `BusyBeaverState`), which implements both
+/// `RawState` and `Halting`.
 ///
-/// ```rust
+/// ```ignore
+/// rstm::fsm! {
+///     /// the two-state, two-symbol busy beaver
+///     pub struct BusyBeaver {
+///         symbols: usize = [0, 1],
+///         states: [A, B],
+///         halt: [H],
+///         initial: A,
+///         rules: {
+///             (A, 0) -> Right(B, 1),
+///             (A, 1) -> Left(B, 1),
+///             (B, 0) -> Left(A, 1),
+///             (B, 1) -> Right(H, 1),
+///         }
+///     }
+/// }
 ///
+/// let program = BusyBeaver::new().into_program();
 /// ```
+///
+/// The `halt` and `initial` sections are optional, with the first state being used as the
+/// initial state whenever it is omitted. The machine is checked at compile time, producing
+/// an error for any undeclared state or symbol, duplicate transition, or missing transition
+/// for a non-halting state.
+///
+/// [`Program`]: https://docs.rs/rstm/latest/rstm/programs/type.Program.html
 #[proc_macro]
 pub fn fsm(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as FiniteStateMachineAst);
-    let output = impls::impl_wrapper_binary_ops(ast);
+    let output = impls::impl_fsm(&ast);
+    output.into()
+}
+/// The [`RawState`] derive macro implements the sealed `RawState` trait for the given type,
+/// enabling it to be used as the state of a machine.
+///
+/// ```ignore
+/// #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, rstm::RawState)]
+/// pub enum Parity {
+///     #[default]
+///     Even,
+///     Odd,
+/// }
+/// ```
+#[proc_macro_derive(RawState)]
+pub fn raw_state(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    let output = impls::impl_raw_state(&ast);
     output.into()
 }
+/// The [`Halting`] derive macro implements the `Halting` trait for enums, where variants may
+/// be marked with `#[halt]`, `#[accept]` or `#[reject]`. Accepting and rejecting variants are
+/// also considered to be halted.
+///
+/// ```ignore
+/// #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, rstm::RawState, rstm::Halting)]
+/// pub enum Parity {
+///     #[default]
+///     Even,
+///     Odd,
+///     #[accept]
+///     Accept,
+///     #[reject]
+///     Reject,
+/// }
+/// ```
+#[proc_macro_derive(Halting, attributes(accept, halt, reject))]
+pub fn halting(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    impls::impl_halting(&ast)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+/// The [`Alphabet`] derive macro declares a fieldless enum as a finite set of symbols,
+/// implementing both the `Alphabet` and `FiniteSymbol` traits. The variant marked with
+/// `#[blank]` (or the first variant, if none are marked) is used as the blank symbol.
+///
+/// Since `FiniteSymbol` requires the `Symbolic` trait, the enum must also implement `Clone`,
+/// `Debug`, `Default`, `Display`, `Eq`, `Hash` and `PartialOrd`. Should the enum not provide
+/// its own, `#[alphabet(default)]` implements `Default` by returning the blank symbol while
+/// `#[alphabet(display)]` implements `Display` by writing the name of each variant.
+///
+/// ```ignore
+/// #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, rstm::Alphabet)]
+/// #[alphabet(default, display)]
+/// pub enum Binary {
+///     #[blank]
+///     Zero,
+///     One,
+/// }
+/// ```
+#[proc_macro_derive(Alphabet, attributes(alphabet, blank))]
+pub fn alphabet(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    impls::impl_alphabet(&ast)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -17,3 +17,165 @@ fn test_ruler_macro() {
     assert_eq! { rule.head(), &head }
     assert_eq! { rule.tail(), &exp }
 }
+
+rstm::fsm! {
+    /// the two-state, two-symbol busy beaver
+    pub struct BusyBeaver {
+        symbols: usize = [0, 1],
+        states: [A, B],
+        halt: [H],
+        initial: A,
+        rules: {
+            (A, 0) -> Right(B, 1),
+            (A, 1) -> Left(B, 1),
+            (B, 0) -> Left(A, 1),
+            (B, 1) -> Right(H, 1),
+        }
+    }
+}
+
+rstm::fsm! {
+    /// a machine whose initial state is also its halting state
+    pub struct Halted {
+        symbols: usize = [0],
+        states: [A],
+        halt: [H],
+        initial: H,
+        rules: {
+            (A, 0) -> Stay(H, 0),
+        }
+    }
+}
+
+#[test]
+fn test_fsm_macro_halting_initial_state() {
+    use rstm::Halting;
+
+    assert_eq! { HaltedState::default(), HaltedState::H }
+    assert! { Halted::INITIAL_STATE.is_halted() }
+    assert! { !HaltedState::A.is_halted() }
+}
+
+#[test]
+fn test_fsm_macro() {
+    use rstm::Halting;
+    use rstm::actors::Configuration;
+
+    assert_eq! { BusyBeaver::INITIAL_STATE, BusyBeaverState::A }
+    assert_eq! { BusyBeaverState::default(), BusyBeaverState::A }
+    assert_eq! { BusyBeaver::SYMBOLS, [0, 1] }
+    assert_eq! { BusyBeaverState::VARIANTS.len(), 3 }
+    assert! { BusyBeaverState::H.is_halted() && !BusyBeaverState::B.is_halted() }
+    assert_eq! { BusyBeaverState::B.to_string(), "B" }
+
+    let program = BusyBeaver::new().into_program();
+    assert_eq! { program.len(), 4 }
+    assert_eq! { program.initial_state().map(|s| *s.get()), Some(BusyBeaverState::A) }
+    // run the machine until it halts
+    let mut config = Configuration::<BusyBeaverState, usize>::new(BusyBeaverState::A);
+    while !config.is_halted() {
+        config.step(&program).expect("the machine should halt");
+    }
+    assert_eq! { config.steps(), 6 }
+    assert_eq! { config.sigma(), 4 }
+}
+
+#[derive(
+    Clone, Copy, Debug, Default, Eq, Hash, PartialEq, PartialOrd, rstm::Halting, rstm::RawState,
+)]
+pub enum Parity {
+    #[default]
+    Even,
+    Odd,
+    #[halt]
+    Done(u8),
+    #[accept]
+    Accept,
+    #[reject]
+    Reject {
+        reason: u8,
+    },
+}
+
+#[test]
+fn test_derive_halting() {
+    use rstm::{Halting, State};
+
+    assert! { !Parity::Even.is_halted() && !Parity::Odd.is_halted() }
+    assert! { Parity::Done(0).is_halted() }
+    assert! { !Parity::Done(0).is_accepting() && !Parity::Done(0).is_rejecting() }
+    assert! { Parity::Accept.is_halted() && Parity::Accept.is_accepting() }
+    assert! { Parity::Reject { reason: 1 }.is_halted() && Parity::Reject { reason: 1 }.is_rejecting() }
+    // the derived state may be used within a program and wrapped in a state
+    assert! { State(Parity::Accept).is_accepting() }
+    let program = rstm::program! {
+        #[default_state(Parity::Even)]
+        rules: {
+            (Parity::Even, 0) -> Right(Parity::Even, 0),
+            (Parity::Even, 1) -> Right(Parity::Odd, 0),
+            (Parity::Odd, 0) -> Right(Parity::Odd, 0),
+            (Parity::Odd, 1) -> Right(Parity::Even, 0),
+        };
+    };
+    assert_eq! { program.len(), 4 }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, rstm::Alphabet)]
+#[alphabet(default, display)]
+pub enum Ternary {
+    Zero,
+    One,
+    #[blank]
+    Blank,
+}
+
+/// an alphabet providing its own implementations of `Default` and `Display`
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, rstm::Alphabet)]
+pub enum Bit {
+    #[default]
+    #[blank]
+    Zero,
+    One,
+}
+
+impl core::fmt::Display for Bit {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::Zero => "0",
+            Self::One => "1",
+        })
+    }
+}
+
+#[test]
+fn test_derive_alphabet_with_user_impls() {
+    use rstm::traits::FiniteSymbol;
+
+    assert_eq! { Bit::COUNT, 2 }
+    assert_eq! { Bit::blank(), Bit::default() }
+    assert_eq! { Bit::One.to_string(), "1" }
+}
+
+#[test]
+fn test_derive_alphabet() {
+    use rstm::traits::{Alphabet, FiniteSymbol, Symbolic};
+
+    fn is_symbolic<S: Symbolic>() {}
+    is_symbolic::<Ternary>();
+
+    assert_eq! { Ternary::COUNT, 3 }
+    assert_eq! { Ternary::SYMBOLS, &[Ternary::Zero, Ternary::One, Ternary::Blank] }
+    assert_eq! { Ternary::blank(), Ternary::Blank }
+    assert_eq! { Ternary::default(), Ternary::Blank }
+    assert! { Ternary::Blank.is_blank() && !Ternary::Zero.is_blank() }
+    // the index conversion is dense and invertible
+    for (i, symbol) in Ternary::SYMBOLS.iter().enumerate() {
+        assert_eq! { symbol.index(), i }
+        assert_eq! { Ternary::from_index(i), Some(*symbol) }
+    }
+    assert_eq! { Ternary::from_index(3), None }
+    // each symbol exposes the entire alphabet
+    assert_eq! { Ternary::One.len(), 3 }
+    assert_eq! { Ternary::One.as_slice(), Ternary::SYMBOLS }
+    assert_eq! { Ternary::One.to_string(), "One" }
+}
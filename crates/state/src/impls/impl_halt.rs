@@ -2,7 +2,7 @@
     Appellation: wrap <module>
     Contrib: FL03 <jo3mccain@icloud.com>
 */
-use crate::{Halt, RawState, State};
+use crate::{Halt, HaltKind, RawState, State};
 
 impl<Q, H> RawState for Halt<Q, H>
 where
@@ -25,11 +25,30 @@ where
     pub const fn from_state(state: Q) -> Self {
         Self::Step(state)
     }
+    /// Creates a new instance of a [Halt] with an accepting state.
+    pub const fn from_accept(state: H) -> Self {
+        Self::Accept(state)
+    }
+    /// Creates a new instance of a [Halt] with a rejecting state.
+    pub const fn from_reject(state: H) -> Self {
+        Self::Reject(state)
+    }
+    /// returns the kind of halt represented by the state, if it is halted
+    pub const fn kind(&self) -> Option<HaltKind> {
+        match self {
+            Self::Step(_) => None,
+            Self::Halt(_) => Some(HaltKind::Halt),
+            Self::Accept(_) => Some(HaltKind::Accept),
+            Self::Reject(_) => Some(HaltKind::Reject),
+        }
+    }
     /// [`swap`](core::mem::swap) the inner value of the halt state with that of the given state.
     pub const fn swap(&mut self, other: &mut Halt<Q, H>) {
         match (self, other) {
             (Self::Step(a), Self::Step(b)) => core::mem::swap(a, b),
             (Self::Halt(a), Self::Halt(b)) => core::mem::swap(a, b),
+            (Self::Accept(a), Self::Accept(b)) => core::mem::swap(a, b),
+            (Self::Reject(a), Self::Reject(b)) => core::mem::swap(a, b),
             _ => {}
         }
     }
@@ -38,6 +57,8 @@ where
         match self {
             Self::Step(inner) => Halt::Step(inner),
             Self::Halt(inner) => Halt::Halt(inner),
+            Self::Accept(inner) => Halt::Accept(inner),
+            Self::Reject(inner) => Halt::Reject(inner),
         }
     }
     /// returns a new instance of the halt state containing a mutable reference to its inner
@@ -46,6 +67,8 @@ where
         match self {
             Self::Step(inner) => Halt::Step(inner),
             Self::Halt(inner) => Halt::Halt(inner),
+            Self::Accept(inner) => Halt::Accept(inner),
+            Self::Reject(inner) => Halt::Reject(inner),
         }
     }
     /// returns an owned version of the current haltable state
@@ -57,6 +80,8 @@ where
         match self {
             Self::Step(inner) => Halt::Step(inner.clone()),
             Self::Halt(inner) => Halt::Halt(inner.clone()),
+            Self::Accept(inner) => Halt::Accept(inner.clone()),
+            Self::Reject(inner) => Halt::Reject(inner.clone()),
         }
     }
 }
@@ -69,8 +94,9 @@ where
     /// consumes the current haltable state, returning the inner state.
     pub fn into_inner(self) -> Q {
         match self {
-            Self::Step(inner) => inner,
-            Self::Halt(inner) => inner,
+            Self::Step(inner) | Self::Halt(inner) | Self::Accept(inner) | Self::Reject(inner) => {
+                inner
+            }
         }
     }
     #[inline]
@@ -85,8 +111,9 @@ where
     /// consumes the current haltable state, returning the inner state.
     pub fn into_state(self) -> State<Q> {
         match self {
-            Self::Step(inner) => State(inner),
-            Self::Halt(inner) => State(inner),
+            Self::Step(inner) | Self::Halt(inner) | Self::Accept(inner) | Self::Reject(inner) => {
+                State(inner)
+            }
         }
     }
     #[inline]
@@ -114,15 +141,17 @@ where
     /// returns a reference to the internal state
     pub const fn get(&self) -> &Q {
         match self {
-            Self::Step(inner) => inner,
-            Self::Halt(inner) => inner,
+            Self::Step(inner) | Self::Halt(inner) | Self::Accept(inner) | Self::Reject(inner) => {
+                inner
+            }
         }
     }
     /// returns a mutable reference to the internal state
     pub const fn get_mut(&mut self) -> &mut Q {
         match self {
-            Self::Step(inner) => inner,
-            Self::Halt(inner) => inner,
+            Self::Step(inner) | Self::Halt(inner) | Self::Accept(inner) | Self::Reject(inner) => {
+                inner
+            }
         }
     }
     /// [`replace`](core::mem::replace) the inner value of the halt state with the given state.
@@ -151,6 +180,8 @@ where
         match self {
             Self::Step(inner) => Halt::Step(inner.clone()),
             Self::Halt(inner) => Halt::Halt(inner.clone()),
+            Self::Accept(inner) => Halt::Accept(inner.clone()),
+            Self::Reject(inner) => Halt::Reject(inner.clone()),
         }
     }
     #[inline]
@@ -166,6 +197,8 @@ where
         match self {
             Self::Step(&inner) => Halt::Step(inner),
             Self::Halt(&inner) => Halt::Halt(inner),
+            Self::Accept(&inner) => Halt::Accept(inner),
+            Self::Reject(&inner) => Halt::Reject(inner),
         }
     }
 }
@@ -186,6 +219,8 @@ where
         match self {
             Self::Step(inner) => Halt::Step(inner.clone()),
             Self::Halt(inner) => Halt::Halt(inner.clone()),
+            Self::Accept(inner) => Halt::Accept(inner.clone()),
+            Self::Reject(inner) => Halt::Reject(inner.clone()),
         }
     }
     #[inline]
@@ -199,6 +234,8 @@ where
         match self {
             Self::Step(&mut inner) => Halt::Step(inner),
             Self::Halt(&mut inner) => Halt::Halt(inner),
+            Self::Accept(&mut inner) => Halt::Accept(inner),
+            Self::Reject(&mut inner) => Halt::Reject(inner),
         }
     }
 }
@@ -64,6 +64,12 @@ mod types {
 // re-exports
 #[doc(inline)]
 pub use self::{error::*, state::*, traits::*, types::*};
+// private items used by the procedural macros; these are not considered part of the public
+// api and may change at any time.
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::macros::seal::Seal;
+}
 // prelude
 #[doc(hidden)]
 pub mod prelude {
@@ -16,8 +16,10 @@ use crate::RawState;
 #[repr(transparent)]
 pub struct State<Q: ?Sized = bool>(pub Q);
 
-/// The [`Halt`] implementation is a binary enum designed to represent either a halting state
-/// or a stepping state within a Turing machine or similar computational model.
+/// The [`Halt`] implementation is an enum designed to represent either a halting state or a
+/// stepping state within a Turing machine or similar computational model. Halting states may
+/// additionally be marked as _accepting_ or _rejecting_, as is common when recognizing
+/// languages.
 #[derive(
     Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, strum::EnumCount, strum::EnumIs,
 )]
@@ -30,6 +32,40 @@ where
 {
     Halt(H),
     Step(Q),
+    Accept(H),
+    Reject(H),
+}
+
+/// [`HaltKind`] enumerates the different ways in which a machine may halt
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    strum::AsRefStr,
+    strum::Display,
+    strum::EnumCount,
+    strum::EnumIs,
+    strum::EnumString,
+    strum::VariantNames,
+)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[strum(serialize_all = "snake_case")]
+pub enum HaltKind {
+    /// the machine halted without accepting or rejecting its input
+    Halt,
+    /// the machine halted, accepting its input
+    Accept,
+    /// the machine halted, rejecting its input
+    Reject,
 }
 
 #[cfg(test)]
@@ -2,8 +2,8 @@
     appellation: halted <module>
     authors: @FL03
 */
-use crate::RawState;
 use crate::state::State;
+use crate::{HaltKind, RawState};
 
 /// The [`Halting`] trait establishes an interface for determining whether a given state
 /// is in a halted condition. This trait is essential for Turing machine simulations,
@@ -11,6 +11,28 @@ use crate::state::State;
 pub trait Halting {
     /// returns true if the current state is considered to be _halted_, otherwise false.
     fn is_halted(&self) -> bool;
+    /// returns true if the current state is an _accepting_ halt; by default, no state is
+    /// considered to be accepting.
+    fn is_accepting(&self) -> bool {
+        false
+    }
+    /// returns true if the current state is a _rejecting_ halt; by default, no state is
+    /// considered to be rejecting.
+    fn is_rejecting(&self) -> bool {
+        false
+    }
+    /// returns the kind of halt represented by the current state, if it is halted
+    fn halt_kind(&self) -> Option<HaltKind> {
+        if self.is_accepting() {
+            Some(HaltKind::Accept)
+        } else if self.is_rejecting() {
+            Some(HaltKind::Reject)
+        } else if self.is_halted() {
+            Some(HaltKind::Halt)
+        } else {
+            None
+        }
+    }
 }
 
 /*
@@ -25,6 +47,14 @@ where
     fn is_halted(&self) -> bool {
         self.get().is_halted()
     }
+
+    fn is_accepting(&self) -> bool {
+        self.get().is_accepting()
+    }
+
+    fn is_rejecting(&self) -> bool {
+        self.get().is_rejecting()
+    }
 }
 
 impl<Q, H> Halting for Halt<Q, H>
@@ -33,7 +63,15 @@ where
     H: RawState,
 {
     fn is_halted(&self) -> bool {
-        matches!(self, &Halt::Halt(_))
+        !matches!(self, &Halt::Step(_))
+    }
+
+    fn is_accepting(&self) -> bool {
+        matches!(self, &Halt::Accept(_))
+    }
+
+    fn is_rejecting(&self) -> bool {
+        matches!(self, &Halt::Reject(_))
     }
 }
 
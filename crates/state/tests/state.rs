@@ -4,7 +4,7 @@
 */
 extern crate rstm_state as rstm;
 
-use rstm::{Halt, State};
+use rstm::{Halt, HaltKind, Halting, State};
 
 #[test]
 fn state() {
@@ -38,6 +38,21 @@ fn halt_state() {
     assert_eq! { state.into_halt(), Halt::Step(0) }
 }
 
+#[test]
+fn halt_kind() {
+    let accept = Halt::<usize, usize>::from_accept(1);
+    let reject = Halt::<usize, usize>::from_reject(2);
+    assert! { accept.is_halted() && accept.is_accepting() }
+    assert! { reject.is_halted() && reject.is_rejecting() }
+    assert_eq! { accept.kind(), Some(HaltKind::Accept) }
+    assert_eq! { reject.halt_kind(), Some(HaltKind::Reject) }
+    assert_eq! { Halt::<usize, usize>::Halt(0).kind(), Some(HaltKind::Halt) }
+    assert_eq! { Halt::<usize, usize>::Step(0).kind(), None }
+    // numeric states fall back onto their maximum to signal halting
+    assert_eq! { usize::MAX.halt_kind(), Some(HaltKind::Halt) }
+    assert_eq! { 0usize.halt_kind(), None }
+}
+
 #[test]
 fn cast_state() {
     // create a new instance of state with a value of 0_isize
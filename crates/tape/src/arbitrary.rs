@@ -0,0 +1,170 @@
+/*
+    Appellation: arbitrary <module>
+    Created At: 2026.10.19:00:31:14
+    Contrib: @FL03
+*/
+//! this module provides generators for property testing, implementing the `Arbitrary` traits
+//! of both [`proptest`] and [`quickcheck`] for the [`Direction`](crate::Direction) and [`DenseTape`] types
+//! alongside the [`TapeParams`] for generating tapes over a given alphabet.
+use crate::{DenseTape, TapeBase};
+use alloc::vec::Vec;
+
+/// the default maximum length of a generated tape
+pub const DEFAULT_MAX_LEN: usize = 32;
+
+/// [`TapeParams`] describes the tapes to generate: each is at least one and at most
+/// `max_len` cells long, every cell holds a symbol drawn from the alphabet, and the head rests
+/// upon any one of them.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TapeParams<A> {
+    pub(crate) alphabet: Vec<A>,
+    pub(crate) max_len: usize,
+}
+
+impl<A> TapeParams<A> {
+    /// returns a new instance generating tapes over the given, non-empty, alphabet
+    pub fn new<I>(alphabet: I) -> Self
+    where
+        I: IntoIterator<Item = A>,
+    {
+        let alphabet = alphabet.into_iter().collect::<Vec<_>>();
+        assert!(!alphabet.is_empty(), "the alphabet must not be empty");
+        Self {
+            alphabet,
+            max_len: DEFAULT_MAX_LEN,
+        }
+    }
+    /// consumes the instance to create another generating tapes of at most the given length
+    pub fn with_max_len(self, max_len: usize) -> Self {
+        Self {
+            max_len: max_len.max(1),
+            ..self
+        }
+    }
+    /// returns the alphabet the cells are drawn from
+    pub fn alphabet(&self) -> &[A] {
+        &self.alphabet
+    }
+    /// returns the maximum length of a generated tape
+    pub const fn max_len(&self) -> usize {
+        self.max_len
+    }
+    #[cfg(feature = "proptest")]
+    /// returns a [`Strategy`](proptest::strategy::Strategy) generating tapes according to
+    /// the parameters
+    pub fn strategy(&self) -> impl proptest::strategy::Strategy<Value = DenseTape<A>> + use<A>
+    where
+        A: 'static + Clone + core::fmt::Debug,
+    {
+        use proptest::prelude::*;
+        let cells = proptest::collection::vec(
+            proptest::sample::select(self.alphabet.clone()),
+            1..=self.max_len,
+        );
+        (cells, any::<proptest::sample::Index>()).prop_map(|(cells, head)| {
+            let head = head.index(cells.len());
+            TapeBase::new(cells).with_head(head)
+        })
+    }
+    #[cfg(feature = "quickcheck")]
+    /// generates a tape according to the parameters
+    pub fn generate(&self, g: &mut quickcheck::Gen) -> DenseTape<A>
+    where
+        A: Clone,
+    {
+        use quickcheck::Arbitrary;
+        let len = usize::arbitrary(g) % self.max_len + 1;
+        let cells = (0..len)
+            .map(|_| g.choose(&self.alphabet).cloned())
+            .collect::<Option<Vec<_>>>()
+            .expect("the alphabet is never empty");
+        TapeBase::new(cells).with_head(usize::arbitrary(g) % len)
+    }
+}
+
+#[cfg(feature = "proptest")]
+mod impl_proptest {
+    use super::DEFAULT_MAX_LEN;
+    use crate::{DenseTape, Direction, TapeBase};
+    use proptest::arbitrary::{Arbitrary, any};
+    use proptest::strategy::{BoxedStrategy, Strategy};
+    use strum::VariantArray;
+
+    impl Arbitrary for Direction {
+        type Parameters = ();
+        type Strategy = proptest::sample::Select<Self>;
+
+        fn arbitrary_with(_: ()) -> Self::Strategy {
+            proptest::sample::select(Self::VARIANTS)
+        }
+    }
+
+    impl<A> Arbitrary for DenseTape<A>
+    where
+        A: 'static + Arbitrary,
+    {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: ()) -> Self::Strategy {
+            let cells = proptest::collection::vec(any::<A>(), 1..=DEFAULT_MAX_LEN);
+            (cells, any::<proptest::sample::Index>())
+                .prop_map(|(cells, head)| {
+                    let head = head.index(cells.len());
+                    TapeBase::new(cells).with_head(head)
+                })
+                .boxed()
+        }
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+mod impl_quickcheck {
+    use crate::{DenseTape, Direction, TapeBase};
+    use alloc::boxed::Box;
+    use alloc::vec::Vec;
+    use quickcheck::{Arbitrary, Gen};
+    use strum::VariantArray;
+
+    impl Arbitrary for Direction {
+        fn arbitrary(g: &mut Gen) -> Self {
+            *g.choose(Self::VARIANTS)
+                .expect("there is always a direction")
+        }
+
+        fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+            // shrink towards staying in place
+            match self {
+                Self::Stay => quickcheck::empty_shrinker(),
+                _ => quickcheck::single_shrinker(Self::Stay),
+            }
+        }
+    }
+
+    impl<A> Arbitrary for DenseTape<A>
+    where
+        A: Arbitrary,
+    {
+        fn arbitrary(g: &mut Gen) -> Self {
+            let mut cells = Vec::<A>::arbitrary(g);
+            if cells.is_empty() {
+                cells.push(A::arbitrary(g));
+            }
+            let head = usize::arbitrary(g) % cells.len();
+            TapeBase::new(cells).with_head(head)
+        }
+
+        fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+            let head = self.head();
+            Box::new(
+                self.store()
+                    .shrink()
+                    .filter(|cells| !cells.is_empty())
+                    .map(move |cells| {
+                        let head = head.min(cells.len() - 1);
+                        TapeBase::new(cells).with_head(head)
+                    }),
+            )
+        }
+    }
+}
@@ -0,0 +1,32 @@
+/*
+    Appellation: bounded <module>
+    Created At: 2026.10.19:20:12:36
+    Contrib: @FL03
+*/
+use crate::RawData;
+
+/// [`BoundedTape`] is the tape of a linear bounded automaton: the input framed by a pair of
+/// end markers which the head may neither move beyond nor overwrite.
+///
+/// The markers are not held by the store; instead, they occupy the virtual cells at positions
+/// `-1` and `len`, where `len` is the length of the input. The head begins upon the first
+/// cell of the input, or upon the right marker should the input be empty.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct BoundedTape<S, A = <S as RawData>::Elem>
+where
+    S: RawData<Elem = A>,
+{
+    /// the input, exclusive of the markers
+    pub(crate) store: S,
+    /// the position of the head, where `-1` and `len` denote the markers
+    pub(crate) position: isize,
+    /// the marker framing the left edge of the input
+    pub(crate) left: A,
+    /// the marker framing the right edge of the input
+    pub(crate) right: A,
+}
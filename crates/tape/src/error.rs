@@ -14,4 +14,10 @@ pub enum TapeError {
     AllocationFailure,
     #[error("The index ({idx}) is out of bounds for a tape of length {len}")]
     IndexOutOfBounds { idx: usize, len: usize },
+    #[error("Unable to move the head {0} beyond the edge of the tape")]
+    EdgeOfTape(crate::Direction),
+    #[error("Unable to move the head {0} beyond the end marker of a bounded tape")]
+    CrossedEndMarker(crate::Direction),
+    #[error("The end markers of a bounded tape cannot be overwritten")]
+    OverwroteEndMarker,
 }
@@ -0,0 +1,130 @@
+/*
+    Appellation: impl_bounded <module>
+    Created At: 2026.10.19:20:14:02
+    Contrib: @FL03
+*/
+use crate::bounded::BoundedTape;
+use crate::error::{Result, TapeError};
+use crate::{Direction, RawData, RawDataMut};
+
+impl<S, A> BoundedTape<S, A>
+where
+    S: RawData<Elem = A>,
+{
+    /// frames the store between the given markers, positioning the head upon the first cell
+    pub fn new(left: A, store: S, right: A) -> Self {
+        Self {
+            store,
+            position: 0,
+            left,
+            right,
+        }
+    }
+    /// returns a reference to the store holding the input
+    pub const fn store(&self) -> &S {
+        &self.store
+    }
+    /// consumes the tape to return the store holding the input
+    pub fn into_store(self) -> S {
+        self.store
+    }
+    /// returns a reference to the marker framing the left edge of the input
+    pub const fn left_marker(&self) -> &A {
+        &self.left
+    }
+    /// returns a reference to the marker framing the right edge of the input
+    pub const fn right_marker(&self) -> &A {
+        &self.right
+    }
+    /// returns the position of the head, where `-1` and `len` denote the markers
+    pub const fn position(&self) -> isize {
+        self.position
+    }
+    /// returns the length of the input, exclusive of the markers
+    pub fn len(&self) -> usize {
+        self.store.len()
+    }
+    /// returns true if the input is empty
+    pub fn is_empty(&self) -> bool {
+        self.store.is_empty()
+    }
+    /// returns true if the head rests upon either marker
+    pub fn is_at_marker(&self) -> bool {
+        self.position < 0 || self.position >= self.len() as isize
+    }
+    /// returns a reference to the cell at the given position, which may be either marker
+    pub fn get(&self, position: isize) -> Option<&A> {
+        match position {
+            -1 => Some(&self.left),
+            p if p == self.len() as isize => Some(&self.right),
+            p => usize::try_from(p).ok().and_then(|idx| self.store.get(idx)),
+        }
+    }
+    /// returns a reference to the cell beneath the head
+    pub fn read(&self) -> &A {
+        self.get(self.position)
+            .expect("the head never leaves the bounds of the tape")
+    }
+    /// returns an iterator over every cell of the tape, including the markers
+    pub fn iter(&self) -> impl Iterator<Item = &A> {
+        (-1..=self.len() as isize).filter_map(|p| self.get(p))
+    }
+    /// moves the head a single cell in the given direction, returning an error rather than
+    /// moving beyond either marker.
+    pub fn try_shift(&mut self, direction: Direction) -> Result<()> {
+        let position = self.position + direction as isize;
+        if position < -1 || position > self.len() as isize {
+            return Err(TapeError::CrossedEndMarker(direction));
+        }
+        self.position = position;
+        Ok(())
+    }
+}
+
+impl<S, A> BoundedTape<S, A>
+where
+    S: RawDataMut<Elem = A>,
+    A: PartialEq,
+{
+    /// writes the value onto the cell beneath the head, returning the previous value. While
+    /// upon a marker, only the marker itself may be written back; any other value results in
+    /// an error.
+    pub fn try_write(&mut self, value: A) -> Result<A> {
+        if self.is_at_marker() {
+            let marker = if self.position < 0 {
+                &self.left
+            } else {
+                &self.right
+            };
+            if value != *marker {
+                return Err(TapeError::OverwroteEndMarker);
+            }
+            return Ok(value);
+        }
+        let len = self.len();
+        let idx = self.position as usize;
+        self.store
+            .replace(idx, value)
+            .ok_or(TapeError::IndexOutOfBounds { idx, len })
+    }
+}
+
+impl<S, A> core::fmt::Display for BoundedTape<S, A>
+where
+    S: RawData<Elem = A>,
+    A: core::fmt::Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for (p, cell) in (-1..).zip(self.iter()) {
+            if p > -1 {
+                f.write_str(", ")?;
+            }
+            if p == self.position {
+                write!(f, "[{cell}]")?;
+            } else {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
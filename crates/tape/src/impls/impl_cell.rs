@@ -1,8 +1,11 @@
+/*
+    Appellation: impl_cell <module>
+    Created At: 2026.01.14:20:45:12
+    Contrib: @FL03
+*/
+use crate::cell::CellBase;
 
-
-use crate::cell::Cell;
-
-impl<T> Cell<T> {
+impl<T> CellBase<T> {
     pub const fn new(value: T) -> Self {
         Self { value }
     }
@@ -23,4 +26,28 @@ impl<T> Cell<T> {
     pub const fn replace(&mut self, value: T) -> T {
         core::mem::replace(self.get_mut(), value)
     }
-}
\ No newline at end of file
+}
+
+#[cfg(feature = "alloc")]
+impl<T> crate::cell::SharedCell<T> {
+    /// returns a new cell sharing ownership of the given value
+    pub fn shared(value: T) -> Self {
+        Self::new(alloc::sync::Arc::new(value))
+    }
+    /// returns a mutable reference to the shared value, cloning it beforehand should any
+    /// other cell share ownership of it.
+    pub fn make_mut(&mut self) -> &mut T
+    where
+        T: Clone,
+    {
+        alloc::sync::Arc::make_mut(&mut self.value)
+    }
+    /// returns true if both cells share ownership of the same value
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        alloc::sync::Arc::ptr_eq(&self.value, &other.value)
+    }
+    /// returns a raw pointer to the shared value, identifying the allocation it lives within
+    pub fn as_ptr(&self) -> *const T {
+        alloc::sync::Arc::as_ptr(&self.value)
+    }
+}
@@ -0,0 +1,201 @@
+/*
+    Appellation: impl_instrumented <module>
+    Created At: 2026.10.19:21:31:52
+    Contrib: @FL03
+*/
+use crate::instrumented::{InstrumentedCell, InstrumentedStore};
+use crate::tape_base::TapeBase;
+use crate::{RawData, RawDataMut, ResizableData};
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use core::ops::RangeInclusive;
+
+impl<A> InstrumentedCell<A> {
+    /// returns a new cell holding the given value that has yet to be accessed
+    pub const fn new(value: A) -> Self {
+        Self {
+            value,
+            writes: 0,
+            last_read: None,
+            last_write: None,
+            history: Vec::new(),
+        }
+    }
+    /// returns a reference to the current value of the cell
+    pub const fn value(&self) -> &A {
+        &self.value
+    }
+    /// returns the number of times the cell was written
+    pub const fn writes(&self) -> usize {
+        self.writes
+    }
+    /// returns the step during which the cell was last read, if ever
+    pub const fn last_read(&self) -> Option<usize> {
+        self.last_read
+    }
+    /// returns the step during which the cell was last written, if ever
+    pub const fn last_write(&self) -> Option<usize> {
+        self.last_write
+    }
+    /// returns the step during which the cell was last accessed, if ever
+    pub fn last_access(&self) -> Option<usize> {
+        self.last_read.max(self.last_write)
+    }
+    /// returns the symbols written onto the cell, in order
+    pub const fn history(&self) -> &[A] {
+        self.history.as_slice()
+    }
+    /// returns true if the cell has not been accessed since the given step
+    pub fn is_idle_since(&self, step: usize) -> bool {
+        self.last_access().is_none_or(|last| last < step)
+    }
+}
+
+impl<A> InstrumentedStore<A> {
+    /// returns a new, empty store
+    pub const fn new() -> Self {
+        Self {
+            cells: VecDeque::new(),
+        }
+    }
+    /// returns the number of cells held by the store
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+    /// returns true if the store holds no cells
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+    /// returns a reference to the cell, alongside its metadata, at the given index
+    pub fn cell(&self, idx: usize) -> Option<&InstrumentedCell<A>> {
+        self.cells.get(idx)
+    }
+    /// returns an iterator over the cells of the store alongside their metadata
+    pub fn cells(&self) -> impl Iterator<Item = &InstrumentedCell<A>> {
+        self.cells.iter()
+    }
+    /// returns an iterator over the number of times each cell was written, in order
+    pub fn heatmap(&self) -> impl Iterator<Item = usize> {
+        self.cells.iter().map(InstrumentedCell::writes)
+    }
+    /// returns an iterator over the _dead_ regions of the store; i.e. the inclusive ranges of
+    /// indices spanned by each run of adjacent cells left idle since the given step.
+    pub fn dead_regions(&self, since: usize) -> impl Iterator<Item = RangeInclusive<usize>> {
+        let mut idle = self
+            .cells
+            .iter()
+            .enumerate()
+            .filter(move |(_, cell)| cell.is_idle_since(since))
+            .map(|(idx, _)| idx)
+            .peekable();
+        core::iter::from_fn(move || {
+            let start = idle.next()?;
+            let mut end = start;
+            while idle.next_if_eq(&(end + 1)).is_some() {
+                end += 1;
+            }
+            Some(start..=end)
+        })
+    }
+    /// discards the metadata collected for every cell, retaining their values
+    pub fn reset(&mut self) {
+        self.cells.iter_mut().for_each(|cell| {
+            cell.writes = 0;
+            cell.last_read = None;
+            cell.last_write = None;
+            cell.history.clear();
+        })
+    }
+}
+
+impl<A> TapeBase<InstrumentedStore<A>, A> {
+    /// returns a reference to the cell, alongside its metadata, at the given position relative
+    /// to the origin
+    pub fn cell_at(&self, position: isize) -> Option<&InstrumentedCell<A>> {
+        let idx = self.origin().checked_add_signed(position)?;
+        self.store().cell(idx)
+    }
+    /// returns an iterator over the number of times each cell was written alongside its
+    /// position relative to the origin
+    pub fn heatmap(&self) -> impl Iterator<Item = (isize, usize)> {
+        let origin = self.origin() as isize;
+        (-origin..).zip(self.store().heatmap())
+    }
+    /// returns an iterator over the regions of the tape left idle since the given step, each
+    /// given as an inclusive range of positions relative to the origin.
+    pub fn dead_regions(&self, since: usize) -> impl Iterator<Item = RangeInclusive<isize>> {
+        let origin = self.origin() as isize;
+        self.store()
+            .dead_regions(since)
+            .map(move |r| (*r.start() as isize - origin)..=(*r.end() as isize - origin))
+    }
+}
+
+impl<A> RawData for InstrumentedStore<A> {
+    type Elem = A;
+
+    fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    fn get(&self, idx: usize) -> Option<&A> {
+        self.cells.get(idx).map(InstrumentedCell::value)
+    }
+}
+
+impl<A> RawDataMut for InstrumentedStore<A>
+where
+    A: Clone,
+{
+    fn get_mut(&mut self, idx: usize) -> Option<&mut A> {
+        self.cells.get_mut(idx).map(|cell| &mut cell.value)
+    }
+
+    fn record_read(&mut self, idx: usize, step: usize) {
+        if let Some(cell) = self.cells.get_mut(idx) {
+            cell.last_read = Some(step);
+        }
+    }
+
+    fn record_write(&mut self, idx: usize, step: usize) {
+        if let Some(cell) = self.cells.get_mut(idx) {
+            cell.writes += 1;
+            cell.last_write = Some(step);
+            cell.history.push(cell.value.clone());
+        }
+    }
+}
+
+impl<A> ResizableData for InstrumentedStore<A>
+where
+    A: Clone,
+{
+    fn push_front(&mut self, elem: A) {
+        self.cells.push_front(InstrumentedCell::new(elem));
+    }
+
+    fn push_back(&mut self, elem: A) {
+        self.cells.push_back(InstrumentedCell::new(elem));
+    }
+}
+
+impl<A> Extend<A> for InstrumentedStore<A> {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = A>,
+    {
+        self.cells
+            .extend(iter.into_iter().map(InstrumentedCell::new));
+    }
+}
+
+impl<A> FromIterator<A> for InstrumentedStore<A> {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = A>,
+    {
+        Self {
+            cells: iter.into_iter().map(InstrumentedCell::new).collect(),
+        }
+    }
+}
@@ -0,0 +1,322 @@
+/*
+    Appellation: impl_persistent <module>
+    Created At: 2026.10.19:21:03:40
+    Contrib: @FL03
+*/
+use crate::cell::SharedCell;
+use crate::persistent::{BRANCHING_FACTOR, CHUNK_SIZE, Node, PersistentStore};
+use crate::{RawData, RawDataMut, ResizableData};
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// returns the number of cells spanned by a node at the given height
+const fn span(height: usize) -> usize {
+    CHUNK_SIZE * BRANCHING_FACTOR.pow(height as u32)
+}
+
+impl<A> Node<A> {
+    /// returns a reference to the cell at the given position, if any
+    fn get(&self, height: usize, pos: usize) -> Option<&A> {
+        match self {
+            Self::Branch(children) => {
+                let child = (pos / span(height - 1)) % BRANCHING_FACTOR;
+                children.get()[child].as_ref()?.get(height - 1, pos)
+            }
+            Self::Leaf(chunk) => chunk.get().get(pos % CHUNK_SIZE),
+        }
+    }
+    /// returns a mutable reference to the cell at the given position, if any, copying every
+    /// shared node along the way
+    fn get_mut(&mut self, height: usize, pos: usize) -> Option<&mut A>
+    where
+        A: Clone,
+    {
+        match self {
+            Self::Branch(children) => {
+                let child = (pos / span(height - 1)) % BRANCHING_FACTOR;
+                children.make_mut()[child]
+                    .as_mut()?
+                    .get_mut(height - 1, pos)
+            }
+            Self::Leaf(chunk) => chunk.make_mut().get_mut(pos % CHUNK_SIZE),
+        }
+    }
+    /// writes the element onto the cell at the given position, creating any missing nodes
+    /// along the way; the unused cells of a new chunk are padded with copies of the element.
+    fn insert(slot: &mut Option<Self>, height: usize, pos: usize, elem: A)
+    where
+        A: Clone,
+    {
+        let node = slot.get_or_insert_with(|| match height {
+            0 => Self::Leaf(SharedCell::shared(vec![elem.clone(); CHUNK_SIZE])),
+            _ => Self::Branch(SharedCell::shared(vec![None; BRANCHING_FACTOR])),
+        });
+        match node {
+            Self::Branch(children) => {
+                let child = (pos / span(height - 1)) % BRANCHING_FACTOR;
+                Self::insert(&mut children.make_mut()[child], height - 1, pos, elem)
+            }
+            Self::Leaf(chunk) => chunk.make_mut()[pos % CHUNK_SIZE] = elem,
+        }
+    }
+    /// appends the chunks beneath the node onto the buffer, in order
+    fn chunks<'a>(&'a self, buf: &mut Vec<&'a SharedCell<Vec<A>>>) {
+        match self {
+            Self::Branch(children) => children
+                .get()
+                .iter()
+                .flatten()
+                .for_each(|child| child.chunks(buf)),
+            Self::Leaf(chunk) => buf.push(chunk),
+        }
+    }
+}
+
+impl<A> Clone for Node<A> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Branch(children) => Self::Branch(children.clone()),
+            Self::Leaf(chunk) => Self::Leaf(chunk.clone()),
+        }
+    }
+}
+
+impl<A> PersistentStore<A> {
+    /// returns a new, empty store
+    pub const fn new() -> Self {
+        Self {
+            root: None,
+            height: 0,
+            start: 0,
+            len: 0,
+        }
+    }
+    /// returns the number of cells held by the store
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+    /// returns true if the store holds no cells
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    /// returns the number of branches between the root of the tree and each of its chunks
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+    /// returns the number of chunks spanned by the store
+    pub const fn chunks(&self) -> usize {
+        match self.len {
+            0 => 0,
+            len => (self.start + len - 1) / CHUNK_SIZE - self.start / CHUNK_SIZE + 1,
+        }
+    }
+    /// returns the number of chunks shared, in their entirety, with the other store
+    pub fn shared_chunks(&self, other: &Self) -> usize {
+        let mut theirs = other
+            .leaves()
+            .iter()
+            .map(|chunk| chunk.as_ptr())
+            .collect::<Vec<_>>();
+        theirs.sort_unstable();
+        self.leaves()
+            .iter()
+            .filter(|chunk| theirs.binary_search(&chunk.as_ptr()).is_ok())
+            .count()
+    }
+    /// returns the chunks of the store, in order
+    fn leaves(&self) -> Vec<&SharedCell<Vec<A>>> {
+        let mut buf = Vec::with_capacity(self.chunks());
+        if let Some(root) = &self.root {
+            root.chunks(&mut buf);
+        }
+        buf
+    }
+    /// returns a reference to the cell at the given index, if any
+    pub fn get(&self, idx: usize) -> Option<&A> {
+        if idx >= self.len {
+            return None;
+        }
+        self.root.as_ref()?.get(self.height, self.start + idx)
+    }
+    /// returns a mutable reference to the cell at the given index, if any, copying the chunk
+    /// containing it alongside the branches leading to it should they be shared with another
+    /// store.
+    pub fn get_mut(&mut self, idx: usize) -> Option<&mut A>
+    where
+        A: Clone,
+    {
+        if idx >= self.len {
+            return None;
+        }
+        self.root.as_mut()?.get_mut(self.height, self.start + idx)
+    }
+    /// returns an iterator over the cells of the store, in order
+    pub fn iter(&self) -> impl Iterator<Item = &A> {
+        self.leaves()
+            .into_iter()
+            .flat_map(|chunk| chunk.get().iter())
+            .skip(self.start % CHUNK_SIZE)
+            .take(self.len)
+    }
+    /// removes every cell from the store
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+    /// raises the tree by placing the current root at the given index of a new one
+    fn grow(&mut self, child: usize) {
+        let mut children = vec![None; BRANCHING_FACTOR];
+        children[child] = self.root.take();
+        self.root = Some(Node::Branch(SharedCell::shared(children)));
+        self.start += child * span(self.height);
+        self.height += 1;
+    }
+}
+
+impl<A> RawData for PersistentStore<A> {
+    type Elem = A;
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn get(&self, idx: usize) -> Option<&A> {
+        PersistentStore::get(self, idx)
+    }
+}
+
+impl<A> RawDataMut for PersistentStore<A>
+where
+    A: Clone,
+{
+    fn get_mut(&mut self, idx: usize) -> Option<&mut A> {
+        PersistentStore::get_mut(self, idx)
+    }
+}
+
+impl<A> ResizableData for PersistentStore<A>
+where
+    A: Clone,
+{
+    fn push_front(&mut self, elem: A) {
+        if self.start == 0 {
+            // leave room for the tape to continue growing in either direction
+            self.grow(BRANCHING_FACTOR / 2);
+        }
+        self.start -= 1;
+        self.len += 1;
+        Node::insert(&mut self.root, self.height, self.start, elem);
+    }
+
+    fn push_back(&mut self, elem: A) {
+        if self.start + self.len == span(self.height) {
+            self.grow(0);
+        }
+        Node::insert(&mut self.root, self.height, self.start + self.len, elem);
+        self.len += 1;
+    }
+}
+
+impl<A> Clone for PersistentStore<A> {
+    fn clone(&self) -> Self {
+        Self {
+            root: self.root.clone(),
+            height: self.height,
+            start: self.start,
+            len: self.len,
+        }
+    }
+}
+
+impl<A> core::fmt::Debug for PersistentStore<A>
+where
+    A: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<A> Default for PersistentStore<A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A: Eq> Eq for PersistentStore<A> {}
+
+impl<A: PartialEq> PartialEq for PersistentStore<A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<A: core::hash::Hash> core::hash::Hash for PersistentStore<A> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+        self.iter().for_each(|cell| cell.hash(state));
+    }
+}
+
+impl<A: Ord> Ord for PersistentStore<A> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<A: PartialOrd> PartialOrd for PersistentStore<A> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<A> Extend<A> for PersistentStore<A>
+where
+    A: Clone,
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = A>,
+    {
+        iter.into_iter().for_each(|cell| self.push_back(cell));
+    }
+}
+
+impl<A> FromIterator<A> for PersistentStore<A>
+where
+    A: Clone,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = A>,
+    {
+        let mut store = Self::new();
+        store.extend(iter);
+        store
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<A> serde::Serialize for PersistentStore<A>
+where
+    A: serde::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, A> serde::Deserialize<'de> for PersistentStore<A>
+where
+    A: Clone + serde::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        alloc::vec::Vec::<A>::deserialize(deserializer).map(Self::from_iter)
+    }
+}
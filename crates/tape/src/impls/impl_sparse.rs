@@ -0,0 +1,312 @@
+/*
+    Appellation: impl_sparse <module>
+    Created At: 2026.10.19:19:09:26
+    Contrib: @FL03
+*/
+use crate::sparse::SparseStore;
+use crate::tape_base::TapeBase;
+use crate::{RawData, RawDataMut, ResizableData};
+use alloc::collections::BTreeMap;
+use core::ops::RangeInclusive;
+
+impl<A> SparseStore<A> {
+    /// returns a new, empty store using the default value of the symbol as the blank
+    pub fn new() -> Self
+    where
+        A: Default,
+    {
+        Self::with_blank(A::default())
+    }
+    /// returns a new, empty store using the given blank
+    pub const fn with_blank(blank: A) -> Self {
+        Self {
+            cells: BTreeMap::new(),
+            base: 0,
+            len: 0,
+            blank,
+            materialized: None,
+        }
+    }
+    /// returns a reference to the blank
+    pub const fn blank(&self) -> &A {
+        &self.blank
+    }
+    /// returns the number of cells spanned by the store
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+    /// returns true if the store does not span any cells
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    /// returns the number of non-blank cells held by the store
+    pub fn count(&self) -> usize
+    where
+        A: PartialEq,
+    {
+        self.iter().count()
+    }
+    /// converts the index into the key of the corresponding cell
+    const fn key(&self, idx: usize) -> isize {
+        self.base + idx as isize
+    }
+    /// returns a reference to the cell at the given index, if any
+    pub fn get(&self, idx: usize) -> Option<&A> {
+        if idx >= self.len {
+            return None;
+        }
+        Some(self.cells.get(&self.key(idx)).unwrap_or(&self.blank))
+    }
+    /// returns an iterator over the non-blank cells alongside their indices, in order
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &A)>
+    where
+        A: PartialEq,
+    {
+        self.cells
+            .iter()
+            .filter(|(_, cell)| **cell != self.blank)
+            .map(|(key, cell)| ((key - self.base) as usize, cell))
+    }
+    /// returns an iterator over the written regions of the store; i.e. the inclusive ranges
+    /// of indices spanned by each run of adjacent non-blank cells.
+    pub fn regions(&self) -> impl Iterator<Item = RangeInclusive<usize>>
+    where
+        A: PartialEq,
+    {
+        let mut keys = self.iter().map(|(idx, _)| idx).peekable();
+        core::iter::from_fn(move || {
+            let start = keys.next()?;
+            let mut end = start;
+            while keys.next_if_eq(&(end + 1)).is_some() {
+                end += 1;
+            }
+            Some(start..=end)
+        })
+    }
+    /// removes every cell from the store
+    pub fn clear(&mut self) {
+        self.cells.clear();
+        self.base = 0;
+        self.len = 0;
+        self.materialized = None;
+    }
+    /// prunes the most recently materialized cell should it still hold the blank
+    fn settle(&mut self)
+    where
+        A: PartialEq,
+    {
+        if let Some(key) = self.materialized.take()
+            && self.cells.get(&key) == Some(&self.blank)
+        {
+            self.cells.remove(&key);
+        }
+    }
+}
+
+impl<A> TapeBase<SparseStore<A>, A>
+where
+    A: PartialEq,
+{
+    /// returns the number of non-blank cells on the tape
+    pub fn count(&self) -> usize {
+        self.store().count()
+    }
+    /// returns an iterator over the written regions of the tape, each given as an inclusive
+    /// range of positions relative to the origin.
+    pub fn regions(&self) -> impl Iterator<Item = RangeInclusive<isize>> {
+        let origin = self.origin() as isize;
+        self.store()
+            .regions()
+            .map(move |r| (*r.start() as isize - origin)..=(*r.end() as isize - origin))
+    }
+}
+
+impl<A> Default for SparseStore<A>
+where
+    A: Default,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A> PartialEq for SparseStore<A>
+where
+    A: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.base == other.base
+            && self.len == other.len
+            && self.blank == other.blank
+            && self.iter().eq(other.iter())
+    }
+}
+
+impl<A> Eq for SparseStore<A> where A: Eq {}
+
+impl<A> core::hash::Hash for SparseStore<A>
+where
+    A: core::hash::Hash + PartialEq,
+{
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.base.hash(state);
+        self.len.hash(state);
+        self.blank.hash(state);
+        for cell in self.iter() {
+            cell.hash(state);
+        }
+    }
+}
+
+impl<A> PartialOrd for SparseStore<A>
+where
+    A: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        use core::cmp::Ordering;
+
+        match (self.base, self.len).cmp(&(other.base, other.len)) {
+            Ordering::Equal => {}
+            ord => return Some(ord),
+        }
+        match self.blank.partial_cmp(&other.blank) {
+            Some(Ordering::Equal) => self.iter().partial_cmp(other.iter()),
+            ord => ord,
+        }
+    }
+}
+
+impl<A> Ord for SparseStore<A>
+where
+    A: Ord,
+{
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        (self.base, self.len)
+            .cmp(&(other.base, other.len))
+            .then_with(|| self.blank.cmp(&other.blank))
+            .then_with(|| self.iter().cmp(other.iter()))
+    }
+}
+
+impl<A> RawData for SparseStore<A>
+where
+    A: PartialEq,
+{
+    type Elem = A;
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn get(&self, idx: usize) -> Option<&A> {
+        SparseStore::get(self, idx)
+    }
+
+    fn iter_stored(&self) -> impl Iterator<Item = (usize, &A)> {
+        self.iter()
+    }
+}
+
+impl<A> RawDataMut for SparseStore<A>
+where
+    A: Clone + PartialEq,
+{
+    /// returns a mutable reference to the cell at the given index, materializing the cell
+    /// when it has yet to be written; the cell is pruned by the next mutation of the store
+    /// should it still hold the blank.
+    fn get_mut(&mut self, idx: usize) -> Option<&mut A> {
+        self.settle();
+        if idx >= self.len {
+            return None;
+        }
+        let key = self.key(idx);
+        self.materialized = Some(key);
+        let blank = &self.blank;
+        Some(self.cells.entry(key).or_insert_with(|| blank.clone()))
+    }
+
+    fn replace(&mut self, idx: usize, elem: A) -> Option<A> {
+        self.settle();
+        if idx >= self.len {
+            return None;
+        }
+        let key = self.key(idx);
+        // writing the blank erases the cell rather than storing it
+        let prev = if elem == self.blank {
+            self.cells.remove(&key)
+        } else {
+            self.cells.insert(key, elem)
+        };
+        Some(prev.unwrap_or_else(|| self.blank.clone()))
+    }
+}
+
+impl<A> ResizableData for SparseStore<A>
+where
+    A: Clone + PartialEq,
+{
+    fn push_front(&mut self, elem: A) {
+        self.grow_front(1, elem);
+    }
+
+    fn push_back(&mut self, elem: A) {
+        self.grow_back(1, elem);
+    }
+
+    fn blank_elem(&self) -> A
+    where
+        A: Default,
+    {
+        self.blank.clone()
+    }
+
+    fn grow_front(&mut self, count: usize, elem: A) {
+        self.settle();
+        self.base -= count as isize;
+        self.len += count;
+        if elem != self.blank {
+            for idx in 0..count {
+                self.cells.insert(self.key(idx), elem.clone());
+            }
+        }
+    }
+
+    fn grow_back(&mut self, count: usize, elem: A) {
+        self.settle();
+        let start = self.len;
+        self.len += count;
+        if elem != self.blank {
+            for idx in start..self.len {
+                self.cells.insert(self.key(idx), elem.clone());
+            }
+        }
+    }
+}
+
+impl<A> FromIterator<A> for SparseStore<A>
+where
+    A: Clone + Default + PartialEq,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = A>,
+    {
+        let mut store = Self::new();
+        store.extend(iter);
+        store
+    }
+}
+
+impl<A> Extend<A> for SparseStore<A>
+where
+    A: Clone + PartialEq,
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = A>,
+    {
+        for elem in iter {
+            self.push_back(elem);
+        }
+    }
+}
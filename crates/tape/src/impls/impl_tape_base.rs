@@ -3,8 +3,9 @@
     Created At: 2026.01.14:20:37:28
     Contrib: @FL03
 */
-use crate::RawData;
-use crate::tape_base::TapeBase;
+use crate::error::{Result, TapeError};
+use crate::tape_base::{TapeBase, Window};
+use crate::{Direction, RawData, RawDataMut, ResizableData};
 
 impl<S, T> TapeBase<S, T>
 where
@@ -20,6 +21,261 @@ where
     ///
     /// A new instance of `TapeBase`.
     pub fn new(store: S) -> Self {
-        Self { store }
+        Self {
+            store,
+            head: 0,
+            origin: 0,
+        }
+    }
+    /// consumes the tape to create another with the head resting on the cell at the given
+    /// index, which also becomes the origin of the tape.
+    pub fn with_head(self, idx: usize) -> Self {
+        Self {
+            head: idx,
+            origin: idx,
+            ..self
+        }
+    }
+    /// returns a reference to the underlying store
+    pub const fn store(&self) -> &S {
+        &self.store
+    }
+    /// returns a mutable reference to the underlying store
+    pub const fn store_mut(&mut self) -> &mut S {
+        &mut self.store
+    }
+    /// consumes the tape to return the underlying store
+    pub fn into_store(self) -> S {
+        self.store
+    }
+    /// returns the index of the head within the store
+    pub const fn head(&self) -> usize {
+        self.head
+    }
+    /// returns the index of the origin within the store
+    pub const fn origin(&self) -> usize {
+        self.origin
+    }
+    /// returns the position of the head relative to the origin
+    pub const fn position(&self) -> isize {
+        self.head as isize - self.origin as isize
+    }
+    /// returns the number of cells within the tape
+    pub fn len(&self) -> usize {
+        self.store().len()
+    }
+    /// returns true if the tape contains no cells
+    pub fn is_empty(&self) -> bool {
+        self.store().is_empty()
+    }
+    /// returns a reference to the cell at the given index within the store, if any
+    pub fn get(&self, idx: usize) -> Option<&T> {
+        self.store().get(idx)
+    }
+    /// returns a reference to the cell at the given position relative to the origin, if any
+    pub fn get_at(&self, position: isize) -> Option<&T> {
+        let idx = self.origin.checked_add_signed(position)?;
+        self.get(idx)
+    }
+    /// returns a reference to the cell beneath the head, if any
+    pub fn read(&self) -> Option<&T> {
+        self.get(self.head)
+    }
+    /// moves the head a single cell in the given direction, returning an error rather than
+    /// moving beyond either edge of the tape.
+    pub fn try_shift(&mut self, direction: Direction) -> Result<()> {
+        match self.head.checked_add_signed(direction as isize) {
+            Some(idx) if idx < self.len() => {
+                self.head = idx;
+                Ok(())
+            }
+            _ => Err(TapeError::EdgeOfTape(direction)),
+        }
+    }
+    /// returns an iterator over the cells of the tape
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        (0..self.len()).filter_map(|idx| self.get(idx))
+    }
+    /// returns an iterator over the non-blank cells of the tape alongside their positions
+    /// relative to the origin, where the blank is the default value of the symbol.
+    pub fn iter_non_blank(&self) -> impl Iterator<Item = (isize, &T)>
+    where
+        T: Default + PartialEq,
+    {
+        let blank = T::default();
+        self.store
+            .iter_stored()
+            .filter(move |(_, cell)| **cell != blank)
+            .map(|(idx, cell)| (idx as isize - self.origin as isize, cell))
+    }
+    /// returns a [`Window`] displaying up to `radius` cells on either side of the head
+    pub const fn window(&self, radius: usize) -> Window<'_, S, T> {
+        Window { tape: self, radius }
+    }
+}
+
+impl<S, T> TapeBase<S, T>
+where
+    S: RawDataMut<Elem = T>,
+{
+    /// returns a mutable reference to the cell at the given index within the store, if any
+    pub fn get_mut(&mut self, idx: usize) -> Option<&mut T> {
+        self.store_mut().get_mut(idx)
+    }
+    /// returns a mutable reference to the cell beneath the head, if any
+    pub fn read_mut(&mut self) -> Option<&mut T> {
+        self.store.get_mut(self.head)
+    }
+    /// writes the value onto the cell beneath the head, returning the previous value or an
+    /// error if the head does not rest upon a cell.
+    pub fn try_write(&mut self, value: T) -> Result<T> {
+        let len = self.len();
+        match self.read_mut() {
+            Some(cell) => Ok(core::mem::replace(cell, value)),
+            None => Err(TapeError::IndexOutOfBounds {
+                idx: self.head,
+                len,
+            }),
+        }
+    }
+    /// records that the cell beneath the head was read during the given step
+    pub fn record_read(&mut self, step: usize) {
+        self.store.record_read(self.head, step);
+    }
+    /// records that the cell beneath the head was written during the given step
+    pub fn record_write(&mut self, step: usize) {
+        self.store.record_write(self.head, step);
+    }
+}
+
+impl<S, T> TapeBase<S, T>
+where
+    S: ResizableData<Elem = T>,
+    T: Clone + Default,
+{
+    /// grows the tape with the blank of its store until it contains the cell at the given
+    /// position relative to the origin, returning the index of the cell within the store.
+    fn reserve(&mut self, position: isize) -> usize {
+        let idx = self.origin as isize + position;
+        if idx < 0 {
+            let count = idx.unsigned_abs();
+            let blank = self.store.blank_elem();
+            self.store.grow_front(count, blank);
+            self.head += count;
+            self.origin += count;
+        }
+        let idx = idx.max(0) as usize;
+        if idx >= self.len() {
+            let (count, blank) = (idx + 1 - self.len(), self.store.blank_elem());
+            self.store.grow_back(count, blank);
+        }
+        idx
+    }
+    /// returns a mutable reference to the cell at the given position relative to the origin,
+    /// growing the tape with blanks as necessary.
+    pub fn cell_mut(&mut self, position: isize) -> &mut T {
+        let idx = self.reserve(position);
+        self.store
+            .get_mut(idx)
+            .expect("the tape was grown to contain the cell")
+    }
+    /// writes the value onto the cell at the given position relative to the origin, growing
+    /// the tape as necessary before returning the previous value.
+    pub fn write_at(&mut self, position: isize, value: T) -> T {
+        let idx = self.reserve(position);
+        self.store
+            .replace(idx, value)
+            .expect("the tape was grown to contain the cell")
+    }
+    /// writes the value onto the cell beneath the head, growing the tape as necessary before
+    /// returning the previous value.
+    pub fn write(&mut self, value: T) -> T {
+        self.write_at(self.position(), value)
+    }
+    /// moves the head a single cell in the given direction, growing the tape with a blank
+    /// whenever the head would otherwise move beyond either edge.
+    pub fn shift(&mut self, direction: Direction) {
+        let position = self.position() + direction as isize;
+        self.head = self.reserve(position);
+    }
+}
+
+impl<S, T> core::fmt::Display for Window<'_, S, T>
+where
+    S: RawData<Elem = T>,
+    T: core::fmt::Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let head = self.tape.head();
+        let start = head.saturating_sub(self.radius);
+        let end = head
+            .saturating_add(self.radius)
+            .min(self.tape.len().saturating_sub(1));
+        for idx in start..=end {
+            let Some(cell) = self.tape.get(idx) else {
+                break;
+            };
+            if idx > start {
+                f.write_str(", ")?;
+            }
+            if idx == head {
+                write!(f, "[{cell}]")?;
+            } else {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<S, T> core::fmt::Display for TapeBase<S, T>
+where
+    S: RawData<Elem = T>,
+    T: core::fmt::Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.window(self.len()), f)
+    }
+}
+
+impl<S, T> Default for TapeBase<S, T>
+where
+    S: RawData<Elem = T> + Default,
+{
+    fn default() -> Self {
+        Self::new(S::default())
+    }
+}
+
+impl<S, T> From<S> for TapeBase<S, T>
+where
+    S: RawData<Elem = T>,
+{
+    fn from(store: S) -> Self {
+        Self::new(store)
+    }
+}
+
+impl<S, T> FromIterator<T> for TapeBase<S, T>
+where
+    S: RawData<Elem = T> + FromIterator<T>,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        Self::new(S::from_iter(iter))
+    }
+}
+
+impl<S, T> Extend<T> for TapeBase<S, T>
+where
+    S: RawData<Elem = T> + Extend<T>,
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        self.store.extend(iter);
     }
 }
@@ -0,0 +1,43 @@
+/*
+    Appellation: instrumented <module>
+    Created At: 2026.10.19:21:27:05
+    Contrib: @FL03
+*/
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+
+/// [`InstrumentedCell`] pairs the value of a cell with a record of how it has been accessed;
+/// i.e. the number of times it was written, the steps at which it was last read and written,
+/// and the symbols written onto it.
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(deny_unknown_fields, rename_all = "snake_case")
+)]
+pub struct InstrumentedCell<A> {
+    /// the current value of the cell
+    pub(crate) value: A,
+    /// the number of times the cell was written
+    pub(crate) writes: usize,
+    /// the step during which the cell was last read, if ever
+    pub(crate) last_read: Option<usize>,
+    /// the step during which the cell was last written, if ever
+    pub(crate) last_write: Option<usize>,
+    /// the symbols written onto the cell, in order
+    pub(crate) history: Vec<A>,
+}
+
+/// [`InstrumentedStore`] is a store which collects an [`InstrumentedCell`] for each of its
+/// cells, enabling the engine to record every access to the tape without any bookkeeping on
+/// the part of the program. The metadata may then be used to produce heatmaps of the tape or
+/// to detect regions the machine no longer visits.
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(deny_unknown_fields, rename_all = "snake_case")
+)]
+pub struct InstrumentedStore<A> {
+    pub(crate) cells: VecDeque<InstrumentedCell<A>>,
+}
@@ -25,12 +25,29 @@ mod macros {
     pub(crate) mod seal;
 }
 // modules
+#[cfg(any(feature = "proptest", feature = "quickcheck"))]
+pub mod arbitrary;
+pub mod bounded;
 pub mod cell;
 pub mod error;
+#[cfg(feature = "alloc")]
+pub mod instrumented;
+#[cfg(feature = "alloc")]
+pub mod persistent;
+#[cfg(feature = "alloc")]
+pub mod sparse;
 // modules (private)
 mod tape_base;
 
 mod impls {
+    mod impl_bounded;
+    mod impl_cell;
+    #[cfg(feature = "alloc")]
+    mod impl_instrumented;
+    #[cfg(feature = "alloc")]
+    mod impl_persistent;
+    #[cfg(feature = "alloc")]
+    mod impl_sparse;
     mod impl_tape_base;
 }
 
@@ -43,14 +60,29 @@ mod traits {
     mod raw_tape;
 }
 
-mod types {}
+mod types {
+    #[doc(inline)]
+    pub use self::direction::*;
+
+    mod direction;
+}
 // re-exports
 #[doc(inline)]
-pub use self::{cell::CellBase, error::*, tape_base::*, traits::*};
+pub use self::{bounded::BoundedTape, cell::CellBase, error::*, tape_base::*, traits::*, types::*};
+#[cfg(feature = "alloc")]
+pub use self::{instrumented::InstrumentedStore, persistent::PersistentStore, sparse::SparseStore};
 // prelude
 #[doc(hidden)]
 pub mod prelude {
+    pub use crate::bounded::*;
     pub use crate::cell::*;
+    #[cfg(feature = "alloc")]
+    pub use crate::instrumented::*;
+    #[cfg(feature = "alloc")]
+    pub use crate::persistent::*;
+    #[cfg(feature = "alloc")]
+    pub use crate::sparse::*;
     pub use crate::tape_base::*;
     pub use crate::traits::*;
+    pub use crate::types::*;
 }
@@ -0,0 +1,45 @@
+/*
+    Appellation: persistent <module>
+    Created At: 2026.10.19:20:58:17
+    Contrib: @FL03
+*/
+use crate::cell::SharedCell;
+use alloc::vec::Vec;
+
+/// the number of cells held by each chunk of a [`PersistentStore`]
+pub const CHUNK_SIZE: usize = 64;
+/// the number of children of each branch within the tree of a [`PersistentStore`]
+pub const BRANCHING_FACTOR: usize = 32;
+
+/// [`PersistentStore`] is a copy-on-write store for tapes that are forked often, such as
+/// those explored by a nondeterministic search.
+///
+/// The cells are divided into chunks of [`CHUNK_SIZE`] cells, which form the leaves of a
+/// tree whose branches each hold up to [`BRANCHING_FACTOR`] children, with every node held
+/// within a [`SharedCell`]. Cloning the store is thus a constant-time operation, while
+/// writing to a shared store copies only the branches along the path to the chunk being
+/// written to alongside the chunk itself, costing `O(log(len / CHUNK_SIZE))` pointers and
+/// `CHUNK_SIZE` cells. Subsequent writes to the same store copy only the nodes they touch
+/// for the first time.
+///
+/// The tree is raised whenever the cells outgrow it, placing the current root at the start of
+/// a new one when growing towards the right, or in its middle when growing towards the left,
+/// so pushing onto either end of the store costs amortized constant time.
+pub struct PersistentStore<A> {
+    /// the root of the tree, if any cells have been written
+    pub(crate) root: Option<Node<A>>,
+    /// the number of branches between the root and each of the chunks
+    pub(crate) height: usize,
+    /// the position of the first cell within the span of the tree
+    pub(crate) start: usize,
+    /// the number of cells held by the store
+    pub(crate) len: usize,
+}
+
+/// a node within the tree of a [`PersistentStore`]
+pub(crate) enum Node<A> {
+    /// an inner node, whose children each span an equal number of chunks
+    Branch(SharedCell<Vec<Option<Node<A>>>>),
+    /// a chunk of cells, filled to capacity
+    Leaf(SharedCell<Vec<A>>),
+}
@@ -0,0 +1,36 @@
+/*
+    Appellation: sparse <module>
+    Created At: 2026.10.19:19:04:51
+    Contrib: @FL03
+*/
+use alloc::collections::BTreeMap;
+
+/// [`SparseStore`] is a store for tapes whose written cells lie far apart, holding only the
+/// cells that differ from the blank within a [`BTreeMap`] keyed by their offset.
+///
+/// The store spans a contiguous range of indices much like a dense store; however, growing
+/// it in either direction is a constant-time operation since no cells are materialized.
+/// Reading an unwritten cell yields a reference to the blank.
+///
+/// **Note**: mutably borrowing an unwritten cell (e.g. through
+/// [`cell_mut`](crate::TapeBase::cell_mut)) materializes it; should the cell still hold the
+/// blank, it is pruned by the next mutation of the store and is never reported as written.
+#[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(deny_unknown_fields, rename_all = "snake_case")
+)]
+pub struct SparseStore<A> {
+    /// the non-blank cells, keyed by their offset
+    pub(crate) cells: BTreeMap<isize, A>,
+    /// the offset of the first index within the store
+    pub(crate) base: isize,
+    /// the number of cells spanned by the store
+    pub(crate) len: usize,
+    /// the value of every unwritten cell
+    pub(crate) blank: A,
+    /// the offset of the cell most recently materialized by a mutable borrow, if any
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) materialized: Option<isize>,
+}
@@ -5,9 +5,29 @@
 */
 use crate::RawData;
 
+#[cfg(feature = "alloc")]
+/// a type alias for a [`TapeBase`] densely storing every cell within a [`Vec`](alloc::vec::Vec)
+pub type DenseTape<A> = TapeBase<alloc::vec::Vec<A>, A>;
+#[cfg(feature = "alloc")]
+/// a type alias for a [`TapeBase`] storing only its non-blank cells within a
+/// [`SparseStore`](crate::SparseStore)
+pub type SparseTape<A> = TapeBase<crate::SparseStore<A>, A>;
+#[cfg(feature = "alloc")]
+/// a type alias for a [`TapeBase`] whose clones share their cells until written to, using a
+/// [`PersistentStore`](crate::PersistentStore)
+pub type PersistentTape<A> = TapeBase<crate::PersistentStore<A>, A>;
+#[cfg(feature = "alloc")]
+/// a type alias for a [`TapeBase`] recording the accesses made to each of its cells using an
+/// [`InstrumentedStore`](crate::InstrumentedStore)
+pub type InstrumentedTape<A> = TapeBase<crate::InstrumentedStore<A>, A>;
+
 /// [`TapeBase`] provides a generic implementation of a linear, cell-based memory system that
 /// can be used in various computational contexts.
-#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+///
+/// The tape maintains the index of its head within the underlying store alongside the index
+/// of its _origin_, the cell initially beneath the head. Positions are measured relative to
+/// the origin, ensuring they remain stable as the tape grows towards the left.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 #[cfg_attr(
     feature = "serde",
     derive(serde::Deserialize, serde::Serialize),
@@ -19,4 +39,20 @@ where
     S: RawData<Elem = A>,
 {
     pub(crate) store: S,
+    /// the index of the head within the store
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) head: usize,
+    /// the index of the origin within the store
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) origin: usize,
+}
+
+/// [`Window`] displays the cells of a tape surrounding its head, highlighting the cell
+/// beneath the head in brackets; i.e. `0, 1, 0, [1], 1, 0, 0` for a radius of `3`.
+pub struct Window<'a, S, A = <S as RawData>::Elem>
+where
+    S: RawData<Elem = A>,
+{
+    pub(crate) tape: &'a TapeBase<S, A>,
+    pub(crate) radius: usize,
 }
@@ -4,23 +4,204 @@
     Contrib: @FL03
 */
 
+/// [`RawData`] defines the interface for the underlying storage of a tape, providing indexed
+/// access to a contiguous sequence of elements.
 pub trait RawData {
     type Elem;
-}
 
-macro_rules! impl_raw_data {
-    (@impl<Elem = $E:ident> $trait:ident for $($cont:ident)::*<$($T:ident),* $(,)?>) => {
-        impl<$($T),*> $trait for $($cont)::*<$($T),*> {
-            type Elem = $E;
+    /// returns the number of elements within the store
+    fn len(&self) -> usize;
+    /// returns a reference to the element at the given index, if any
+    fn get(&self, idx: usize) -> Option<&Self::Elem>;
+    /// returns true if the store contains no elements
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// returns an iterator over the elements physically held by the store alongside their
+    /// indices; sparse stores are free to skip any elements they do not hold.
+    fn iter_stored(&self) -> impl Iterator<Item = (usize, &Self::Elem)> {
+        (0..self.len()).filter_map(move |idx| self.get(idx).map(|elem| (idx, elem)))
+    }
+}
+/// [`RawDataMut`] extends [`RawData`] to provide mutable access to the elements of the store
+pub trait RawDataMut: RawData {
+    /// returns a mutable reference to the element at the given index, if any
+    fn get_mut(&mut self, idx: usize) -> Option<&mut Self::Elem>;
+    /// replaces the element at the given index, returning the previous value if the index is
+    /// within the bounds of the store.
+    fn replace(&mut self, idx: usize, elem: Self::Elem) -> Option<Self::Elem> {
+        self.get_mut(idx).map(|cell| core::mem::replace(cell, elem))
+    }
+    /// records that the cell at the given index was read during the given step; a no-op for
+    /// stores that do not collect any metadata.
+    fn record_read(&mut self, idx: usize, step: usize) {
+        let _ = (idx, step);
+    }
+    /// records that the cell at the given index was written during the given step; a no-op
+    /// for stores that do not collect any metadata.
+    fn record_write(&mut self, idx: usize, step: usize) {
+        let _ = (idx, step);
+    }
+}
+/// [`ResizableData`] is implemented by stores capable of growing at either end, enabling the
+/// tape to be extended on demand.
+pub trait ResizableData: RawDataMut {
+    /// prepends the element onto the store
+    fn push_front(&mut self, elem: Self::Elem);
+    /// appends the element onto the store
+    fn push_back(&mut self, elem: Self::Elem);
+    /// returns the value filling any cells the store is grown by, i.e. the default value of
+    /// the element unless the store defines a blank of its own
+    fn blank_elem(&self) -> Self::Elem
+    where
+        Self::Elem: Default,
+    {
+        Self::Elem::default()
+    }
+    /// prepends `count` copies of the element onto the store
+    fn grow_front(&mut self, count: usize, elem: Self::Elem)
+    where
+        Self::Elem: Clone,
+    {
+        for _ in 0..count {
+            self.push_front(elem.clone());
         }
-    };
-    (impl<Elem = $E:ident> $trait:ident for {$($($cont:ident)::*<$($T:ident),* $(,)?>),* $(,)?}) => {
-        $(impl_raw_data! { @impl<Elem = $E> $trait for $($cont)::*<$($T),*> } )*
-    };
+    }
+    /// appends `count` copies of the element onto the store
+    fn grow_back(&mut self, count: usize, elem: Self::Elem)
+    where
+        Self::Elem: Clone,
+    {
+        for _ in 0..count {
+            self.push_back(elem.clone());
+        }
+    }
+}
+
+/*
+ ************* Implementations *************
+*/
+
+impl<const N: usize, T> RawData for [T; N] {
+    type Elem = T;
+
+    fn len(&self) -> usize {
+        N
+    }
+
+    fn get(&self, idx: usize) -> Option<&T> {
+        <[T]>::get(self, idx)
+    }
+}
+
+impl<const N: usize, T> RawDataMut for [T; N] {
+    fn get_mut(&mut self, idx: usize) -> Option<&mut T> {
+        <[T]>::get_mut(self, idx)
+    }
+}
+
+impl<T> RawData for &[T] {
+    type Elem = T;
+
+    fn len(&self) -> usize {
+        <[T]>::len(self)
+    }
+
+    fn get(&self, idx: usize) -> Option<&T> {
+        <[T]>::get(self, idx)
+    }
+}
+
+impl<T> RawData for &mut [T] {
+    type Elem = T;
+
+    fn len(&self) -> usize {
+        <[T]>::len(self)
+    }
+
+    fn get(&self, idx: usize) -> Option<&T> {
+        <[T]>::get(self, idx)
+    }
+}
+
+impl<T> RawDataMut for &mut [T] {
+    fn get_mut(&mut self, idx: usize) -> Option<&mut T> {
+        <[T]>::get_mut(self, idx)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> RawData for alloc::vec::Vec<T> {
+    type Elem = T;
+
+    fn len(&self) -> usize {
+        <[T]>::len(self)
+    }
+
+    fn get(&self, idx: usize) -> Option<&T> {
+        <[T]>::get(self, idx)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> RawDataMut for alloc::vec::Vec<T> {
+    fn get_mut(&mut self, idx: usize) -> Option<&mut T> {
+        <[T]>::get_mut(self, idx)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> ResizableData for alloc::vec::Vec<T> {
+    fn push_front(&mut self, elem: T) {
+        self.insert(0, elem);
+    }
+
+    fn push_back(&mut self, elem: T) {
+        self.push(elem);
+    }
+
+    fn grow_front(&mut self, count: usize, elem: T)
+    where
+        T: Clone,
+    {
+        self.splice(0..0, core::iter::repeat_n(elem, count));
+    }
+
+    fn grow_back(&mut self, count: usize, elem: T)
+    where
+        T: Clone,
+    {
+        self.resize(self.len() + count, elem);
+    }
 }
 
-impl_raw_data! {
-    impl<Elem = T> RawData for {
-        alloc::vec::Vec<T>,
+#[cfg(feature = "alloc")]
+impl<T> RawData for alloc::collections::VecDeque<T> {
+    type Elem = T;
+
+    fn len(&self) -> usize {
+        alloc::collections::VecDeque::len(self)
+    }
+
+    fn get(&self, idx: usize) -> Option<&T> {
+        alloc::collections::VecDeque::get(self, idx)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> RawDataMut for alloc::collections::VecDeque<T> {
+    fn get_mut(&mut self, idx: usize) -> Option<&mut T> {
+        alloc::collections::VecDeque::get_mut(self, idx)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> ResizableData for alloc::collections::VecDeque<T> {
+    fn push_front(&mut self, elem: T) {
+        alloc::collections::VecDeque::push_front(self, elem);
+    }
+
+    fn push_back(&mut self, elem: T) {
+        alloc::collections::VecDeque::push_back(self, elem);
     }
 }
@@ -25,11 +25,7 @@
     strum::VariantArray,
     strum::VariantNames,
 )]
-#[cfg_attr(
-    feature = "serde",
-    derive(serde::Deserialize, serde::Serialize),
-    serde(untagged)
-)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 #[strum(serialize_all = "lowercase")]
 pub enum Direction {
     /// Represents a single left shift
@@ -103,6 +99,15 @@ impl Direction {
             Self::Stay => 'S',
         }
     }
+    /// returns the mirror image of the direction; i.e. left becomes right and vice versa
+    /// while [`Stay`](Direction::Stay) remains unchanged.
+    pub const fn reverse(self) -> Self {
+        match self {
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+            Self::Stay => Self::Stay,
+        }
+    }
     /// Returns a [str] representation of the [direction](Direction).
     pub fn as_str(&self) -> &str {
         match self {
@@ -135,6 +140,22 @@ where
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for Direction {
+    /// directions are written as plain strings, rather than unit variants, ensuring they may be
+    /// read back from within flattened rules by formats like RON that distinguish the two.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(match self {
+            Self::Left => "Left",
+            Self::Right => "Right",
+            Self::Stay => "Stay",
+        })
+    }
+}
+
 impl From<char> for Direction {
     fn from(value: char) -> Self {
         match value {
@@ -164,7 +185,7 @@ macro_rules! impl_apply_direction {
                 *self = match rhs {
                     Direction::Left => self.wrapping_sub(1),
                     Direction::Right => self.wrapping_add(1),
-                    Direction::Stay => 0,
+                    Direction::Stay => *self,
                 };
             }
         }
@@ -211,7 +232,7 @@ macro_rules! impl_from_direction {
                     $(
                         /// Converts an instance of the named type into a [Direction].
                         pub fn [<from_ $T>](value: $T) -> Self {
-                            $crate::IntoDirection::into_direction(value)
+                            Self::from(value)
                         }
                     )*
                 }
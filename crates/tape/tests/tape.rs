@@ -0,0 +1,224 @@
+/*
+    Appellation: tape <test>
+    Created At: 2026.10.19:18:47:12
+    Contrib: @FL03
+*/
+use rstm_tape::{
+    BoundedTape, Direction, InstrumentedTape, PersistentStore, PersistentTape, SparseStore,
+    SparseTape, TapeBase, TapeError,
+};
+use std::collections::VecDeque;
+
+#[test]
+fn test_tape_read_write() {
+    let mut tape = TapeBase::new(vec![1, 0, 1]);
+    assert_eq! { tape.read(), Some(&1) }
+    assert_eq! { tape.write(2), 1 }
+    tape.shift(Direction::Right);
+    assert_eq! { tape.position(), 1 }
+    assert_eq! { tape.try_write(3).ok(), Some(0) }
+    assert_eq! { tape.store(), &vec![2, 3, 1] }
+    // remaining in place leaves the head where it is
+    tape.shift(Direction::Stay);
+    assert_eq! { tape.read(), Some(&3) }
+    assert_eq! { tape.to_string(), "2, [3], 1" }
+}
+
+#[test]
+fn test_tape_grows_on_demand() {
+    let mut tape = TapeBase::<VecDeque<usize>>::default();
+    assert! { tape.is_empty() }
+    assert_eq! { tape.read(), None }
+    tape.write(1);
+    tape.shift(Direction::Left);
+    tape.shift(Direction::Left);
+    // moving beyond the left edge shifts the origin alongside the head
+    assert_eq! { tape.len(), 3 }
+    assert_eq! { (tape.head(), tape.origin()), (0, 2) }
+    assert_eq! { tape.position(), -2 }
+    assert_eq! { tape.get_at(0), Some(&1) }
+    tape.write_at(3, 4);
+    assert_eq! { tape.len(), 6 }
+    assert_eq! { tape.iter().copied().collect::<Vec<_>>(), [0, 0, 1, 0, 0, 4] }
+    assert_eq! {
+        tape.iter_non_blank().collect::<Vec<_>>(),
+        [(0, &1), (3, &4)]
+    }
+    assert_eq! { tape.window(2).to_string(), "[0], 0, 1" }
+    tape.shift(Direction::Right);
+    tape.shift(Direction::Right);
+    assert_eq! { tape.window(1).to_string(), "0, [1], 0" }
+}
+
+#[test]
+fn test_tape_fixed() {
+    let mut tape = TapeBase::new([0u8; 3]).with_head(1);
+    assert_eq! { tape.position(), 0 }
+    assert! { tape.try_shift(Direction::Right).is_ok() }
+    assert! { matches!(tape.try_shift(Direction::Right), Err(TapeError::EdgeOfTape(Direction::Right))) }
+    assert_eq! { tape.try_write(1).ok(), Some(0) }
+    tape.try_shift(Direction::Left).unwrap();
+    tape.try_shift(Direction::Left).unwrap();
+    assert! { matches!(tape.try_shift(Direction::Left), Err(TapeError::EdgeOfTape(Direction::Left))) }
+    assert_eq! { tape.position(), -1 }
+    assert_eq! { tape.into_store(), [0, 0, 1] }
+}
+
+#[test]
+fn test_sparse_tape() {
+    let mut tape = SparseTape::<u8>::default();
+    tape.write(1);
+    // writing cells millions of positions apart only stores the non-blank cells
+    tape.write_at(-3_000_000, 2);
+    tape.write_at(5_000_000, 3);
+    tape.write_at(5_000_001, 4);
+    assert_eq! { tape.len(), 8_000_002 }
+    assert_eq! { tape.count(), 4 }
+    assert_eq! { tape.position(), 0 }
+    assert_eq! { tape.read(), Some(&1) }
+    assert_eq! { tape.get_at(-3_000_000), Some(&2) }
+    assert_eq! { tape.get_at(17), Some(&0) }
+    assert_eq! {
+        tape.iter_non_blank().collect::<Vec<_>>(),
+        [(-3_000_000, &2), (0, &1), (5_000_000, &3), (5_000_001, &4)]
+    }
+    assert_eq! {
+        tape.regions().collect::<Vec<_>>(),
+        [-3_000_000..=-3_000_000, 0..=0, 5_000_000..=5_000_001]
+    }
+    // writing the blank erases the cell
+    assert_eq! { tape.write_at(5_000_000, 0), 3 }
+    assert_eq! { tape.count(), 3 }
+    tape.shift(Direction::Left);
+    tape.shift(Direction::Left);
+    assert_eq! { tape.window(2).to_string(), "0, 0, [0], 0, 1" }
+}
+
+#[test]
+fn test_sparse_tape_blanks() {
+    let mut tape = SparseTape::<u8>::default();
+    tape.write(1);
+    let pristine = tape.clone();
+    // borrowing an unwritten cell without writing to it leaves it unwritten
+    assert_eq! { *tape.cell_mut(4), 0 }
+    assert_eq! { tape.count(), 1 }
+    assert_eq! { tape.regions().collect::<Vec<_>>(), [0..=0] }
+    // cells written through a mutable borrow are kept
+    *tape.cell_mut(2) = 3;
+    tape.write_at(1, 0);
+    assert_eq! { tape.regions().collect::<Vec<_>>(), [0..=0, 2..=2] }
+    *tape.cell_mut(2) = 0;
+    assert_eq! { tape.count(), 1 }
+    // stores spanning the same cells compare equal no matter how they were borrowed
+    let mut other = pristine.clone();
+    other.write_at(4, 0);
+    assert_eq! { tape.store(), other.store() }
+    // the tape grows with the blank of its store
+    let mut tape = SparseTape::new(SparseStore::with_blank(b'_'));
+    tape.write_at(-2, b'a');
+    tape.write_at(2, b'b');
+    assert_eq! { tape.len(), 5 }
+    assert_eq! { tape.count(), 2 }
+    assert_eq! { tape.get_at(0), Some(&b'_') }
+}
+
+#[test]
+fn test_bounded_tape() {
+    let mut tape = BoundedTape::new('<', vec!['a', 'b'], '>');
+    assert_eq! { tape.read(), &'a' }
+    assert_eq! { tape.to_string(), "<, [a], b, >" }
+    // the head may rest upon, but not move beyond, either marker
+    tape.try_shift(Direction::Left).unwrap();
+    assert! { tape.is_at_marker() }
+    assert! { matches!(tape.try_shift(Direction::Left), Err(TapeError::CrossedEndMarker(Direction::Left))) }
+    assert_eq! { tape.position(), -1 }
+    // the markers may be written back but never overwritten
+    assert_eq! { tape.try_write('<').ok(), Some('<') }
+    assert! { matches!(tape.try_write('x'), Err(TapeError::OverwroteEndMarker)) }
+    tape.try_shift(Direction::Right).unwrap();
+    assert_eq! { tape.try_write('x').ok(), Some('a') }
+    assert_eq! { tape.store(), &vec!['x', 'b'] }
+    // an empty input leaves the head upon the right marker
+    let tape = BoundedTape::new('<', Vec::<char>::new(), '>');
+    assert_eq! { tape.read(), &'>' }
+}
+
+#[test]
+fn test_persistent_tape() {
+    let mut tape = (0..200).collect::<PersistentTape<usize>>();
+    assert_eq! { tape.store().chunks(), 4 }
+    // clones share every chunk...
+    let fork = tape.clone();
+    assert_eq! { fork.store().shared_chunks(tape.store()), 4 }
+    // ...while writes copy only the chunk being written to
+    assert_eq! { tape.write_at(100, 0), 100 }
+    assert_eq! { fork.store().shared_chunks(tape.store()), 3 }
+    assert_eq! { fork.get_at(100), Some(&100) }
+    assert_eq! { tape.get_at(100), Some(&0) }
+    // once copied, neither the path to the chunk nor the chunk are copied again...
+    tape.write_at(101, 0);
+    assert_eq! { fork.store().shared_chunks(tape.store()), 3 }
+    // ...while writing to another chunk copies only that chunk
+    tape.write_at(0, 1);
+    assert_eq! { fork.store().shared_chunks(tape.store()), 2 }
+    assert_eq! { fork.get_at(0), Some(&0) }
+    // the tape grows in either direction
+    tape.write_at(-3, 7);
+    tape.write_at(200, 9);
+    assert_eq! { tape.len(), 204 }
+    assert_eq! { tape.get_at(-3), Some(&7) }
+    assert_eq! { tape.get_at(-1), Some(&0) }
+    assert_eq! { tape.get_at(200), Some(&9) }
+    assert_eq! { fork.len(), 200 }
+
+    // forks of a large tape copy only the chunk being written to, regardless of its length
+    let mut tape = (0..100_000).collect::<PersistentTape<usize>>();
+    let chunks = tape.store().chunks();
+    assert_eq! { tape.store().height(), 3 }
+    let fork = tape.clone();
+    tape.write_at(50_000, 0);
+    assert_eq! { fork.store().shared_chunks(tape.store()), chunks - 1 }
+    // growing towards the left raises the tree without copying any of its chunks
+    let mut tape = fork.clone();
+    (1..=1_000).for_each(|i| {
+        tape.write_at(-i, 0);
+    });
+    assert_eq! { tape.len(), 101_000 }
+    assert_eq! { fork.store().shared_chunks(tape.store()), chunks }
+    assert! { tape.store().iter().take(1_001).eq(std::iter::repeat_n(&0, 1_001)) }
+    assert! { tape.store().iter().skip(1_000).eq(fork.store().iter()) }
+
+    let store = PersistentStore::from_iter([1, 2, 3]);
+    assert_eq! { store, PersistentStore::from_iter(vec![1, 2, 3]) }
+    assert_eq! { format!("{store:?}"), "[1, 2, 3]" }
+}
+
+#[test]
+fn test_instrumented_tape() {
+    let mut tape = [0, 0, 0].into_iter().collect::<InstrumentedTape<usize>>();
+    tape.record_read(0);
+    tape.write(1);
+    tape.record_write(0);
+    tape.shift(Direction::Left);
+    tape.write(2);
+    tape.record_write(1);
+    tape.shift(Direction::Right);
+    tape.write(3);
+    tape.record_write(2);
+
+    let cell = tape.cell_at(0).unwrap();
+    assert_eq! { cell.value(), &3 }
+    assert_eq! { cell.writes(), 2 }
+    assert_eq! { cell.last_read(), Some(0) }
+    assert_eq! { cell.last_write(), Some(2) }
+    assert_eq! { cell.history(), [1, 3] }
+    // the cells grown onto the tape are tracked as well
+    assert_eq! { tape.heatmap().collect::<Vec<_>>(), [(-1, 1), (0, 2), (1, 0), (2, 0)] }
+    assert_eq! { tape.dead_regions(2).collect::<Vec<_>>(), [-1..=-1, 1..=2] }
+    // writes made without recording them leave the metadata untouched
+    tape.write_at(2, 4);
+    assert_eq! { tape.cell_at(2).unwrap().writes(), 0 }
+    tape.store_mut().reset();
+    assert! { tape.store().cells().all(|cell| cell.last_access().is_none()) }
+    assert_eq! { tape.get_at(2), Some(&4) }
+}
@@ -42,7 +42,31 @@ pub trait Alphabet {
     }
     #[cfg(feature = "alloc")]
     /// returns the alphabet as a vector
-    fn to_vec(&self) -> Vec<Self::Elem>;
+    fn to_vec(&self) -> Vec<Self::Elem> {
+        self.as_slice().to_vec()
+    }
+}
+
+/// The [`FiniteSymbol`] trait describes symbols drawn from a finite set known at compile time,
+/// where each symbol is assigned a dense index (i.e. `0..COUNT`) w.r.t. the set. The trait is
+/// typically implemented on fieldless enums using the `Alphabet` derive macro.
+pub trait FiniteSymbol: Symbolic {
+    /// the total number of symbols
+    const COUNT: usize;
+    /// every symbol, ordered by its index
+    const SYMBOLS: &'static [Self];
+    /// returns the blank symbol
+    fn blank() -> Self;
+    /// returns the index of the symbol
+    fn index(&self) -> usize;
+    /// returns the symbol associated with the given index, if any
+    fn from_index(index: usize) -> Option<Self> {
+        Self::SYMBOLS.get(index).cloned()
+    }
+    /// returns true if the symbol is the blank symbol
+    fn is_blank(&self) -> bool {
+        self == &Self::blank()
+    }
 }
 
 pub trait AlphabetMut: Alphabet {